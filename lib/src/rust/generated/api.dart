@@ -6,155 +6,681 @@
 import 'frb_generated.dart';
 import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
 
-// These types are ignored because they are neither used by any `pub` functions nor (for structs and enums) marked `#[frb(unignore)]`: `ConvertProgress`
-// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `clone`, `clone`, `clone`, `clone`, `eq`, `fmt`, `fmt`, `fmt`, `fmt`
 
-/// 检测文件类型
-Future<FileType?> detectFileType({required String filePath}) =>
-    RustLib.instance.api.crateApiDetectFileType(filePath: filePath);
+            // These functions are ignored because they are not marked as `pub`: `file_type_for_extension`, `progress_callback`, `reachable_outputs`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `eq`, `eq`, `eq`, `eq`, `eq`, `eq`, `eq`, `eq`, `eq`, `eq`, `eq`, `eq`, `eq`, `eq`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`
 
-/// 获取支持的输出格式
-Future<List<String>> getSupportedOutputFormats({required FileType fileType}) =>
-    RustLib.instance.api.crateApiGetSupportedOutputFormats(fileType: fileType);
 
-/// 获取某个具体文件（根据扩展名/类型）实际支持的输出格式
+            /// Detect file type (by content, falling back to the extension)
+Future<FileType?>  detectFileType({required String filePath }) => RustLib.instance.api.crateApiDetectFileType(filePath: filePath);
+
+/// Detect file type and format, reporting whether the content matches the extension
+Future<FileDetection>  detectFile({required String filePath }) => RustLib.instance.api.crateApiDetectFile(filePath: filePath);
+
+/// Get supported output formats
+///
+/// This is the union of the outputs reachable (directly or through intermediate formats)
+/// from every registered input format of this type.
+Future<List<String>>  getSupportedOutputFormats({required FileType fileType }) => RustLib.instance.api.crateApiGetSupportedOutputFormats(fileType: fileType);
+
+/// Get supported output formats for a specific file (based on extension/type)
+///
+/// Note: This is stricter than `get_supported_output_formats(FileType)` to avoid showing invalid options in the UI.
+Future<List<String>>  getSupportedOutputFormatsForFile({required String filePath }) => RustLib.instance.api.crateApiGetSupportedOutputFormatsForFile(filePath: filePath);
+
+/// Convert single file
+Future<ConvertResult>  convertFile({required String inputPath , required String outputDir , required ConvertOptions options }) => RustLib.instance.api.crateApiConvertFile(inputPath: inputPath, outputDir: outputDir, options: options);
+
+/// Convert single file, streaming progress events
+Stream<ConvertProgress>  convertFileWithProgress({required String inputPath , required String outputDir , required ConvertOptions options }) => RustLib.instance.api.crateApiConvertFileWithProgress(inputPath: inputPath, outputDir: outputDir, options: options);
+
+/// Batch convert files
+Future<List<ConvertResult>>  convertFiles({required List<String> inputPaths , required String outputDir , required ConvertOptions options }) => RustLib.instance.api.crateApiConvertFiles(inputPaths: inputPaths, outputDir: outputDir, options: options);
+
+/// Batch convert files, streaming per-file progress events
+Stream<ConvertProgress>  convertFilesWithProgress({required List<String> inputPaths , required String outputDir , required ConvertOptions options }) => RustLib.instance.api.crateApiConvertFilesWithProgress(inputPaths: inputPaths, outputDir: outputDir, options: options);
+
+/// Put images, in the given order, into one multi-page PDF in `output_dir`
+///
+/// The PDF is named after the first image; see the `pdf_*` options for the page layout.
+Future<ConvertResult>  combineImagesToPdf({required List<String> inputPaths , required String outputDir , required ConvertOptions options }) => RustLib.instance.api.crateApiCombineImagesToPdf(inputPaths: inputPaths, outputDir: outputDir, options: options);
+
+/// Put images into one multi-page PDF, streaming progress events
+Stream<ConvertProgress>  combineImagesToPdfWithProgress({required List<String> inputPaths , required String outputDir , required ConvertOptions options }) => RustLib.instance.api.crateApiCombineImagesToPdfWithProgress(inputPaths: inputPaths, outputDir: outputDir, options: options);
+
+/// Lay images out, in the given order, as a grid of thumbnails in one image in `output_dir`
 ///
-/// 注意：这会比 `get_supported_output_formats(FileType)` 更严格，避免 UI 展示无效选项。
-Future<List<String>> getSupportedOutputFormatsForFile(
-        {required String filePath}) =>
-    RustLib.instance.api
-        .crateApiGetSupportedOutputFormatsForFile(filePath: filePath);
-
-/// 转换单个文件
-Future<ConvertResult> convertFile(
-        {required String inputPath,
-        required String outputDir,
-        required ConvertOptions options}) =>
-    RustLib.instance.api.crateApiConvertFile(
-        inputPath: inputPath, outputDir: outputDir, options: options);
-
-/// 批量转换文件
-Future<List<ConvertResult>> convertFiles(
-        {required List<String> inputPaths,
-        required String outputDir,
-        required ConvertOptions options}) =>
-    RustLib.instance.api.crateApiConvertFiles(
-        inputPaths: inputPaths, outputDir: outputDir, options: options);
-
-/// 打开文件夹
-Future<bool> openFolder({required String folderPath}) =>
-    RustLib.instance.api.crateApiOpenFolder(folderPath: folderPath);
-
-/// 转换选项
-class ConvertOptions {
-  /// 输出格式 (如 "png", "jpg", "pdf", "mp4")
-  final String outputFormat;
-
-  /// 图片质量 (1-100, 用于 JPEG/WebP 压缩)
-  final int? imageQuality;
-
-  /// 音频质量 (MP3/OGG: 0-9, 越低越好; 或 AAC 的比特率如 192)
-  final int? audioQuality;
-
-  /// 音频比特率 kbps (如 128, 192, 320) - audioQuality 的替代选项
-  final int? audioBitrate;
-
-  /// 音频采样率 Hz (如 44100, 48000)
-  final int? audioSampleRate;
-
-  /// 视频 CRF 质量 (0-51, 越低越好, H.264 默认 23)
-  final int? videoCrf;
-
-  /// 视频比特率 kbps (如 2000, 5000) - videoCrf 的替代选项
-  final int? videoBitrate;
-
-  /// 视频宽度 (用于调整尺寸)
-  final int? videoWidth;
-
-  /// 视频高度 (用于调整尺寸)
-  final int? videoHeight;
-
-  /// FFmpeg 执行文件路径（如果由 Dart 端下载提供）
-  final String? ffmpegPath;
-
-  const ConvertOptions({
-    required this.outputFormat,
-    this.imageQuality,
-    this.audioQuality,
-    this.audioBitrate,
-    this.audioSampleRate,
-    this.videoCrf,
-    this.videoBitrate,
-    this.videoWidth,
-    this.videoHeight,
-    this.ffmpegPath,
-  });
-
-  @override
-  int get hashCode =>
-      outputFormat.hashCode ^
-      imageQuality.hashCode ^
-      audioQuality.hashCode ^
-      audioBitrate.hashCode ^
-      audioSampleRate.hashCode ^
-      videoCrf.hashCode ^
-      videoBitrate.hashCode ^
-      videoWidth.hashCode ^
-      videoHeight.hashCode ^
-      ffmpegPath.hashCode;
-
-  @override
-  bool operator ==(Object other) =>
-      identical(this, other) ||
-      other is ConvertOptions &&
-          runtimeType == other.runtimeType &&
-          outputFormat == other.outputFormat &&
-          imageQuality == other.imageQuality &&
-          audioQuality == other.audioQuality &&
-          audioBitrate == other.audioBitrate &&
-          audioSampleRate == other.audioSampleRate &&
-          videoCrf == other.videoCrf &&
-          videoBitrate == other.videoBitrate &&
-          videoWidth == other.videoWidth &&
-          videoHeight == other.videoHeight &&
-          ffmpegPath == other.ffmpegPath;
-}
-
-/// 转换结果
-class ConvertResult {
-  /// 是否成功
-  final bool success;
-
-  /// 输出文件路径
-  final String? outputPath;
-
-  /// 错误信息
-  final String? error;
-
-  const ConvertResult({
-    required this.success,
-    this.outputPath,
-    this.error,
-  });
-
-  @override
-  int get hashCode => success.hashCode ^ outputPath.hashCode ^ error.hashCode;
-
-  @override
-  bool operator ==(Object other) =>
-      identical(this, other) ||
-      other is ConvertResult &&
-          runtimeType == other.runtimeType &&
-          success == other.success &&
-          outputPath == other.outputPath &&
-          error == other.error;
-}
-
-/// 文件类型枚举
+/// The image is named `contact_sheet.<output_format>`; see the `sheet_*` options for the layout.
+Future<ConvertResult>  createContactSheet({required List<String> inputPaths , required String outputDir , required ConvertOptions options }) => RustLib.instance.api.crateApiCreateContactSheet(inputPaths: inputPaths, outputDir: outputDir, options: options);
+
+/// Lay images out as a contact sheet, streaming progress events
+Stream<ConvertProgress>  createContactSheetWithProgress({required List<String> inputPaths , required String outputDir , required ConvertOptions options }) => RustLib.instance.api.crateApiCreateContactSheetWithProgress(inputPaths: inputPaths, outputDir: outputDir, options: options);
+
+/// Convert all matching files of a directory tree, mirroring its folder structure under `output_dir`
+Future<List<DirectoryFileResult>>  convertDirectory({required String inputDir , required String outputDir , required ConvertOptions options , required DirectoryFilter filter }) => RustLib.instance.api.crateApiConvertDirectory(inputDir: inputDir, outputDir: outputDir, options: options, filter: filter);
+
+/// Convert a directory tree, streaming per-file progress events
+Stream<ConvertProgress>  convertDirectoryWithProgress({required String inputDir , required String outputDir , required ConvertOptions options , required DirectoryFilter filter }) => RustLib.instance.api.crateApiConvertDirectoryWithProgress(inputDir: inputDir, outputDir: outputDir, options: options, filter: filter);
+
+/// Cancel a running conversion (single file or batch) by task ID
+///
+/// Returns false if no conversion with this task ID is running.
+Future<bool>  cancelConversion({required String taskId }) => RustLib.instance.api.crateApiCancelConversion(taskId: taskId);
+
+/// Open folder
+Future<bool>  openFolder({required String folderPath }) => RustLib.instance.api.crateApiOpenFolder(folderPath: folderPath);
+
+            /// Error code
+enum ConvertErrorCode {
+                    /// A required external tool is not installed / not found
+toolMissing,
+/// The input/output format pair is not supported
+unsupportedPair,
+/// The input file type is not recognized
+unsupportedFileType,
+/// Failed to decode / parse the input
+decode,
+/// Failed to encode / serialize the output
+encode,
+/// File system error
+io,
+/// An external tool exited with an error
+externalToolFailed,
+/// Unexpected internal error
+internal,
+/// The conversion was cancelled (see `cancel_conversion`)
+cancelled,
+/// The output file already exists (`OnConflict::Fail`)
+outputExists,
+/// An option value is invalid (e.g. an unsupported rotation angle)
+invalidOption,
+                    ;
+                    
+                }
+
+/// Structured error fields (only the ones relevant to the error code are set)
+class ConvertErrorDetails  {
+                /// External tool name (e.g., "ffmpeg", "pandoc")
+final String? tool;
+/// Source format
+final String? from;
+/// Target format
+final String? to;
+/// External tool exit code
+final int? exitCode;
+/// External tool stderr output
+final String? stderr;
+
+                const ConvertErrorDetails({this.tool ,this.from ,this.to ,this.exitCode ,this.stderr ,});
+
+                static Future<ConvertErrorDetails>  default_()=>RustLib.instance.api.crateApiConvertErrorDetailsDefault();
+
+
+                
+
+                
+        @override
+        int get hashCode => tool.hashCode^from.hashCode^to.hashCode^exitCode.hashCode^stderr.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is ConvertErrorDetails &&
+                runtimeType == other.runtimeType
+                && tool == other.tool&& from == other.from&& to == other.to&& exitCode == other.exitCode&& stderr == other.stderr;
+        
+            }
+
+/// Convert options
+class ConvertOptions  {
+                /// Output format (e.g., "png", "jpg", "pdf", "mp4")
+final String outputFormat;
+/// Image quality (1-100, for JPEG/WebP/AVIF compression)
+final int? imageQuality;
+/// Audio quality (0-9 for MP3/OGG, lower is better; or bitrate like 192 for AAC)
+final int? audioQuality;
+/// Audio bitrate in kbps (e.g., 128, 192, 320) - alternative to audio_quality
+final int? audioBitrate;
+/// Audio sample rate in Hz (e.g., 44100, 48000)
+final int? audioSampleRate;
+/// Video CRF quality (0-51, lower is better, 23 is default for H.264)
+final int? videoCrf;
+/// Video bitrate in kbps (e.g., 2000, 5000) - alternative to video_crf
+final int? videoBitrate;
+/// Video width (for resizing)
+final int? videoWidth;
+/// Video height (for resizing)
+final int? videoHeight;
+/// FFmpeg executable path (if provided by Dart side)
+final String? ffmpegPath;
+/// Task ID used by `cancel_conversion` (generated if not provided)
+final String? taskId;
+/// Batch: maximum number of files converted in parallel in-process (images, configs, ...);
+/// defaults to the number of CPU cores
+final int? maxParallel;
+/// Batch: maximum number of external tool jobs (FFmpeg, pandoc, ...) run in parallel;
+/// defaults to 2
+final int? maxParallelExternal;
+/// What to do when the output file already exists (default: overwrite)
+final OnConflict? onConflict;
+/// Image target width in pixels (resize; see `resize_fit`)
+final int? imageWidth;
+/// Image target height in pixels (resize; see `resize_fit`)
+final int? imageHeight;
+/// How the image is fitted into `image_width` x `image_height` (default: contain)
+final ResizeFit? resizeFit;
+/// Resampling filter used for resizing (default: Lanczos3)
+final ResizeFilter? resizeFilter;
+/// Crop rectangle, applied before rotating and resizing
+final CropRect? crop;
+/// Clockwise rotation in degrees (90, 180 or 270)
+final int? rotate;
+/// Mirror the image (applied after rotating)
+final Flip? flip;
+/// Which EXIF/XMP metadata is carried into JPEG, PNG and WebP outputs (default: strip GPS)
+final MetadataMode? metadata;
+/// SVG input: rasterization DPI (default 96, the SVG's own pixel size); ignored when
+/// `image_width` / `image_height` set the size
+final int? svgDpi;
+/// CSS color painted behind SVG input (default: transparent) and behind transparent
+/// images written to formats without alpha, such as JPEG (default: white);
+/// e.g. `#ffffff` or `white`
+final String? background;
+/// SVG input: font family used for text whose fonts are not installed
+/// (default: any sans-serif font)
+final String? svgFontFamily;
+/// Animated input: convert only this frame (0-based) into a still image
+final int? frame;
+/// Write every frame as a numbered image, `name_001.png`, `name_002.png`, ...
+/// (listed in `ConvertResult::output_paths`)
+final bool? extractFrames;
+/// ICO output: icon sizes in pixels, 1-256 (default: 16, 24, 32, 48, 64, 128, 256,
+/// up to the source size)
+final Int32List? icoSizes;
+/// ICO input: the embedded icon size to convert (default: the largest)
+final int? icoSize;
+/// WebP output: lossless instead of lossy `image_quality` encoding (default: lossy)
+final bool? webpLossless;
+/// WebP output: quality of the alpha channel, 0-100 (default: 100, lossless alpha)
+final int? webpAlphaQuality;
+/// PNG output: zlib compression level, 0-9 (default: 6)
+final int? pngCompression;
+/// PNG output: row filter (default: adaptive)
+final PngFilter? pngFilter;
+/// PNG output: quantize to a palette of this many colors, 2-256 (lossy; default: off)
+final int? pngColors;
+/// PNG output: dither when quantizing with `png_colors` (default: true)
+final bool? pngDither;
+/// PNG output: losslessly optimize the file (smallest color type, best filters and
+/// compression); slower, pixels are unchanged
+final bool? pngOptimize;
+/// AVIF output: encoder speed, 1 (slowest, smallest) to 10 (default: 4)
+final int? avifSpeed;
+/// TIFF output: compression (default: LZW)
+final TiffCompression? tiffCompression;
+/// JPEG, lossy WebP and AVIF output: pick the highest quality (capped by
+/// `image_quality`) whose file fits in this many bytes
+final int? targetSizeBytes;
+/// With `target_size_bytes`: shrink the image rather than going below quality 50
+/// (default: false)
+final bool? targetSizeDownscale;
+/// PDF output: page size (default: each page is the size of its image at 96 DPI)
+final PdfPageSize? pdfPageSize;
+/// PDF output: blank space around the image on every side, in millimeters (default: 0)
+final int? pdfMarginMm;
+/// PDF output: how the image is fitted into the page inside the margins (default: contain)
+final ResizeFit? pdfFit;
+/// PDF output: re-encode every image as JPEG with this quality, 1-100 (default: JPEG
+/// input is embedded as it is, other images losslessly)
+final int? pdfJpegQuality;
+/// Text stamped onto image outputs, after the transforms (lines separated by `\n`)
+final String? watermarkText;
+/// Path of an image (e.g. a PNG logo) stamped onto image outputs instead of text
+final String? watermarkImage;
+/// Watermark text font family (default: sans-serif)
+final String? watermarkFontFamily;
+/// Watermark text size in pixels (default: 1/20 of the image's shorter side)
+final int? watermarkFontSize;
+/// Watermark image width in pixels, keeping its aspect ratio (default: its own size)
+final int? watermarkImageWidth;
+/// CSS color of the watermark text (default: white)
+final String? watermarkColor;
+/// Watermark opacity, 0-100 (default: 50)
+final int? watermarkOpacity;
+/// Where the watermark is placed (default: bottom right)
+final WatermarkPosition? watermarkPosition;
+/// Distance in pixels from the image edges, and between tiles with `watermark_tile`
+/// (default: 1/50 of the image's shorter side)
+final int? watermarkMargin;
+/// Repeat the watermark across the whole image (`watermark_position` is ignored)
+final bool? watermarkTile;
+/// Convert the image to grayscale
+final bool? grayscale;
+/// Brightness change in percent, -100 (black) to 100 (twice as bright)
+final int? brightness;
+/// Contrast change in percent, -100 (flat gray) to 100 (twice the contrast)
+final int? contrast;
+/// Rotate the hue of every pixel by this many degrees
+final int? hueRotate;
+/// Gamma correction (greater than 1 brightens the midtones, less than 1 darkens them)
+final double? gamma;
+/// Invert the colors (alpha is kept)
+final bool? invert;
+/// Gaussian blur radius (sigma) in pixels
+final double? blur;
+/// Unsharp-mask sharpening radius (sigma) in pixels, e.g. 1.0
+final double? sharpen;
+/// With `sharpen`: only sharpen where the difference to the blurred image is greater
+/// than this, 0-255 (default: 0)
+final int? sharpenThreshold;
+/// Contact sheet columns (default: enough for a roughly square grid)
+final int? sheetColumns;
+/// Contact sheet cell width in pixels; thumbnails are shrunk to fit (default: 200)
+final int? sheetCellWidth;
+/// Contact sheet cell height in pixels (default: 200)
+final int? sheetCellHeight;
+/// Pixels between the contact sheet cells and around them (default: 10)
+final int? sheetSpacing;
+/// CSS color of the contact sheet background (default: white)
+final String? sheetBackground;
+/// Write each file name under its thumbnail
+final bool? sheetCaptions;
+/// What happens to the input's embedded ICC color profile (default: preserve)
+final IccProfileMode? iccProfile;
+
+                const ConvertOptions({required this.outputFormat ,this.imageQuality ,this.audioQuality ,this.audioBitrate ,this.audioSampleRate ,this.videoCrf ,this.videoBitrate ,this.videoWidth ,this.videoHeight ,this.ffmpegPath ,this.taskId ,this.maxParallel ,this.maxParallelExternal ,this.onConflict ,this.imageWidth ,this.imageHeight ,this.resizeFit ,this.resizeFilter ,this.crop ,this.rotate ,this.flip ,this.metadata ,this.svgDpi ,this.background ,this.svgFontFamily ,this.frame ,this.extractFrames ,this.icoSizes ,this.icoSize ,this.webpLossless ,this.webpAlphaQuality ,this.pngCompression ,this.pngFilter ,this.pngColors ,this.pngDither ,this.pngOptimize ,this.avifSpeed ,this.tiffCompression ,this.targetSizeBytes ,this.targetSizeDownscale ,this.pdfPageSize ,this.pdfMarginMm ,this.pdfFit ,this.pdfJpegQuality ,this.watermarkText ,this.watermarkImage ,this.watermarkFontFamily ,this.watermarkFontSize ,this.watermarkImageWidth ,this.watermarkColor ,this.watermarkOpacity ,this.watermarkPosition ,this.watermarkMargin ,this.watermarkTile ,this.grayscale ,this.brightness ,this.contrast ,this.hueRotate ,this.gamma ,this.invert ,this.blur ,this.sharpen ,this.sharpenThreshold ,this.sheetColumns ,this.sheetCellWidth ,this.sheetCellHeight ,this.sheetSpacing ,this.sheetBackground ,this.sheetCaptions ,this.iccProfile ,});
+
+                static Future<ConvertOptions>  default_()=>RustLib.instance.api.crateApiConvertOptionsDefault();
+
+
+                
+
+                
+        @override
+        int get hashCode => outputFormat.hashCode^imageQuality.hashCode^audioQuality.hashCode^audioBitrate.hashCode^audioSampleRate.hashCode^videoCrf.hashCode^videoBitrate.hashCode^videoWidth.hashCode^videoHeight.hashCode^ffmpegPath.hashCode^taskId.hashCode^maxParallel.hashCode^maxParallelExternal.hashCode^onConflict.hashCode^imageWidth.hashCode^imageHeight.hashCode^resizeFit.hashCode^resizeFilter.hashCode^crop.hashCode^rotate.hashCode^flip.hashCode^metadata.hashCode^svgDpi.hashCode^background.hashCode^svgFontFamily.hashCode^frame.hashCode^extractFrames.hashCode^icoSizes.hashCode^icoSize.hashCode^webpLossless.hashCode^webpAlphaQuality.hashCode^pngCompression.hashCode^pngFilter.hashCode^pngColors.hashCode^pngDither.hashCode^pngOptimize.hashCode^avifSpeed.hashCode^tiffCompression.hashCode^targetSizeBytes.hashCode^targetSizeDownscale.hashCode^pdfPageSize.hashCode^pdfMarginMm.hashCode^pdfFit.hashCode^pdfJpegQuality.hashCode^watermarkText.hashCode^watermarkImage.hashCode^watermarkFontFamily.hashCode^watermarkFontSize.hashCode^watermarkImageWidth.hashCode^watermarkColor.hashCode^watermarkOpacity.hashCode^watermarkPosition.hashCode^watermarkMargin.hashCode^watermarkTile.hashCode^grayscale.hashCode^brightness.hashCode^contrast.hashCode^hueRotate.hashCode^gamma.hashCode^invert.hashCode^blur.hashCode^sharpen.hashCode^sharpenThreshold.hashCode^sheetColumns.hashCode^sheetCellWidth.hashCode^sheetCellHeight.hashCode^sheetSpacing.hashCode^sheetBackground.hashCode^sheetCaptions.hashCode^iccProfile.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is ConvertOptions &&
+                runtimeType == other.runtimeType
+                && outputFormat == other.outputFormat&& imageQuality == other.imageQuality&& audioQuality == other.audioQuality&& audioBitrate == other.audioBitrate&& audioSampleRate == other.audioSampleRate&& videoCrf == other.videoCrf&& videoBitrate == other.videoBitrate&& videoWidth == other.videoWidth&& videoHeight == other.videoHeight&& ffmpegPath == other.ffmpegPath&& taskId == other.taskId&& maxParallel == other.maxParallel&& maxParallelExternal == other.maxParallelExternal&& onConflict == other.onConflict&& imageWidth == other.imageWidth&& imageHeight == other.imageHeight&& resizeFit == other.resizeFit&& resizeFilter == other.resizeFilter&& crop == other.crop&& rotate == other.rotate&& flip == other.flip&& metadata == other.metadata&& svgDpi == other.svgDpi&& background == other.background&& svgFontFamily == other.svgFontFamily&& frame == other.frame&& extractFrames == other.extractFrames&& icoSizes == other.icoSizes&& icoSize == other.icoSize&& webpLossless == other.webpLossless&& webpAlphaQuality == other.webpAlphaQuality&& pngCompression == other.pngCompression&& pngFilter == other.pngFilter&& pngColors == other.pngColors&& pngDither == other.pngDither&& pngOptimize == other.pngOptimize&& avifSpeed == other.avifSpeed&& tiffCompression == other.tiffCompression&& targetSizeBytes == other.targetSizeBytes&& targetSizeDownscale == other.targetSizeDownscale&& pdfPageSize == other.pdfPageSize&& pdfMarginMm == other.pdfMarginMm&& pdfFit == other.pdfFit&& pdfJpegQuality == other.pdfJpegQuality&& watermarkText == other.watermarkText&& watermarkImage == other.watermarkImage&& watermarkFontFamily == other.watermarkFontFamily&& watermarkFontSize == other.watermarkFontSize&& watermarkImageWidth == other.watermarkImageWidth&& watermarkColor == other.watermarkColor&& watermarkOpacity == other.watermarkOpacity&& watermarkPosition == other.watermarkPosition&& watermarkMargin == other.watermarkMargin&& watermarkTile == other.watermarkTile&& grayscale == other.grayscale&& brightness == other.brightness&& contrast == other.contrast&& hueRotate == other.hueRotate&& gamma == other.gamma&& invert == other.invert&& blur == other.blur&& sharpen == other.sharpen&& sharpenThreshold == other.sharpenThreshold&& sheetColumns == other.sheetColumns&& sheetCellWidth == other.sheetCellWidth&& sheetCellHeight == other.sheetCellHeight&& sheetSpacing == other.sheetSpacing&& sheetBackground == other.sheetBackground&& sheetCaptions == other.sheetCaptions&& iccProfile == other.iccProfile;
+        
+            }
+
+/// Conversion progress
+class ConvertProgress  {
+                /// Task ID
+final String taskId;
+/// Input file being converted
+final String inputPath;
+/// Index of the file within the batch (0 for single-file conversions)
+final int fileIndex;
+/// Number of files in the batch (1 for single-file conversions)
+final int fileCount;
+/// Progress percentage of the current file (0-100)
+final int progress;
+/// Current status
+final String status;
+
+                const ConvertProgress({required this.taskId ,required this.inputPath ,required this.fileIndex ,required this.fileCount ,required this.progress ,required this.status ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => taskId.hashCode^inputPath.hashCode^fileIndex.hashCode^fileCount.hashCode^progress.hashCode^status.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is ConvertProgress &&
+                runtimeType == other.runtimeType
+                && taskId == other.taskId&& inputPath == other.inputPath&& fileIndex == other.fileIndex&& fileCount == other.fileCount&& progress == other.progress&& status == other.status;
+        
+            }
+
+/// Conversion result
+class ConvertResult  {
+                /// Whether the conversion was successful
+final bool success;
+/// Output file path
+final String? outputPath;
+/// Error message
+final String? error;
+/// Stable error code (for matching on the Dart side instead of the message)
+final ConvertErrorCode? errorCode;
+/// Structured error fields
+final ConvertErrorDetails? errorDetails;
+/// The output file already existed and was kept (`OnConflict::Skip`)
+final bool skipped;
+/// Formats the file went through, e.g. `["docx", "md", "pdf"]` for a two-step conversion
+/// (empty if no route was found)
+final List<String> route;
+/// Every file written; several when extracting animation frames (`output_path` is the first)
+final List<String> outputPaths;
+/// Non-fatal issues, e.g. transparency removed for a format without alpha
+final List<String> warnings;
+/// Quality chosen to fit `ConvertOptions::target_size_bytes`
+final int? imageQuality;
+
+                const ConvertResult({required this.success ,this.outputPath ,this.error ,this.errorCode ,this.errorDetails ,required this.skipped ,required this.route ,required this.outputPaths ,required this.warnings ,this.imageQuality ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => success.hashCode^outputPath.hashCode^error.hashCode^errorCode.hashCode^errorDetails.hashCode^skipped.hashCode^route.hashCode^outputPaths.hashCode^warnings.hashCode^imageQuality.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is ConvertResult &&
+                runtimeType == other.runtimeType
+                && success == other.success&& outputPath == other.outputPath&& error == other.error&& errorCode == other.errorCode&& errorDetails == other.errorDetails&& skipped == other.skipped&& route == other.route&& outputPaths == other.outputPaths&& warnings == other.warnings&& imageQuality == other.imageQuality;
+        
+            }
+
+/// Rectangle in pixels
+class CropRect  {
+                final int x;
+final int y;
+final int width;
+final int height;
+
+                const CropRect({required this.x ,required this.y ,required this.width ,required this.height ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => x.hashCode^y.hashCode^width.hashCode^height.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is CropRect &&
+                runtimeType == other.runtimeType
+                && x == other.x&& y == other.y&& width == other.width&& height == other.height;
+        
+            }
+
+/// How the file type was determined
+enum DetectionConfidence {
+                    /// Matched a binary file signature (magic bytes)
+high,
+/// Matched a text heuristic (e.g. parses as JSON/YAML)
+medium,
+/// Only the file extension is known
+low,
+/// Could not be determined
+unknown,
+                    ;
+                    
+                }
+
+/// Result of one file of a directory conversion
+class DirectoryFileResult  {
+                /// Input file path
+final String inputPath;
+/// Path relative to the input directory (`/`-separated)
+final String relativePath;
+/// Conversion result
+final ConvertResult result;
+
+                const DirectoryFileResult({required this.inputPath ,required this.relativePath ,required this.result ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => inputPath.hashCode^relativePath.hashCode^result.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is DirectoryFileResult &&
+                runtimeType == other.runtimeType
+                && inputPath == other.inputPath&& relativePath == other.relativePath&& result == other.result;
+        
+            }
+
+/// Which files `convert_directory` picks up
+class DirectoryFilter  {
+                /// Glob patterns (`*`, `**`, `?`, `[...]`) a file must match (empty = all files).
+/// Patterns without `/` match the file name, others the path relative to the input directory.
+final List<String> include;
+/// Glob patterns of files and folders to skip
+final List<String> exclude;
+/// File types to convert (empty = every file that can be converted to the output format)
+final List<FileType> fileTypes;
+
+                const DirectoryFilter({required this.include ,required this.exclude ,required this.fileTypes ,});
+
+                static Future<DirectoryFilter>  default_()=>RustLib.instance.api.crateApiDirectoryFilterDefault();
+
+
+                
+
+                
+        @override
+        int get hashCode => include.hashCode^exclude.hashCode^fileTypes.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is DirectoryFilter &&
+                runtimeType == other.runtimeType
+                && include == other.include&& exclude == other.exclude&& fileTypes == other.fileTypes;
+        
+            }
+
+/// File detection result
+class FileDetection  {
+                /// Detected file type
+final FileType? fileType;
+/// Detected format as an extension (e.g., "png", "docx"); falls back to the file extension
+final String? format;
+/// File extension (lowercase)
+final String? extension_;
+/// How reliable the detection is
+final DetectionConfidence confidence;
+/// The content does not match the file extension (misnamed file)
+final bool extensionMismatch;
+
+                const FileDetection({this.fileType ,this.format ,this.extension_ ,required this.confidence ,required this.extensionMismatch ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => fileType.hashCode^format.hashCode^extension_.hashCode^confidence.hashCode^extensionMismatch.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is FileDetection &&
+                runtimeType == other.runtimeType
+                && fileType == other.fileType&& format == other.format&& extension_ == other.extension_&& confidence == other.confidence&& extensionMismatch == other.extensionMismatch;
+        
+            }
+
+/// File type enum
 enum FileType {
-  image,
-  document,
-  audio,
-  video,
-  ;
-}
+                    image,
+document,
+audio,
+video,
+config,
+                    ;
+                    
+                }
+
+/// Mirror direction
+enum Flip {
+                    horizontal,
+vertical,
+both,
+                    ;
+                    
+                }
+
+/// Handling of an image's embedded ICC color profile (e.g. Adobe RGB, Display P3)
+enum IccProfileMode {
+                    /// Embed the profile in JPEG, PNG, WebP, TIFF and PDF output; other formats cannot carry
+/// one, so their colors are converted to sRGB (with a warning). Grayscale images and
+/// `grayscale` output are always converted.
+preserve,
+/// Convert the colors to sRGB and leave the profile out
+convertToSrgb,
+                    ;
+                    static Future<IccProfileMode>  default_()=>RustLib.instance.api.crateApiIccProfileModeDefault();
+
+
+                }
+
+/// Image metadata handling
+///
+/// The EXIF orientation is always applied to the pixels and reset in the kept EXIF.
+/// ICC color profiles are color data, not metadata, and are kept in every mode.
+enum MetadataMode {
+                    /// Drop all EXIF and XMP metadata
+stripAll,
+/// Keep EXIF and XMP, except GPS location
+stripGps,
+/// Keep EXIF and XMP as they are
+preserve,
+                    ;
+                    static Future<MetadataMode>  default_()=>RustLib.instance.api.crateApiMetadataModeDefault();
+
+
+                }
+
+/// What to do when the output file already exists
+enum OnConflict {
+                    /// Replace the existing file
+overwrite,
+/// Keep the existing file and do not convert
+skip,
+/// Write to `name_1.ext`, `name_2.ext`, ... instead
+rename,
+/// Report an `OutputExists` error
+fail,
+                    ;
+                    static Future<OnConflict>  default_()=>RustLib.instance.api.crateApiOnConflictDefault();
+
+
+                }
+
+/// PDF page size; fixed sizes turn to landscape for images wider than tall
+enum PdfPageSize {
+                    /// The size of the image (plus margins)
+image,
+a3,
+a4,
+a5,
+letter,
+legal,
+                    ;
+                    static Future<PdfPageSize>  default_()=>RustLib.instance.api.crateApiPdfPageSizeDefault();
+
+
+                }
+
+/// PNG row filter, applied before compression
+enum PngFilter {
+                    noFilter,
+sub,
+up,
+average,
+paeth,
+/// Picks the best filter per row
+adaptive,
+                    ;
+                    static Future<PngFilter>  default_()=>RustLib.instance.api.crateApiPngFilterDefault();
+
+
+                }
+
+/// Resampling filter
+enum ResizeFilter {
+                    nearest,
+/// Bilinear
+triangle,
+catmullRom,
+gaussian,
+lanczos3,
+                    ;
+                    static Future<ResizeFilter>  default_()=>RustLib.instance.api.crateApiResizeFilterDefault();
+
+
+                }
+
+/// How an image is resized to the target width/height
+///
+/// With only one of width/height set, the other follows from the aspect ratio.
+enum ResizeFit {
+                    /// Scale to fit inside the box, keeping the aspect ratio
+contain,
+/// Scale to fill the box, keeping the aspect ratio and cropping the overflow
+cover,
+/// Stretch to exactly the given size
+exact,
+/// Like `Contain`, but only ever shrinks (e.g. "cap at 1920px wide")
+maxDimension,
+                    ;
+                    static Future<ResizeFit>  default_()=>RustLib.instance.api.crateApiResizeFitDefault();
+
+
+                }
+
+/// TIFF compression scheme (all lossless)
+enum TiffCompression {
+                    uncompressed,
+lzw,
+deflate,
+packBits,
+                    ;
+                    static Future<TiffCompression>  default_()=>RustLib.instance.api.crateApiTiffCompressionDefault();
+
+
+                }
+
+/// Watermark anchor within the image
+enum WatermarkPosition {
+                    topLeft,
+top,
+topRight,
+left,
+center,
+right,
+bottomLeft,
+bottom,
+bottomRight,
+                    ;
+                    static Future<WatermarkPosition>  default_()=>RustLib.instance.api.crateApiWatermarkPositionDefault();
+
+
+                }
+            
\ No newline at end of file
@@ -7,679 +7,2130 @@ import 'api.dart';
 import 'dart:async';
 import 'dart:convert';
 import 'frb_generated.dart';
-import 'frb_generated.io.dart'
-    if (dart.library.js_interop) 'frb_generated.web.dart';
+import 'frb_generated.io.dart' if (dart.library.js_interop) 'frb_generated.web.dart';
 import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
 
-/// Main entrypoint of the Rust API
-class RustLib extends BaseEntrypoint<RustLibApi, RustLibApiImpl, RustLibWire> {
-  @internal
-  static final instance = RustLib._();
-
-  RustLib._();
-
-  /// Initialize flutter_rust_bridge
-  static Future<void> init({
-    RustLibApi? api,
-    BaseHandler? handler,
-    ExternalLibrary? externalLibrary,
-    bool forceSameCodegenVersion = true,
-  }) async {
-    await instance.initImpl(
-      api: api,
-      handler: handler,
-      externalLibrary: externalLibrary,
-      forceSameCodegenVersion: forceSameCodegenVersion,
-    );
-  }
-
-  /// Initialize flutter_rust_bridge in mock mode.
-  /// No libraries for FFI are loaded.
-  static void initMock({
-    required RustLibApi api,
-  }) {
-    instance.initMockImpl(
-      api: api,
-    );
-  }
-
-  /// Dispose flutter_rust_bridge
-  ///
-  /// The call to this function is optional, since flutter_rust_bridge (and everything else)
-  /// is automatically disposed when the app stops.
-  static void dispose() => instance.disposeImpl();
-
-  @override
-  ApiImplConstructor<RustLibApiImpl, RustLibWire> get apiImplConstructor =>
-      RustLibApiImpl.new;
-
-  @override
-  WireConstructor<RustLibWire> get wireConstructor =>
-      RustLibWire.fromExternalLibrary;
-
-  @override
-  Future<void> executeRustInitializers() async {}
-
-  @override
-  ExternalLibraryLoaderConfig get defaultExternalLibraryLoaderConfig =>
-      kDefaultExternalLibraryLoaderConfig;
-
-  @override
-  String get codegenVersion => '2.11.1';
-
-  @override
-  int get rustContentHash => 545976053;
-
-  static const kDefaultExternalLibraryLoaderConfig =
-      ExternalLibraryLoaderConfig(
-    stem: 'convertx_core',
-    ioDirectory: 'rust/target/release/',
-    webPrefix: 'pkg/',
-  );
-}
-
-abstract class RustLibApi extends BaseApi {
-  Future<ConvertResult> crateApiConvertFile(
-      {required String inputPath,
-      required String outputDir,
-      required ConvertOptions options});
-
-  Future<List<ConvertResult>> crateApiConvertFiles(
-      {required List<String> inputPaths,
-      required String outputDir,
-      required ConvertOptions options});
-
-  Future<FileType?> crateApiDetectFileType({required String filePath});
-
-  Future<List<String>> crateApiGetSupportedOutputFormats(
-      {required FileType fileType});
-
-  Future<List<String>> crateApiGetSupportedOutputFormatsForFile(
-      {required String filePath});
-
-  Future<bool> crateApiOpenFolder({required String folderPath});
-}
-
-class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
-  RustLibApiImpl({
-    required super.handler,
-    required super.wire,
-    required super.generalizedFrbRustBinding,
-    required super.portManager,
-  });
-
-  @override
-  Future<ConvertResult> crateApiConvertFile(
-      {required String inputPath,
-      required String outputDir,
-      required ConvertOptions options}) {
-    return handler.executeNormal(NormalTask(
-      callFfi: (port_) {
-        final serializer = SseSerializer(generalizedFrbRustBinding);
-        sse_encode_String(inputPath, serializer);
-        sse_encode_String(outputDir, serializer);
-        sse_encode_box_autoadd_convert_options(options, serializer);
-        pdeCallFfi(generalizedFrbRustBinding, serializer,
-            funcId: 1, port: port_);
-      },
-      codec: SseCodec(
-        decodeSuccessData: sse_decode_convert_result,
-        decodeErrorData: null,
-      ),
-      constMeta: kCrateApiConvertFileConstMeta,
-      argValues: [inputPath, outputDir, options],
-      apiImpl: this,
-    ));
-  }
-
-  TaskConstMeta get kCrateApiConvertFileConstMeta => const TaskConstMeta(
-        debugName: "convert_file",
-        argNames: ["inputPath", "outputDir", "options"],
-      );
-
-  @override
-  Future<List<ConvertResult>> crateApiConvertFiles(
-      {required List<String> inputPaths,
-      required String outputDir,
-      required ConvertOptions options}) {
-    return handler.executeNormal(NormalTask(
-      callFfi: (port_) {
-        final serializer = SseSerializer(generalizedFrbRustBinding);
-        sse_encode_list_String(inputPaths, serializer);
-        sse_encode_String(outputDir, serializer);
-        sse_encode_box_autoadd_convert_options(options, serializer);
-        pdeCallFfi(generalizedFrbRustBinding, serializer,
-            funcId: 2, port: port_);
-      },
-      codec: SseCodec(
-        decodeSuccessData: sse_decode_list_convert_result,
-        decodeErrorData: null,
-      ),
-      constMeta: kCrateApiConvertFilesConstMeta,
-      argValues: [inputPaths, outputDir, options],
-      apiImpl: this,
-    ));
-  }
-
-  TaskConstMeta get kCrateApiConvertFilesConstMeta => const TaskConstMeta(
-        debugName: "convert_files",
-        argNames: ["inputPaths", "outputDir", "options"],
-      );
-
-  @override
-  Future<FileType?> crateApiDetectFileType({required String filePath}) {
-    return handler.executeNormal(NormalTask(
-      callFfi: (port_) {
-        final serializer = SseSerializer(generalizedFrbRustBinding);
-        sse_encode_String(filePath, serializer);
-        pdeCallFfi(generalizedFrbRustBinding, serializer,
-            funcId: 3, port: port_);
-      },
-      codec: SseCodec(
-        decodeSuccessData: sse_decode_opt_box_autoadd_file_type,
-        decodeErrorData: null,
-      ),
-      constMeta: kCrateApiDetectFileTypeConstMeta,
-      argValues: [filePath],
-      apiImpl: this,
-    ));
-  }
-
-  TaskConstMeta get kCrateApiDetectFileTypeConstMeta => const TaskConstMeta(
-        debugName: "detect_file_type",
-        argNames: ["filePath"],
-      );
-
-  @override
-  Future<List<String>> crateApiGetSupportedOutputFormats(
-      {required FileType fileType}) {
-    return handler.executeNormal(NormalTask(
-      callFfi: (port_) {
-        final serializer = SseSerializer(generalizedFrbRustBinding);
-        sse_encode_file_type(fileType, serializer);
-        pdeCallFfi(generalizedFrbRustBinding, serializer,
-            funcId: 4, port: port_);
-      },
-      codec: SseCodec(
-        decodeSuccessData: sse_decode_list_String,
-        decodeErrorData: null,
-      ),
-      constMeta: kCrateApiGetSupportedOutputFormatsConstMeta,
-      argValues: [fileType],
-      apiImpl: this,
-    ));
-  }
-
-  TaskConstMeta get kCrateApiGetSupportedOutputFormatsConstMeta =>
-      const TaskConstMeta(
-        debugName: "get_supported_output_formats",
-        argNames: ["fileType"],
-      );
-
-  @override
-  Future<List<String>> crateApiGetSupportedOutputFormatsForFile(
-      {required String filePath}) {
-    return handler.executeNormal(NormalTask(
-      callFfi: (port_) {
-        final serializer = SseSerializer(generalizedFrbRustBinding);
-        sse_encode_String(filePath, serializer);
-        pdeCallFfi(generalizedFrbRustBinding, serializer,
-            funcId: 5, port: port_);
-      },
-      codec: SseCodec(
-        decodeSuccessData: sse_decode_list_String,
-        decodeErrorData: null,
-      ),
-      constMeta: kCrateApiGetSupportedOutputFormatsForFileConstMeta,
-      argValues: [filePath],
-      apiImpl: this,
-    ));
-  }
-
-  TaskConstMeta get kCrateApiGetSupportedOutputFormatsForFileConstMeta =>
-      const TaskConstMeta(
-        debugName: "get_supported_output_formats_for_file",
-        argNames: ["filePath"],
-      );
-
-  @override
-  Future<bool> crateApiOpenFolder({required String folderPath}) {
-    return handler.executeNormal(NormalTask(
-      callFfi: (port_) {
-        final serializer = SseSerializer(generalizedFrbRustBinding);
-        sse_encode_String(folderPath, serializer);
-        pdeCallFfi(generalizedFrbRustBinding, serializer,
-            funcId: 6, port: port_);
-      },
-      codec: SseCodec(
-        decodeSuccessData: sse_decode_bool,
-        decodeErrorData: null,
-      ),
-      constMeta: kCrateApiOpenFolderConstMeta,
-      argValues: [folderPath],
-      apiImpl: this,
-    ));
-  }
-
-  TaskConstMeta get kCrateApiOpenFolderConstMeta => const TaskConstMeta(
-        debugName: "open_folder",
-        argNames: ["folderPath"],
-      );
-
-  @protected
-  String dco_decode_String(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw as String;
-  }
-
-  @protected
-  bool dco_decode_bool(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw as bool;
-  }
-
-  @protected
-  ConvertOptions dco_decode_box_autoadd_convert_options(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return dco_decode_convert_options(raw);
-  }
-
-  @protected
-  FileType dco_decode_box_autoadd_file_type(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return dco_decode_file_type(raw);
-  }
-
-  @protected
-  int dco_decode_box_autoadd_i_32(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw as int;
-  }
-
-  @protected
-  ConvertOptions dco_decode_convert_options(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    final arr = raw as List<dynamic>;
-    if (arr.length != 10)
-      throw Exception('unexpected arr length: expect 10 but see ${arr.length}');
-    return ConvertOptions(
-      outputFormat: dco_decode_String(arr[0]),
-      imageQuality: dco_decode_opt_box_autoadd_i_32(arr[1]),
-      audioQuality: dco_decode_opt_box_autoadd_i_32(arr[2]),
-      audioBitrate: dco_decode_opt_box_autoadd_i_32(arr[3]),
-      audioSampleRate: dco_decode_opt_box_autoadd_i_32(arr[4]),
-      videoCrf: dco_decode_opt_box_autoadd_i_32(arr[5]),
-      videoBitrate: dco_decode_opt_box_autoadd_i_32(arr[6]),
-      videoWidth: dco_decode_opt_box_autoadd_i_32(arr[7]),
-      videoHeight: dco_decode_opt_box_autoadd_i_32(arr[8]),
-      ffmpegPath: dco_decode_opt_String(arr[9]),
-    );
-  }
-
-  @protected
-  ConvertResult dco_decode_convert_result(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    final arr = raw as List<dynamic>;
-    if (arr.length != 3)
-      throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
-    return ConvertResult(
-      success: dco_decode_bool(arr[0]),
-      outputPath: dco_decode_opt_String(arr[1]),
-      error: dco_decode_opt_String(arr[2]),
-    );
-  }
-
-  @protected
-  FileType dco_decode_file_type(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return FileType.values[raw as int];
-  }
-
-  @protected
-  int dco_decode_i_32(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw as int;
-  }
-
-  @protected
-  List<String> dco_decode_list_String(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return (raw as List<dynamic>).map(dco_decode_String).toList();
-  }
-
-  @protected
-  List<ConvertResult> dco_decode_list_convert_result(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return (raw as List<dynamic>).map(dco_decode_convert_result).toList();
-  }
-
-  @protected
-  Uint8List dco_decode_list_prim_u_8_strict(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw as Uint8List;
-  }
-
-  @protected
-  String? dco_decode_opt_String(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw == null ? null : dco_decode_String(raw);
-  }
-
-  @protected
-  FileType? dco_decode_opt_box_autoadd_file_type(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw == null ? null : dco_decode_box_autoadd_file_type(raw);
-  }
-
-  @protected
-  int? dco_decode_opt_box_autoadd_i_32(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw == null ? null : dco_decode_box_autoadd_i_32(raw);
-  }
-
-  @protected
-  int dco_decode_u_8(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw as int;
-  }
-
-  @protected
-  void dco_decode_unit(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return;
-  }
-
-  @protected
-  String sse_decode_String(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    var inner = sse_decode_list_prim_u_8_strict(deserializer);
-    return utf8.decoder.convert(inner);
-  }
-
-  @protected
-  bool sse_decode_bool(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    return deserializer.buffer.getUint8() != 0;
-  }
-
-  @protected
-  ConvertOptions sse_decode_box_autoadd_convert_options(
-      SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    return (sse_decode_convert_options(deserializer));
-  }
-
-  @protected
-  FileType sse_decode_box_autoadd_file_type(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    return (sse_decode_file_type(deserializer));
-  }
-
-  @protected
-  int sse_decode_box_autoadd_i_32(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    return (sse_decode_i_32(deserializer));
-  }
-
-  @protected
-  ConvertOptions sse_decode_convert_options(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    var var_outputFormat = sse_decode_String(deserializer);
-    var var_imageQuality = sse_decode_opt_box_autoadd_i_32(deserializer);
-    var var_audioQuality = sse_decode_opt_box_autoadd_i_32(deserializer);
-    var var_audioBitrate = sse_decode_opt_box_autoadd_i_32(deserializer);
-    var var_audioSampleRate = sse_decode_opt_box_autoadd_i_32(deserializer);
-    var var_videoCrf = sse_decode_opt_box_autoadd_i_32(deserializer);
-    var var_videoBitrate = sse_decode_opt_box_autoadd_i_32(deserializer);
-    var var_videoWidth = sse_decode_opt_box_autoadd_i_32(deserializer);
-    var var_videoHeight = sse_decode_opt_box_autoadd_i_32(deserializer);
-    var var_ffmpegPath = sse_decode_opt_String(deserializer);
-    return ConvertOptions(
-        outputFormat: var_outputFormat,
-        imageQuality: var_imageQuality,
-        audioQuality: var_audioQuality,
-        audioBitrate: var_audioBitrate,
-        audioSampleRate: var_audioSampleRate,
-        videoCrf: var_videoCrf,
-        videoBitrate: var_videoBitrate,
-        videoWidth: var_videoWidth,
-        videoHeight: var_videoHeight,
-        ffmpegPath: var_ffmpegPath);
-  }
-
-  @protected
-  ConvertResult sse_decode_convert_result(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    var var_success = sse_decode_bool(deserializer);
-    var var_outputPath = sse_decode_opt_String(deserializer);
-    var var_error = sse_decode_opt_String(deserializer);
-    return ConvertResult(
-        success: var_success, outputPath: var_outputPath, error: var_error);
-  }
-
-  @protected
-  FileType sse_decode_file_type(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    var inner = sse_decode_i_32(deserializer);
-    return FileType.values[inner];
-  }
-
-  @protected
-  int sse_decode_i_32(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    return deserializer.buffer.getInt32();
-  }
-
-  @protected
-  List<String> sse_decode_list_String(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-
-    var len_ = sse_decode_i_32(deserializer);
-    var ans_ = <String>[];
-    for (var idx_ = 0; idx_ < len_; ++idx_) {
-      ans_.add(sse_decode_String(deserializer));
-    }
-    return ans_;
-  }
-
-  @protected
-  List<ConvertResult> sse_decode_list_convert_result(
-      SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-
-    var len_ = sse_decode_i_32(deserializer);
-    var ans_ = <ConvertResult>[];
-    for (var idx_ = 0; idx_ < len_; ++idx_) {
-      ans_.add(sse_decode_convert_result(deserializer));
-    }
-    return ans_;
-  }
-
-  @protected
-  Uint8List sse_decode_list_prim_u_8_strict(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    var len_ = sse_decode_i_32(deserializer);
-    return deserializer.buffer.getUint8List(len_);
-  }
-
-  @protected
-  String? sse_decode_opt_String(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-
-    if (sse_decode_bool(deserializer)) {
-      return (sse_decode_String(deserializer));
-    } else {
-      return null;
-    }
-  }
-
-  @protected
-  FileType? sse_decode_opt_box_autoadd_file_type(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-
-    if (sse_decode_bool(deserializer)) {
-      return (sse_decode_box_autoadd_file_type(deserializer));
-    } else {
-      return null;
-    }
-  }
-
-  @protected
-  int? sse_decode_opt_box_autoadd_i_32(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-
-    if (sse_decode_bool(deserializer)) {
-      return (sse_decode_box_autoadd_i_32(deserializer));
-    } else {
-      return null;
-    }
-  }
-
-  @protected
-  int sse_decode_u_8(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    return deserializer.buffer.getUint8();
-  }
-
-  @protected
-  void sse_decode_unit(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-  }
-
-  @protected
-  void sse_encode_String(String self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_list_prim_u_8_strict(utf8.encoder.convert(self), serializer);
-  }
-
-  @protected
-  void sse_encode_bool(bool self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    serializer.buffer.putUint8(self ? 1 : 0);
-  }
-
-  @protected
-  void sse_encode_box_autoadd_convert_options(
-      ConvertOptions self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_convert_options(self, serializer);
-  }
-
-  @protected
-  void sse_encode_box_autoadd_file_type(
-      FileType self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_file_type(self, serializer);
-  }
-
-  @protected
-  void sse_encode_box_autoadd_i_32(int self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self, serializer);
-  }
-
-  @protected
-  void sse_encode_convert_options(
-      ConvertOptions self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_String(self.outputFormat, serializer);
-    sse_encode_opt_box_autoadd_i_32(self.imageQuality, serializer);
-    sse_encode_opt_box_autoadd_i_32(self.audioQuality, serializer);
-    sse_encode_opt_box_autoadd_i_32(self.audioBitrate, serializer);
-    sse_encode_opt_box_autoadd_i_32(self.audioSampleRate, serializer);
-    sse_encode_opt_box_autoadd_i_32(self.videoCrf, serializer);
-    sse_encode_opt_box_autoadd_i_32(self.videoBitrate, serializer);
-    sse_encode_opt_box_autoadd_i_32(self.videoWidth, serializer);
-    sse_encode_opt_box_autoadd_i_32(self.videoHeight, serializer);
-    sse_encode_opt_String(self.ffmpegPath, serializer);
-  }
-
-  @protected
-  void sse_encode_convert_result(ConvertResult self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_bool(self.success, serializer);
-    sse_encode_opt_String(self.outputPath, serializer);
-    sse_encode_opt_String(self.error, serializer);
-  }
-
-  @protected
-  void sse_encode_file_type(FileType self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.index, serializer);
-  }
-
-  @protected
-  void sse_encode_i_32(int self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    serializer.buffer.putInt32(self);
-  }
-
-  @protected
-  void sse_encode_list_String(List<String> self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.length, serializer);
-    for (final item in self) {
-      sse_encode_String(item, serializer);
-    }
-  }
-
-  @protected
-  void sse_encode_list_convert_result(
-      List<ConvertResult> self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.length, serializer);
-    for (final item in self) {
-      sse_encode_convert_result(item, serializer);
-    }
-  }
-
-  @protected
-  void sse_encode_list_prim_u_8_strict(
-      Uint8List self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.length, serializer);
-    serializer.buffer.putUint8List(self);
-  }
-
-  @protected
-  void sse_encode_opt_String(String? self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-
-    sse_encode_bool(self != null, serializer);
-    if (self != null) {
-      sse_encode_String(self, serializer);
-    }
-  }
-
-  @protected
-  void sse_encode_opt_box_autoadd_file_type(
-      FileType? self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-
-    sse_encode_bool(self != null, serializer);
-    if (self != null) {
-      sse_encode_box_autoadd_file_type(self, serializer);
-    }
-  }
-
-  @protected
-  void sse_encode_opt_box_autoadd_i_32(int? self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-
-    sse_encode_bool(self != null, serializer);
-    if (self != null) {
-      sse_encode_box_autoadd_i_32(self, serializer);
-    }
-  }
-
-  @protected
-  void sse_encode_u_8(int self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    serializer.buffer.putUint8(self);
-  }
-
-  @protected
-  void sse_encode_unit(void self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-  }
-}
+
+                /// Main entrypoint of the Rust API
+                class RustLib extends BaseEntrypoint<RustLibApi, RustLibApiImpl, RustLibWire> {
+                  @internal
+                  static final instance = RustLib._();
+
+                  RustLib._();
+
+                  /// Initialize flutter_rust_bridge
+                  static Future<void> init({
+                    RustLibApi? api,
+                    BaseHandler? handler,
+                    ExternalLibrary? externalLibrary,
+                    bool forceSameCodegenVersion = true,
+                  }) async {
+                    await instance.initImpl(
+                      api: api,
+                      handler: handler,
+                      externalLibrary: externalLibrary,
+                      forceSameCodegenVersion: forceSameCodegenVersion,
+                    );
+                  }
+
+                  /// Initialize flutter_rust_bridge in mock mode.
+                  /// No libraries for FFI are loaded.
+                  static void initMock({
+                    required RustLibApi api,
+                  }) {
+                    instance.initMockImpl(
+                      api: api,
+                    );
+                  }
+
+                  /// Dispose flutter_rust_bridge
+                  ///
+                  /// The call to this function is optional, since flutter_rust_bridge (and everything else)
+                  /// is automatically disposed when the app stops.
+                  static void dispose() => instance.disposeImpl();
+
+                  @override
+                  ApiImplConstructor<RustLibApiImpl, RustLibWire> get apiImplConstructor => RustLibApiImpl.new;
+
+                  @override
+                  WireConstructor<RustLibWire> get wireConstructor => RustLibWire.fromExternalLibrary;
+
+                  @override
+                  Future<void> executeRustInitializers() async {
+                    
+                  }
+
+                  @override
+                  ExternalLibraryLoaderConfig get defaultExternalLibraryLoaderConfig => kDefaultExternalLibraryLoaderConfig;
+
+                  @override
+                  String get codegenVersion => '2.11.1';
+
+                  @override
+                  int get rustContentHash => -319853550;
+
+                  static const kDefaultExternalLibraryLoaderConfig = ExternalLibraryLoaderConfig(
+                    stem: 'convertx_core',
+                    ioDirectory: 'rust/target/release/',
+                    webPrefix: 'pkg/',
+                  );
+                }
+                
+
+                abstract class RustLibApi extends BaseApi {
+                  Future<bool> crateApiCancelConversion({required String taskId });
+
+Future<ConvertResult> crateApiCombineImagesToPdf({required List<String> inputPaths , required String outputDir , required ConvertOptions options });
+
+Stream<ConvertProgress> crateApiCombineImagesToPdfWithProgress({required List<String> inputPaths , required String outputDir , required ConvertOptions options });
+
+Future<List<DirectoryFileResult>> crateApiConvertDirectory({required String inputDir , required String outputDir , required ConvertOptions options , required DirectoryFilter filter });
+
+Stream<ConvertProgress> crateApiConvertDirectoryWithProgress({required String inputDir , required String outputDir , required ConvertOptions options , required DirectoryFilter filter });
+
+Future<ConvertErrorDetails> crateApiConvertErrorDetailsDefault();
+
+Future<ConvertResult> crateApiConvertFile({required String inputPath , required String outputDir , required ConvertOptions options });
+
+Stream<ConvertProgress> crateApiConvertFileWithProgress({required String inputPath , required String outputDir , required ConvertOptions options });
+
+Future<List<ConvertResult>> crateApiConvertFiles({required List<String> inputPaths , required String outputDir , required ConvertOptions options });
+
+Stream<ConvertProgress> crateApiConvertFilesWithProgress({required List<String> inputPaths , required String outputDir , required ConvertOptions options });
+
+Future<ConvertOptions> crateApiConvertOptionsDefault();
+
+Future<ConvertResult> crateApiCreateContactSheet({required List<String> inputPaths , required String outputDir , required ConvertOptions options });
+
+Stream<ConvertProgress> crateApiCreateContactSheetWithProgress({required List<String> inputPaths , required String outputDir , required ConvertOptions options });
+
+Future<FileDetection> crateApiDetectFile({required String filePath });
+
+Future<FileType?> crateApiDetectFileType({required String filePath });
+
+Future<DirectoryFilter> crateApiDirectoryFilterDefault();
+
+Future<List<String>> crateApiGetSupportedOutputFormats({required FileType fileType });
+
+Future<List<String>> crateApiGetSupportedOutputFormatsForFile({required String filePath });
+
+Future<IccProfileMode> crateApiIccProfileModeDefault();
+
+Future<MetadataMode> crateApiMetadataModeDefault();
+
+Future<OnConflict> crateApiOnConflictDefault();
+
+Future<bool> crateApiOpenFolder({required String folderPath });
+
+Future<PdfPageSize> crateApiPdfPageSizeDefault();
+
+Future<PngFilter> crateApiPngFilterDefault();
+
+Future<ResizeFilter> crateApiResizeFilterDefault();
+
+Future<ResizeFit> crateApiResizeFitDefault();
+
+Future<TiffCompression> crateApiTiffCompressionDefault();
+
+Future<WatermarkPosition> crateApiWatermarkPositionDefault();
+
+
+                }
+                
+
+                class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
+                  RustLibApiImpl({
+                    required super.handler,
+                    required super.wire,
+                    required super.generalizedFrbRustBinding,
+                    required super.portManager,
+                  });
+
+                  @override Future<bool> crateApiCancelConversion({required String taskId })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(taskId, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 1, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_bool,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiCancelConversionConstMeta,
+            argValues: [taskId],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiCancelConversionConstMeta => const TaskConstMeta(
+            debugName: "cancel_conversion",
+            argNames: ["taskId"],
+        );
+        
+
+@override Future<ConvertResult> crateApiCombineImagesToPdf({required List<String> inputPaths , required String outputDir , required ConvertOptions options })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_list_String(inputPaths, serializer);
+sse_encode_String(outputDir, serializer);
+sse_encode_box_autoadd_convert_options(options, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 2, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_convert_result,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiCombineImagesToPdfConstMeta,
+            argValues: [inputPaths, outputDir, options],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiCombineImagesToPdfConstMeta => const TaskConstMeta(
+            debugName: "combine_images_to_pdf",
+            argNames: ["inputPaths", "outputDir", "options"],
+        );
+        
+
+@override Stream<ConvertProgress> crateApiCombineImagesToPdfWithProgress({required List<String> inputPaths , required String outputDir , required ConvertOptions options })  { 
+            final sink = RustStreamSink<ConvertProgress>();
+            unawaited(handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_list_String(inputPaths, serializer);
+sse_encode_String(outputDir, serializer);
+sse_encode_box_autoadd_convert_options(options, serializer);
+sse_encode_StreamSink_convert_progress_Sse(sink, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 3, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_convert_result,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiCombineImagesToPdfWithProgressConstMeta,
+            argValues: [inputPaths, outputDir, options, sink],
+            apiImpl: this,
+        )));
+            return sink.stream;
+             }
+
+
+        TaskConstMeta get kCrateApiCombineImagesToPdfWithProgressConstMeta => const TaskConstMeta(
+            debugName: "combine_images_to_pdf_with_progress",
+            argNames: ["inputPaths", "outputDir", "options", "sink"],
+        );
+        
+
+@override Future<List<DirectoryFileResult>> crateApiConvertDirectory({required String inputDir , required String outputDir , required ConvertOptions options , required DirectoryFilter filter })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(inputDir, serializer);
+sse_encode_String(outputDir, serializer);
+sse_encode_box_autoadd_convert_options(options, serializer);
+sse_encode_box_autoadd_directory_filter(filter, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 4, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_directory_file_result,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiConvertDirectoryConstMeta,
+            argValues: [inputDir, outputDir, options, filter],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiConvertDirectoryConstMeta => const TaskConstMeta(
+            debugName: "convert_directory",
+            argNames: ["inputDir", "outputDir", "options", "filter"],
+        );
+        
+
+@override Stream<ConvertProgress> crateApiConvertDirectoryWithProgress({required String inputDir , required String outputDir , required ConvertOptions options , required DirectoryFilter filter })  { 
+            final sink = RustStreamSink<ConvertProgress>();
+            unawaited(handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(inputDir, serializer);
+sse_encode_String(outputDir, serializer);
+sse_encode_box_autoadd_convert_options(options, serializer);
+sse_encode_box_autoadd_directory_filter(filter, serializer);
+sse_encode_StreamSink_convert_progress_Sse(sink, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 5, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_directory_file_result,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiConvertDirectoryWithProgressConstMeta,
+            argValues: [inputDir, outputDir, options, filter, sink],
+            apiImpl: this,
+        )));
+            return sink.stream;
+             }
+
+
+        TaskConstMeta get kCrateApiConvertDirectoryWithProgressConstMeta => const TaskConstMeta(
+            debugName: "convert_directory_with_progress",
+            argNames: ["inputDir", "outputDir", "options", "filter", "sink"],
+        );
+        
+
+@override Future<ConvertErrorDetails> crateApiConvertErrorDetailsDefault()  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 6, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_convert_error_details,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiConvertErrorDetailsDefaultConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiConvertErrorDetailsDefaultConstMeta => const TaskConstMeta(
+            debugName: "convert_error_details_default",
+            argNames: [],
+        );
+        
+
+@override Future<ConvertResult> crateApiConvertFile({required String inputPath , required String outputDir , required ConvertOptions options })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(inputPath, serializer);
+sse_encode_String(outputDir, serializer);
+sse_encode_box_autoadd_convert_options(options, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 7, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_convert_result,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiConvertFileConstMeta,
+            argValues: [inputPath, outputDir, options],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiConvertFileConstMeta => const TaskConstMeta(
+            debugName: "convert_file",
+            argNames: ["inputPath", "outputDir", "options"],
+        );
+        
+
+@override Stream<ConvertProgress> crateApiConvertFileWithProgress({required String inputPath , required String outputDir , required ConvertOptions options })  { 
+            final sink = RustStreamSink<ConvertProgress>();
+            unawaited(handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(inputPath, serializer);
+sse_encode_String(outputDir, serializer);
+sse_encode_box_autoadd_convert_options(options, serializer);
+sse_encode_StreamSink_convert_progress_Sse(sink, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 8, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_convert_result,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiConvertFileWithProgressConstMeta,
+            argValues: [inputPath, outputDir, options, sink],
+            apiImpl: this,
+        )));
+            return sink.stream;
+             }
+
+
+        TaskConstMeta get kCrateApiConvertFileWithProgressConstMeta => const TaskConstMeta(
+            debugName: "convert_file_with_progress",
+            argNames: ["inputPath", "outputDir", "options", "sink"],
+        );
+        
+
+@override Future<List<ConvertResult>> crateApiConvertFiles({required List<String> inputPaths , required String outputDir , required ConvertOptions options })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_list_String(inputPaths, serializer);
+sse_encode_String(outputDir, serializer);
+sse_encode_box_autoadd_convert_options(options, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 9, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_convert_result,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiConvertFilesConstMeta,
+            argValues: [inputPaths, outputDir, options],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiConvertFilesConstMeta => const TaskConstMeta(
+            debugName: "convert_files",
+            argNames: ["inputPaths", "outputDir", "options"],
+        );
+        
+
+@override Stream<ConvertProgress> crateApiConvertFilesWithProgress({required List<String> inputPaths , required String outputDir , required ConvertOptions options })  { 
+            final sink = RustStreamSink<ConvertProgress>();
+            unawaited(handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_list_String(inputPaths, serializer);
+sse_encode_String(outputDir, serializer);
+sse_encode_box_autoadd_convert_options(options, serializer);
+sse_encode_StreamSink_convert_progress_Sse(sink, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 10, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_convert_result,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiConvertFilesWithProgressConstMeta,
+            argValues: [inputPaths, outputDir, options, sink],
+            apiImpl: this,
+        )));
+            return sink.stream;
+             }
+
+
+        TaskConstMeta get kCrateApiConvertFilesWithProgressConstMeta => const TaskConstMeta(
+            debugName: "convert_files_with_progress",
+            argNames: ["inputPaths", "outputDir", "options", "sink"],
+        );
+        
+
+@override Future<ConvertOptions> crateApiConvertOptionsDefault()  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 11, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_convert_options,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiConvertOptionsDefaultConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiConvertOptionsDefaultConstMeta => const TaskConstMeta(
+            debugName: "convert_options_default",
+            argNames: [],
+        );
+        
+
+@override Future<ConvertResult> crateApiCreateContactSheet({required List<String> inputPaths , required String outputDir , required ConvertOptions options })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_list_String(inputPaths, serializer);
+sse_encode_String(outputDir, serializer);
+sse_encode_box_autoadd_convert_options(options, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 12, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_convert_result,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiCreateContactSheetConstMeta,
+            argValues: [inputPaths, outputDir, options],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiCreateContactSheetConstMeta => const TaskConstMeta(
+            debugName: "create_contact_sheet",
+            argNames: ["inputPaths", "outputDir", "options"],
+        );
+        
+
+@override Stream<ConvertProgress> crateApiCreateContactSheetWithProgress({required List<String> inputPaths , required String outputDir , required ConvertOptions options })  { 
+            final sink = RustStreamSink<ConvertProgress>();
+            unawaited(handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_list_String(inputPaths, serializer);
+sse_encode_String(outputDir, serializer);
+sse_encode_box_autoadd_convert_options(options, serializer);
+sse_encode_StreamSink_convert_progress_Sse(sink, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 13, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_convert_result,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiCreateContactSheetWithProgressConstMeta,
+            argValues: [inputPaths, outputDir, options, sink],
+            apiImpl: this,
+        )));
+            return sink.stream;
+             }
+
+
+        TaskConstMeta get kCrateApiCreateContactSheetWithProgressConstMeta => const TaskConstMeta(
+            debugName: "create_contact_sheet_with_progress",
+            argNames: ["inputPaths", "outputDir", "options", "sink"],
+        );
+        
+
+@override Future<FileDetection> crateApiDetectFile({required String filePath })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(filePath, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 14, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_file_detection,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiDetectFileConstMeta,
+            argValues: [filePath],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiDetectFileConstMeta => const TaskConstMeta(
+            debugName: "detect_file",
+            argNames: ["filePath"],
+        );
+        
+
+@override Future<FileType?> crateApiDetectFileType({required String filePath })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(filePath, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 15, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_opt_box_autoadd_file_type,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiDetectFileTypeConstMeta,
+            argValues: [filePath],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiDetectFileTypeConstMeta => const TaskConstMeta(
+            debugName: "detect_file_type",
+            argNames: ["filePath"],
+        );
+        
+
+@override Future<DirectoryFilter> crateApiDirectoryFilterDefault()  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 16, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_directory_filter,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiDirectoryFilterDefaultConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiDirectoryFilterDefaultConstMeta => const TaskConstMeta(
+            debugName: "directory_filter_default",
+            argNames: [],
+        );
+        
+
+@override Future<List<String>> crateApiGetSupportedOutputFormats({required FileType fileType })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_file_type(fileType, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 17, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_String,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiGetSupportedOutputFormatsConstMeta,
+            argValues: [fileType],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiGetSupportedOutputFormatsConstMeta => const TaskConstMeta(
+            debugName: "get_supported_output_formats",
+            argNames: ["fileType"],
+        );
+        
+
+@override Future<List<String>> crateApiGetSupportedOutputFormatsForFile({required String filePath })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(filePath, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 18, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_String,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiGetSupportedOutputFormatsForFileConstMeta,
+            argValues: [filePath],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiGetSupportedOutputFormatsForFileConstMeta => const TaskConstMeta(
+            debugName: "get_supported_output_formats_for_file",
+            argNames: ["filePath"],
+        );
+        
+
+@override Future<IccProfileMode> crateApiIccProfileModeDefault()  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 19, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_icc_profile_mode,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiIccProfileModeDefaultConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiIccProfileModeDefaultConstMeta => const TaskConstMeta(
+            debugName: "icc_profile_mode_default",
+            argNames: [],
+        );
+        
+
+@override Future<MetadataMode> crateApiMetadataModeDefault()  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 20, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_metadata_mode,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiMetadataModeDefaultConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiMetadataModeDefaultConstMeta => const TaskConstMeta(
+            debugName: "metadata_mode_default",
+            argNames: [],
+        );
+        
+
+@override Future<OnConflict> crateApiOnConflictDefault()  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 21, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_on_conflict,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiOnConflictDefaultConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiOnConflictDefaultConstMeta => const TaskConstMeta(
+            debugName: "on_conflict_default",
+            argNames: [],
+        );
+        
+
+@override Future<bool> crateApiOpenFolder({required String folderPath })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(folderPath, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 22, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_bool,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiOpenFolderConstMeta,
+            argValues: [folderPath],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiOpenFolderConstMeta => const TaskConstMeta(
+            debugName: "open_folder",
+            argNames: ["folderPath"],
+        );
+        
+
+@override Future<PdfPageSize> crateApiPdfPageSizeDefault()  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 23, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_pdf_page_size,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiPdfPageSizeDefaultConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiPdfPageSizeDefaultConstMeta => const TaskConstMeta(
+            debugName: "pdf_page_size_default",
+            argNames: [],
+        );
+        
+
+@override Future<PngFilter> crateApiPngFilterDefault()  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 24, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_png_filter,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiPngFilterDefaultConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiPngFilterDefaultConstMeta => const TaskConstMeta(
+            debugName: "png_filter_default",
+            argNames: [],
+        );
+        
+
+@override Future<ResizeFilter> crateApiResizeFilterDefault()  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 25, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_resize_filter,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiResizeFilterDefaultConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiResizeFilterDefaultConstMeta => const TaskConstMeta(
+            debugName: "resize_filter_default",
+            argNames: [],
+        );
+        
+
+@override Future<ResizeFit> crateApiResizeFitDefault()  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 26, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_resize_fit,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiResizeFitDefaultConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiResizeFitDefaultConstMeta => const TaskConstMeta(
+            debugName: "resize_fit_default",
+            argNames: [],
+        );
+        
+
+@override Future<TiffCompression> crateApiTiffCompressionDefault()  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 27, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_tiff_compression,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiTiffCompressionDefaultConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiTiffCompressionDefaultConstMeta => const TaskConstMeta(
+            debugName: "tiff_compression_default",
+            argNames: [],
+        );
+        
+
+@override Future<WatermarkPosition> crateApiWatermarkPositionDefault()  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 28, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_watermark_position,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiWatermarkPositionDefaultConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiWatermarkPositionDefaultConstMeta => const TaskConstMeta(
+            debugName: "watermark_position_default",
+            argNames: [],
+        );
+        
+
+
+
+                  @protected AnyhowException dco_decode_AnyhowException(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return AnyhowException(raw as String); }
+
+@protected RustStreamSink<ConvertProgress> dco_decode_StreamSink_convert_progress_Sse(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+throw UnimplementedError(); }
+
+@protected String dco_decode_String(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw as String; }
+
+@protected bool dco_decode_bool(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw as bool; }
+
+@protected bool dco_decode_box_autoadd_bool(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw as bool; }
+
+@protected ConvertErrorCode dco_decode_box_autoadd_convert_error_code(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_convert_error_code(raw); }
+
+@protected ConvertErrorDetails dco_decode_box_autoadd_convert_error_details(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_convert_error_details(raw); }
+
+@protected ConvertOptions dco_decode_box_autoadd_convert_options(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_convert_options(raw); }
+
+@protected CropRect dco_decode_box_autoadd_crop_rect(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_crop_rect(raw); }
+
+@protected DirectoryFilter dco_decode_box_autoadd_directory_filter(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_directory_filter(raw); }
+
+@protected double dco_decode_box_autoadd_f_64(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw as double; }
+
+@protected FileType dco_decode_box_autoadd_file_type(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_file_type(raw); }
+
+@protected Flip dco_decode_box_autoadd_flip(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_flip(raw); }
+
+@protected int dco_decode_box_autoadd_i_32(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw as int; }
+
+@protected IccProfileMode dco_decode_box_autoadd_icc_profile_mode(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_icc_profile_mode(raw); }
+
+@protected MetadataMode dco_decode_box_autoadd_metadata_mode(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_metadata_mode(raw); }
+
+@protected OnConflict dco_decode_box_autoadd_on_conflict(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_on_conflict(raw); }
+
+@protected PdfPageSize dco_decode_box_autoadd_pdf_page_size(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_pdf_page_size(raw); }
+
+@protected PngFilter dco_decode_box_autoadd_png_filter(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_png_filter(raw); }
+
+@protected ResizeFilter dco_decode_box_autoadd_resize_filter(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_resize_filter(raw); }
+
+@protected ResizeFit dco_decode_box_autoadd_resize_fit(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_resize_fit(raw); }
+
+@protected TiffCompression dco_decode_box_autoadd_tiff_compression(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_tiff_compression(raw); }
+
+@protected WatermarkPosition dco_decode_box_autoadd_watermark_position(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_watermark_position(raw); }
+
+@protected ConvertErrorCode dco_decode_convert_error_code(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return ConvertErrorCode.values[raw as int]; }
+
+@protected ConvertErrorDetails dco_decode_convert_error_details(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 5) throw Exception('unexpected arr length: expect 5 but see ${arr.length}');
+                return ConvertErrorDetails(tool: dco_decode_opt_String(arr[0]),
+from: dco_decode_opt_String(arr[1]),
+to: dco_decode_opt_String(arr[2]),
+exitCode: dco_decode_opt_box_autoadd_i_32(arr[3]),
+stderr: dco_decode_opt_String(arr[4]),); }
+
+@protected ConvertOptions dco_decode_convert_options(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 70) throw Exception('unexpected arr length: expect 70 but see ${arr.length}');
+                return ConvertOptions(outputFormat: dco_decode_String(arr[0]),
+imageQuality: dco_decode_opt_box_autoadd_i_32(arr[1]),
+audioQuality: dco_decode_opt_box_autoadd_i_32(arr[2]),
+audioBitrate: dco_decode_opt_box_autoadd_i_32(arr[3]),
+audioSampleRate: dco_decode_opt_box_autoadd_i_32(arr[4]),
+videoCrf: dco_decode_opt_box_autoadd_i_32(arr[5]),
+videoBitrate: dco_decode_opt_box_autoadd_i_32(arr[6]),
+videoWidth: dco_decode_opt_box_autoadd_i_32(arr[7]),
+videoHeight: dco_decode_opt_box_autoadd_i_32(arr[8]),
+ffmpegPath: dco_decode_opt_String(arr[9]),
+taskId: dco_decode_opt_String(arr[10]),
+maxParallel: dco_decode_opt_box_autoadd_i_32(arr[11]),
+maxParallelExternal: dco_decode_opt_box_autoadd_i_32(arr[12]),
+onConflict: dco_decode_opt_box_autoadd_on_conflict(arr[13]),
+imageWidth: dco_decode_opt_box_autoadd_i_32(arr[14]),
+imageHeight: dco_decode_opt_box_autoadd_i_32(arr[15]),
+resizeFit: dco_decode_opt_box_autoadd_resize_fit(arr[16]),
+resizeFilter: dco_decode_opt_box_autoadd_resize_filter(arr[17]),
+crop: dco_decode_opt_box_autoadd_crop_rect(arr[18]),
+rotate: dco_decode_opt_box_autoadd_i_32(arr[19]),
+flip: dco_decode_opt_box_autoadd_flip(arr[20]),
+metadata: dco_decode_opt_box_autoadd_metadata_mode(arr[21]),
+svgDpi: dco_decode_opt_box_autoadd_i_32(arr[22]),
+background: dco_decode_opt_String(arr[23]),
+svgFontFamily: dco_decode_opt_String(arr[24]),
+frame: dco_decode_opt_box_autoadd_i_32(arr[25]),
+extractFrames: dco_decode_opt_box_autoadd_bool(arr[26]),
+icoSizes: dco_decode_opt_list_prim_i_32_strict(arr[27]),
+icoSize: dco_decode_opt_box_autoadd_i_32(arr[28]),
+webpLossless: dco_decode_opt_box_autoadd_bool(arr[29]),
+webpAlphaQuality: dco_decode_opt_box_autoadd_i_32(arr[30]),
+pngCompression: dco_decode_opt_box_autoadd_i_32(arr[31]),
+pngFilter: dco_decode_opt_box_autoadd_png_filter(arr[32]),
+pngColors: dco_decode_opt_box_autoadd_i_32(arr[33]),
+pngDither: dco_decode_opt_box_autoadd_bool(arr[34]),
+pngOptimize: dco_decode_opt_box_autoadd_bool(arr[35]),
+avifSpeed: dco_decode_opt_box_autoadd_i_32(arr[36]),
+tiffCompression: dco_decode_opt_box_autoadd_tiff_compression(arr[37]),
+targetSizeBytes: dco_decode_opt_box_autoadd_i_32(arr[38]),
+targetSizeDownscale: dco_decode_opt_box_autoadd_bool(arr[39]),
+pdfPageSize: dco_decode_opt_box_autoadd_pdf_page_size(arr[40]),
+pdfMarginMm: dco_decode_opt_box_autoadd_i_32(arr[41]),
+pdfFit: dco_decode_opt_box_autoadd_resize_fit(arr[42]),
+pdfJpegQuality: dco_decode_opt_box_autoadd_i_32(arr[43]),
+watermarkText: dco_decode_opt_String(arr[44]),
+watermarkImage: dco_decode_opt_String(arr[45]),
+watermarkFontFamily: dco_decode_opt_String(arr[46]),
+watermarkFontSize: dco_decode_opt_box_autoadd_i_32(arr[47]),
+watermarkImageWidth: dco_decode_opt_box_autoadd_i_32(arr[48]),
+watermarkColor: dco_decode_opt_String(arr[49]),
+watermarkOpacity: dco_decode_opt_box_autoadd_i_32(arr[50]),
+watermarkPosition: dco_decode_opt_box_autoadd_watermark_position(arr[51]),
+watermarkMargin: dco_decode_opt_box_autoadd_i_32(arr[52]),
+watermarkTile: dco_decode_opt_box_autoadd_bool(arr[53]),
+grayscale: dco_decode_opt_box_autoadd_bool(arr[54]),
+brightness: dco_decode_opt_box_autoadd_i_32(arr[55]),
+contrast: dco_decode_opt_box_autoadd_i_32(arr[56]),
+hueRotate: dco_decode_opt_box_autoadd_i_32(arr[57]),
+gamma: dco_decode_opt_box_autoadd_f_64(arr[58]),
+invert: dco_decode_opt_box_autoadd_bool(arr[59]),
+blur: dco_decode_opt_box_autoadd_f_64(arr[60]),
+sharpen: dco_decode_opt_box_autoadd_f_64(arr[61]),
+sharpenThreshold: dco_decode_opt_box_autoadd_i_32(arr[62]),
+sheetColumns: dco_decode_opt_box_autoadd_i_32(arr[63]),
+sheetCellWidth: dco_decode_opt_box_autoadd_i_32(arr[64]),
+sheetCellHeight: dco_decode_opt_box_autoadd_i_32(arr[65]),
+sheetSpacing: dco_decode_opt_box_autoadd_i_32(arr[66]),
+sheetBackground: dco_decode_opt_String(arr[67]),
+sheetCaptions: dco_decode_opt_box_autoadd_bool(arr[68]),
+iccProfile: dco_decode_opt_box_autoadd_icc_profile_mode(arr[69]),); }
+
+@protected ConvertProgress dco_decode_convert_progress(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 6) throw Exception('unexpected arr length: expect 6 but see ${arr.length}');
+                return ConvertProgress(taskId: dco_decode_String(arr[0]),
+inputPath: dco_decode_String(arr[1]),
+fileIndex: dco_decode_i_32(arr[2]),
+fileCount: dco_decode_i_32(arr[3]),
+progress: dco_decode_i_32(arr[4]),
+status: dco_decode_String(arr[5]),); }
+
+@protected ConvertResult dco_decode_convert_result(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 10) throw Exception('unexpected arr length: expect 10 but see ${arr.length}');
+                return ConvertResult(success: dco_decode_bool(arr[0]),
+outputPath: dco_decode_opt_String(arr[1]),
+error: dco_decode_opt_String(arr[2]),
+errorCode: dco_decode_opt_box_autoadd_convert_error_code(arr[3]),
+errorDetails: dco_decode_opt_box_autoadd_convert_error_details(arr[4]),
+skipped: dco_decode_bool(arr[5]),
+route: dco_decode_list_String(arr[6]),
+outputPaths: dco_decode_list_String(arr[7]),
+warnings: dco_decode_list_String(arr[8]),
+imageQuality: dco_decode_opt_box_autoadd_i_32(arr[9]),); }
+
+@protected CropRect dco_decode_crop_rect(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 4) throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
+                return CropRect(x: dco_decode_i_32(arr[0]),
+y: dco_decode_i_32(arr[1]),
+width: dco_decode_i_32(arr[2]),
+height: dco_decode_i_32(arr[3]),); }
+
+@protected DetectionConfidence dco_decode_detection_confidence(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return DetectionConfidence.values[raw as int]; }
+
+@protected DirectoryFileResult dco_decode_directory_file_result(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 3) throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+                return DirectoryFileResult(inputPath: dco_decode_String(arr[0]),
+relativePath: dco_decode_String(arr[1]),
+result: dco_decode_convert_result(arr[2]),); }
+
+@protected DirectoryFilter dco_decode_directory_filter(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 3) throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+                return DirectoryFilter(include: dco_decode_list_String(arr[0]),
+exclude: dco_decode_list_String(arr[1]),
+fileTypes: dco_decode_list_file_type(arr[2]),); }
+
+@protected double dco_decode_f_64(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw as double; }
+
+@protected FileDetection dco_decode_file_detection(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 5) throw Exception('unexpected arr length: expect 5 but see ${arr.length}');
+                return FileDetection(fileType: dco_decode_opt_box_autoadd_file_type(arr[0]),
+format: dco_decode_opt_String(arr[1]),
+extension_: dco_decode_opt_String(arr[2]),
+confidence: dco_decode_detection_confidence(arr[3]),
+extensionMismatch: dco_decode_bool(arr[4]),); }
+
+@protected FileType dco_decode_file_type(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return FileType.values[raw as int]; }
+
+@protected Flip dco_decode_flip(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return Flip.values[raw as int]; }
+
+@protected int dco_decode_i_32(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw as int; }
+
+@protected IccProfileMode dco_decode_icc_profile_mode(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return IccProfileMode.values[raw as int]; }
+
+@protected List<String> dco_decode_list_String(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_String).toList(); }
+
+@protected List<ConvertResult> dco_decode_list_convert_result(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_convert_result).toList(); }
+
+@protected List<DirectoryFileResult> dco_decode_list_directory_file_result(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_directory_file_result).toList(); }
+
+@protected List<FileType> dco_decode_list_file_type(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_file_type).toList(); }
+
+@protected Int32List dco_decode_list_prim_i_32_strict(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw as Int32List; }
+
+@protected Uint8List dco_decode_list_prim_u_8_strict(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw as Uint8List; }
+
+@protected MetadataMode dco_decode_metadata_mode(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return MetadataMode.values[raw as int]; }
+
+@protected OnConflict dco_decode_on_conflict(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return OnConflict.values[raw as int]; }
+
+@protected String? dco_decode_opt_String(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw == null ? null : dco_decode_String(raw); }
+
+@protected bool? dco_decode_opt_box_autoadd_bool(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw == null ? null : dco_decode_box_autoadd_bool(raw); }
+
+@protected ConvertErrorCode? dco_decode_opt_box_autoadd_convert_error_code(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw == null ? null : dco_decode_box_autoadd_convert_error_code(raw); }
+
+@protected ConvertErrorDetails? dco_decode_opt_box_autoadd_convert_error_details(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw == null ? null : dco_decode_box_autoadd_convert_error_details(raw); }
+
+@protected CropRect? dco_decode_opt_box_autoadd_crop_rect(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw == null ? null : dco_decode_box_autoadd_crop_rect(raw); }
+
+@protected double? dco_decode_opt_box_autoadd_f_64(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw == null ? null : dco_decode_box_autoadd_f_64(raw); }
+
+@protected FileType? dco_decode_opt_box_autoadd_file_type(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw == null ? null : dco_decode_box_autoadd_file_type(raw); }
+
+@protected Flip? dco_decode_opt_box_autoadd_flip(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw == null ? null : dco_decode_box_autoadd_flip(raw); }
+
+@protected int? dco_decode_opt_box_autoadd_i_32(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw == null ? null : dco_decode_box_autoadd_i_32(raw); }
+
+@protected IccProfileMode? dco_decode_opt_box_autoadd_icc_profile_mode(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw == null ? null : dco_decode_box_autoadd_icc_profile_mode(raw); }
+
+@protected MetadataMode? dco_decode_opt_box_autoadd_metadata_mode(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw == null ? null : dco_decode_box_autoadd_metadata_mode(raw); }
+
+@protected OnConflict? dco_decode_opt_box_autoadd_on_conflict(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw == null ? null : dco_decode_box_autoadd_on_conflict(raw); }
+
+@protected PdfPageSize? dco_decode_opt_box_autoadd_pdf_page_size(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw == null ? null : dco_decode_box_autoadd_pdf_page_size(raw); }
+
+@protected PngFilter? dco_decode_opt_box_autoadd_png_filter(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw == null ? null : dco_decode_box_autoadd_png_filter(raw); }
+
+@protected ResizeFilter? dco_decode_opt_box_autoadd_resize_filter(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw == null ? null : dco_decode_box_autoadd_resize_filter(raw); }
+
+@protected ResizeFit? dco_decode_opt_box_autoadd_resize_fit(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw == null ? null : dco_decode_box_autoadd_resize_fit(raw); }
+
+@protected TiffCompression? dco_decode_opt_box_autoadd_tiff_compression(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw == null ? null : dco_decode_box_autoadd_tiff_compression(raw); }
+
+@protected WatermarkPosition? dco_decode_opt_box_autoadd_watermark_position(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw == null ? null : dco_decode_box_autoadd_watermark_position(raw); }
+
+@protected Int32List? dco_decode_opt_list_prim_i_32_strict(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw == null ? null : dco_decode_list_prim_i_32_strict(raw); }
+
+@protected PdfPageSize dco_decode_pdf_page_size(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return PdfPageSize.values[raw as int]; }
+
+@protected PngFilter dco_decode_png_filter(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return PngFilter.values[raw as int]; }
+
+@protected ResizeFilter dco_decode_resize_filter(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return ResizeFilter.values[raw as int]; }
+
+@protected ResizeFit dco_decode_resize_fit(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return ResizeFit.values[raw as int]; }
+
+@protected TiffCompression dco_decode_tiff_compression(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return TiffCompression.values[raw as int]; }
+
+@protected int dco_decode_u_8(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw as int; }
+
+@protected void dco_decode_unit(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return; }
+
+@protected WatermarkPosition dco_decode_watermark_position(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return WatermarkPosition.values[raw as int]; }
+
+@protected AnyhowException sse_decode_AnyhowException(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var inner = sse_decode_String(deserializer);
+        return AnyhowException(inner); }
+
+@protected RustStreamSink<ConvertProgress> sse_decode_StreamSink_convert_progress_Sse(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+throw UnimplementedError('Unreachable ()'); }
+
+@protected String sse_decode_String(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var inner = sse_decode_list_prim_u_8_strict(deserializer);
+        return utf8.decoder.convert(inner); }
+
+@protected bool sse_decode_bool(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return deserializer.buffer.getUint8() != 0; }
+
+@protected bool sse_decode_box_autoadd_bool(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_bool(deserializer)); }
+
+@protected ConvertErrorCode sse_decode_box_autoadd_convert_error_code(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_convert_error_code(deserializer)); }
+
+@protected ConvertErrorDetails sse_decode_box_autoadd_convert_error_details(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_convert_error_details(deserializer)); }
+
+@protected ConvertOptions sse_decode_box_autoadd_convert_options(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_convert_options(deserializer)); }
+
+@protected CropRect sse_decode_box_autoadd_crop_rect(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_crop_rect(deserializer)); }
+
+@protected DirectoryFilter sse_decode_box_autoadd_directory_filter(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_directory_filter(deserializer)); }
+
+@protected double sse_decode_box_autoadd_f_64(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_f_64(deserializer)); }
+
+@protected FileType sse_decode_box_autoadd_file_type(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_file_type(deserializer)); }
+
+@protected Flip sse_decode_box_autoadd_flip(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_flip(deserializer)); }
+
+@protected int sse_decode_box_autoadd_i_32(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_i_32(deserializer)); }
+
+@protected IccProfileMode sse_decode_box_autoadd_icc_profile_mode(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_icc_profile_mode(deserializer)); }
+
+@protected MetadataMode sse_decode_box_autoadd_metadata_mode(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_metadata_mode(deserializer)); }
+
+@protected OnConflict sse_decode_box_autoadd_on_conflict(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_on_conflict(deserializer)); }
+
+@protected PdfPageSize sse_decode_box_autoadd_pdf_page_size(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_pdf_page_size(deserializer)); }
+
+@protected PngFilter sse_decode_box_autoadd_png_filter(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_png_filter(deserializer)); }
+
+@protected ResizeFilter sse_decode_box_autoadd_resize_filter(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_resize_filter(deserializer)); }
+
+@protected ResizeFit sse_decode_box_autoadd_resize_fit(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_resize_fit(deserializer)); }
+
+@protected TiffCompression sse_decode_box_autoadd_tiff_compression(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_tiff_compression(deserializer)); }
+
+@protected WatermarkPosition sse_decode_box_autoadd_watermark_position(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_watermark_position(deserializer)); }
+
+@protected ConvertErrorCode sse_decode_convert_error_code(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var inner = sse_decode_i_32(deserializer);
+        return ConvertErrorCode.values[inner]; }
+
+@protected ConvertErrorDetails sse_decode_convert_error_details(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_tool = sse_decode_opt_String(deserializer);
+var var_from = sse_decode_opt_String(deserializer);
+var var_to = sse_decode_opt_String(deserializer);
+var var_exitCode = sse_decode_opt_box_autoadd_i_32(deserializer);
+var var_stderr = sse_decode_opt_String(deserializer);
+return ConvertErrorDetails(tool: var_tool, from: var_from, to: var_to, exitCode: var_exitCode, stderr: var_stderr); }
+
+@protected ConvertOptions sse_decode_convert_options(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_outputFormat = sse_decode_String(deserializer);
+var var_imageQuality = sse_decode_opt_box_autoadd_i_32(deserializer);
+var var_audioQuality = sse_decode_opt_box_autoadd_i_32(deserializer);
+var var_audioBitrate = sse_decode_opt_box_autoadd_i_32(deserializer);
+var var_audioSampleRate = sse_decode_opt_box_autoadd_i_32(deserializer);
+var var_videoCrf = sse_decode_opt_box_autoadd_i_32(deserializer);
+var var_videoBitrate = sse_decode_opt_box_autoadd_i_32(deserializer);
+var var_videoWidth = sse_decode_opt_box_autoadd_i_32(deserializer);
+var var_videoHeight = sse_decode_opt_box_autoadd_i_32(deserializer);
+var var_ffmpegPath = sse_decode_opt_String(deserializer);
+var var_taskId = sse_decode_opt_String(deserializer);
+var var_maxParallel = sse_decode_opt_box_autoadd_i_32(deserializer);
+var var_maxParallelExternal = sse_decode_opt_box_autoadd_i_32(deserializer);
+var var_onConflict = sse_decode_opt_box_autoadd_on_conflict(deserializer);
+var var_imageWidth = sse_decode_opt_box_autoadd_i_32(deserializer);
+var var_imageHeight = sse_decode_opt_box_autoadd_i_32(deserializer);
+var var_resizeFit = sse_decode_opt_box_autoadd_resize_fit(deserializer);
+var var_resizeFilter = sse_decode_opt_box_autoadd_resize_filter(deserializer);
+var var_crop = sse_decode_opt_box_autoadd_crop_rect(deserializer);
+var var_rotate = sse_decode_opt_box_autoadd_i_32(deserializer);
+var var_flip = sse_decode_opt_box_autoadd_flip(deserializer);
+var var_metadata = sse_decode_opt_box_autoadd_metadata_mode(deserializer);
+var var_svgDpi = sse_decode_opt_box_autoadd_i_32(deserializer);
+var var_background = sse_decode_opt_String(deserializer);
+var var_svgFontFamily = sse_decode_opt_String(deserializer);
+var var_frame = sse_decode_opt_box_autoadd_i_32(deserializer);
+var var_extractFrames = sse_decode_opt_box_autoadd_bool(deserializer);
+var var_icoSizes = sse_decode_opt_list_prim_i_32_strict(deserializer);
+var var_icoSize = sse_decode_opt_box_autoadd_i_32(deserializer);
+var var_webpLossless = sse_decode_opt_box_autoadd_bool(deserializer);
+var var_webpAlphaQuality = sse_decode_opt_box_autoadd_i_32(deserializer);
+var var_pngCompression = sse_decode_opt_box_autoadd_i_32(deserializer);
+var var_pngFilter = sse_decode_opt_box_autoadd_png_filter(deserializer);
+var var_pngColors = sse_decode_opt_box_autoadd_i_32(deserializer);
+var var_pngDither = sse_decode_opt_box_autoadd_bool(deserializer);
+var var_pngOptimize = sse_decode_opt_box_autoadd_bool(deserializer);
+var var_avifSpeed = sse_decode_opt_box_autoadd_i_32(deserializer);
+var var_tiffCompression = sse_decode_opt_box_autoadd_tiff_compression(deserializer);
+var var_targetSizeBytes = sse_decode_opt_box_autoadd_i_32(deserializer);
+var var_targetSizeDownscale = sse_decode_opt_box_autoadd_bool(deserializer);
+var var_pdfPageSize = sse_decode_opt_box_autoadd_pdf_page_size(deserializer);
+var var_pdfMarginMm = sse_decode_opt_box_autoadd_i_32(deserializer);
+var var_pdfFit = sse_decode_opt_box_autoadd_resize_fit(deserializer);
+var var_pdfJpegQuality = sse_decode_opt_box_autoadd_i_32(deserializer);
+var var_watermarkText = sse_decode_opt_String(deserializer);
+var var_watermarkImage = sse_decode_opt_String(deserializer);
+var var_watermarkFontFamily = sse_decode_opt_String(deserializer);
+var var_watermarkFontSize = sse_decode_opt_box_autoadd_i_32(deserializer);
+var var_watermarkImageWidth = sse_decode_opt_box_autoadd_i_32(deserializer);
+var var_watermarkColor = sse_decode_opt_String(deserializer);
+var var_watermarkOpacity = sse_decode_opt_box_autoadd_i_32(deserializer);
+var var_watermarkPosition = sse_decode_opt_box_autoadd_watermark_position(deserializer);
+var var_watermarkMargin = sse_decode_opt_box_autoadd_i_32(deserializer);
+var var_watermarkTile = sse_decode_opt_box_autoadd_bool(deserializer);
+var var_grayscale = sse_decode_opt_box_autoadd_bool(deserializer);
+var var_brightness = sse_decode_opt_box_autoadd_i_32(deserializer);
+var var_contrast = sse_decode_opt_box_autoadd_i_32(deserializer);
+var var_hueRotate = sse_decode_opt_box_autoadd_i_32(deserializer);
+var var_gamma = sse_decode_opt_box_autoadd_f_64(deserializer);
+var var_invert = sse_decode_opt_box_autoadd_bool(deserializer);
+var var_blur = sse_decode_opt_box_autoadd_f_64(deserializer);
+var var_sharpen = sse_decode_opt_box_autoadd_f_64(deserializer);
+var var_sharpenThreshold = sse_decode_opt_box_autoadd_i_32(deserializer);
+var var_sheetColumns = sse_decode_opt_box_autoadd_i_32(deserializer);
+var var_sheetCellWidth = sse_decode_opt_box_autoadd_i_32(deserializer);
+var var_sheetCellHeight = sse_decode_opt_box_autoadd_i_32(deserializer);
+var var_sheetSpacing = sse_decode_opt_box_autoadd_i_32(deserializer);
+var var_sheetBackground = sse_decode_opt_String(deserializer);
+var var_sheetCaptions = sse_decode_opt_box_autoadd_bool(deserializer);
+var var_iccProfile = sse_decode_opt_box_autoadd_icc_profile_mode(deserializer);
+return ConvertOptions(outputFormat: var_outputFormat, imageQuality: var_imageQuality, audioQuality: var_audioQuality, audioBitrate: var_audioBitrate, audioSampleRate: var_audioSampleRate, videoCrf: var_videoCrf, videoBitrate: var_videoBitrate, videoWidth: var_videoWidth, videoHeight: var_videoHeight, ffmpegPath: var_ffmpegPath, taskId: var_taskId, maxParallel: var_maxParallel, maxParallelExternal: var_maxParallelExternal, onConflict: var_onConflict, imageWidth: var_imageWidth, imageHeight: var_imageHeight, resizeFit: var_resizeFit, resizeFilter: var_resizeFilter, crop: var_crop, rotate: var_rotate, flip: var_flip, metadata: var_metadata, svgDpi: var_svgDpi, background: var_background, svgFontFamily: var_svgFontFamily, frame: var_frame, extractFrames: var_extractFrames, icoSizes: var_icoSizes, icoSize: var_icoSize, webpLossless: var_webpLossless, webpAlphaQuality: var_webpAlphaQuality, pngCompression: var_pngCompression, pngFilter: var_pngFilter, pngColors: var_pngColors, pngDither: var_pngDither, pngOptimize: var_pngOptimize, avifSpeed: var_avifSpeed, tiffCompression: var_tiffCompression, targetSizeBytes: var_targetSizeBytes, targetSizeDownscale: var_targetSizeDownscale, pdfPageSize: var_pdfPageSize, pdfMarginMm: var_pdfMarginMm, pdfFit: var_pdfFit, pdfJpegQuality: var_pdfJpegQuality, watermarkText: var_watermarkText, watermarkImage: var_watermarkImage, watermarkFontFamily: var_watermarkFontFamily, watermarkFontSize: var_watermarkFontSize, watermarkImageWidth: var_watermarkImageWidth, watermarkColor: var_watermarkColor, watermarkOpacity: var_watermarkOpacity, watermarkPosition: var_watermarkPosition, watermarkMargin: var_watermarkMargin, watermarkTile: var_watermarkTile, grayscale: var_grayscale, brightness: var_brightness, contrast: var_contrast, hueRotate: var_hueRotate, gamma: var_gamma, invert: var_invert, blur: var_blur, sharpen: var_sharpen, sharpenThreshold: var_sharpenThreshold, sheetColumns: var_sheetColumns, sheetCellWidth: var_sheetCellWidth, sheetCellHeight: var_sheetCellHeight, sheetSpacing: var_sheetSpacing, sheetBackground: var_sheetBackground, sheetCaptions: var_sheetCaptions, iccProfile: var_iccProfile); }
+
+@protected ConvertProgress sse_decode_convert_progress(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_taskId = sse_decode_String(deserializer);
+var var_inputPath = sse_decode_String(deserializer);
+var var_fileIndex = sse_decode_i_32(deserializer);
+var var_fileCount = sse_decode_i_32(deserializer);
+var var_progress = sse_decode_i_32(deserializer);
+var var_status = sse_decode_String(deserializer);
+return ConvertProgress(taskId: var_taskId, inputPath: var_inputPath, fileIndex: var_fileIndex, fileCount: var_fileCount, progress: var_progress, status: var_status); }
+
+@protected ConvertResult sse_decode_convert_result(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_success = sse_decode_bool(deserializer);
+var var_outputPath = sse_decode_opt_String(deserializer);
+var var_error = sse_decode_opt_String(deserializer);
+var var_errorCode = sse_decode_opt_box_autoadd_convert_error_code(deserializer);
+var var_errorDetails = sse_decode_opt_box_autoadd_convert_error_details(deserializer);
+var var_skipped = sse_decode_bool(deserializer);
+var var_route = sse_decode_list_String(deserializer);
+var var_outputPaths = sse_decode_list_String(deserializer);
+var var_warnings = sse_decode_list_String(deserializer);
+var var_imageQuality = sse_decode_opt_box_autoadd_i_32(deserializer);
+return ConvertResult(success: var_success, outputPath: var_outputPath, error: var_error, errorCode: var_errorCode, errorDetails: var_errorDetails, skipped: var_skipped, route: var_route, outputPaths: var_outputPaths, warnings: var_warnings, imageQuality: var_imageQuality); }
+
+@protected CropRect sse_decode_crop_rect(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_x = sse_decode_i_32(deserializer);
+var var_y = sse_decode_i_32(deserializer);
+var var_width = sse_decode_i_32(deserializer);
+var var_height = sse_decode_i_32(deserializer);
+return CropRect(x: var_x, y: var_y, width: var_width, height: var_height); }
+
+@protected DetectionConfidence sse_decode_detection_confidence(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var inner = sse_decode_i_32(deserializer);
+        return DetectionConfidence.values[inner]; }
+
+@protected DirectoryFileResult sse_decode_directory_file_result(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_inputPath = sse_decode_String(deserializer);
+var var_relativePath = sse_decode_String(deserializer);
+var var_result = sse_decode_convert_result(deserializer);
+return DirectoryFileResult(inputPath: var_inputPath, relativePath: var_relativePath, result: var_result); }
+
+@protected DirectoryFilter sse_decode_directory_filter(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_include = sse_decode_list_String(deserializer);
+var var_exclude = sse_decode_list_String(deserializer);
+var var_fileTypes = sse_decode_list_file_type(deserializer);
+return DirectoryFilter(include: var_include, exclude: var_exclude, fileTypes: var_fileTypes); }
+
+@protected double sse_decode_f_64(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return deserializer.buffer.getFloat64(); }
+
+@protected FileDetection sse_decode_file_detection(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_fileType = sse_decode_opt_box_autoadd_file_type(deserializer);
+var var_format = sse_decode_opt_String(deserializer);
+var var_extension_ = sse_decode_opt_String(deserializer);
+var var_confidence = sse_decode_detection_confidence(deserializer);
+var var_extensionMismatch = sse_decode_bool(deserializer);
+return FileDetection(fileType: var_fileType, format: var_format, extension_: var_extension_, confidence: var_confidence, extensionMismatch: var_extensionMismatch); }
+
+@protected FileType sse_decode_file_type(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var inner = sse_decode_i_32(deserializer);
+        return FileType.values[inner]; }
+
+@protected Flip sse_decode_flip(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var inner = sse_decode_i_32(deserializer);
+        return Flip.values[inner]; }
+
+@protected int sse_decode_i_32(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return deserializer.buffer.getInt32(); }
+
+@protected IccProfileMode sse_decode_icc_profile_mode(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var inner = sse_decode_i_32(deserializer);
+        return IccProfileMode.values[inner]; }
+
+@protected List<String> sse_decode_list_String(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <String>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_String(deserializer)); }
+        return ans_;
+         }
+
+@protected List<ConvertResult> sse_decode_list_convert_result(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <ConvertResult>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_convert_result(deserializer)); }
+        return ans_;
+         }
+
+@protected List<DirectoryFileResult> sse_decode_list_directory_file_result(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <DirectoryFileResult>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_directory_file_result(deserializer)); }
+        return ans_;
+         }
+
+@protected List<FileType> sse_decode_list_file_type(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <FileType>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_file_type(deserializer)); }
+        return ans_;
+         }
+
+@protected Int32List sse_decode_list_prim_i_32_strict(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var len_ = sse_decode_i_32(deserializer);
+                return deserializer.buffer.getInt32List(len_); }
+
+@protected Uint8List sse_decode_list_prim_u_8_strict(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var len_ = sse_decode_i_32(deserializer);
+                return deserializer.buffer.getUint8List(len_); }
+
+@protected MetadataMode sse_decode_metadata_mode(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var inner = sse_decode_i_32(deserializer);
+        return MetadataMode.values[inner]; }
+
+@protected OnConflict sse_decode_on_conflict(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var inner = sse_decode_i_32(deserializer);
+        return OnConflict.values[inner]; }
+
+@protected String? sse_decode_opt_String(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+            if (sse_decode_bool(deserializer)) {
+                return (sse_decode_String(deserializer));
+            } else {
+                return null;
+            }
+             }
+
+@protected bool? sse_decode_opt_box_autoadd_bool(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+            if (sse_decode_bool(deserializer)) {
+                return (sse_decode_box_autoadd_bool(deserializer));
+            } else {
+                return null;
+            }
+             }
+
+@protected ConvertErrorCode? sse_decode_opt_box_autoadd_convert_error_code(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+            if (sse_decode_bool(deserializer)) {
+                return (sse_decode_box_autoadd_convert_error_code(deserializer));
+            } else {
+                return null;
+            }
+             }
+
+@protected ConvertErrorDetails? sse_decode_opt_box_autoadd_convert_error_details(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+            if (sse_decode_bool(deserializer)) {
+                return (sse_decode_box_autoadd_convert_error_details(deserializer));
+            } else {
+                return null;
+            }
+             }
+
+@protected CropRect? sse_decode_opt_box_autoadd_crop_rect(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+            if (sse_decode_bool(deserializer)) {
+                return (sse_decode_box_autoadd_crop_rect(deserializer));
+            } else {
+                return null;
+            }
+             }
+
+@protected double? sse_decode_opt_box_autoadd_f_64(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+            if (sse_decode_bool(deserializer)) {
+                return (sse_decode_box_autoadd_f_64(deserializer));
+            } else {
+                return null;
+            }
+             }
+
+@protected FileType? sse_decode_opt_box_autoadd_file_type(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+            if (sse_decode_bool(deserializer)) {
+                return (sse_decode_box_autoadd_file_type(deserializer));
+            } else {
+                return null;
+            }
+             }
+
+@protected Flip? sse_decode_opt_box_autoadd_flip(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+            if (sse_decode_bool(deserializer)) {
+                return (sse_decode_box_autoadd_flip(deserializer));
+            } else {
+                return null;
+            }
+             }
+
+@protected int? sse_decode_opt_box_autoadd_i_32(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+            if (sse_decode_bool(deserializer)) {
+                return (sse_decode_box_autoadd_i_32(deserializer));
+            } else {
+                return null;
+            }
+             }
+
+@protected IccProfileMode? sse_decode_opt_box_autoadd_icc_profile_mode(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+            if (sse_decode_bool(deserializer)) {
+                return (sse_decode_box_autoadd_icc_profile_mode(deserializer));
+            } else {
+                return null;
+            }
+             }
+
+@protected MetadataMode? sse_decode_opt_box_autoadd_metadata_mode(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+            if (sse_decode_bool(deserializer)) {
+                return (sse_decode_box_autoadd_metadata_mode(deserializer));
+            } else {
+                return null;
+            }
+             }
+
+@protected OnConflict? sse_decode_opt_box_autoadd_on_conflict(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+            if (sse_decode_bool(deserializer)) {
+                return (sse_decode_box_autoadd_on_conflict(deserializer));
+            } else {
+                return null;
+            }
+             }
+
+@protected PdfPageSize? sse_decode_opt_box_autoadd_pdf_page_size(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+            if (sse_decode_bool(deserializer)) {
+                return (sse_decode_box_autoadd_pdf_page_size(deserializer));
+            } else {
+                return null;
+            }
+             }
+
+@protected PngFilter? sse_decode_opt_box_autoadd_png_filter(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+            if (sse_decode_bool(deserializer)) {
+                return (sse_decode_box_autoadd_png_filter(deserializer));
+            } else {
+                return null;
+            }
+             }
+
+@protected ResizeFilter? sse_decode_opt_box_autoadd_resize_filter(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+            if (sse_decode_bool(deserializer)) {
+                return (sse_decode_box_autoadd_resize_filter(deserializer));
+            } else {
+                return null;
+            }
+             }
+
+@protected ResizeFit? sse_decode_opt_box_autoadd_resize_fit(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+            if (sse_decode_bool(deserializer)) {
+                return (sse_decode_box_autoadd_resize_fit(deserializer));
+            } else {
+                return null;
+            }
+             }
+
+@protected TiffCompression? sse_decode_opt_box_autoadd_tiff_compression(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+            if (sse_decode_bool(deserializer)) {
+                return (sse_decode_box_autoadd_tiff_compression(deserializer));
+            } else {
+                return null;
+            }
+             }
+
+@protected WatermarkPosition? sse_decode_opt_box_autoadd_watermark_position(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+            if (sse_decode_bool(deserializer)) {
+                return (sse_decode_box_autoadd_watermark_position(deserializer));
+            } else {
+                return null;
+            }
+             }
+
+@protected Int32List? sse_decode_opt_list_prim_i_32_strict(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+            if (sse_decode_bool(deserializer)) {
+                return (sse_decode_list_prim_i_32_strict(deserializer));
+            } else {
+                return null;
+            }
+             }
+
+@protected PdfPageSize sse_decode_pdf_page_size(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var inner = sse_decode_i_32(deserializer);
+        return PdfPageSize.values[inner]; }
+
+@protected PngFilter sse_decode_png_filter(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var inner = sse_decode_i_32(deserializer);
+        return PngFilter.values[inner]; }
+
+@protected ResizeFilter sse_decode_resize_filter(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var inner = sse_decode_i_32(deserializer);
+        return ResizeFilter.values[inner]; }
+
+@protected ResizeFit sse_decode_resize_fit(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var inner = sse_decode_i_32(deserializer);
+        return ResizeFit.values[inner]; }
+
+@protected TiffCompression sse_decode_tiff_compression(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var inner = sse_decode_i_32(deserializer);
+        return TiffCompression.values[inner]; }
+
+@protected int sse_decode_u_8(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return deserializer.buffer.getUint8(); }
+
+@protected void sse_decode_unit(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+ }
+
+@protected WatermarkPosition sse_decode_watermark_position(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var inner = sse_decode_i_32(deserializer);
+        return WatermarkPosition.values[inner]; }
+
+@protected void sse_encode_AnyhowException(AnyhowException self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_String(self.message, serializer); }
+
+@protected void sse_encode_StreamSink_convert_progress_Sse(RustStreamSink<ConvertProgress> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_String(self.setupAndSerialize(codec: SseCodec(
+            decodeSuccessData: sse_decode_convert_progress,
+            decodeErrorData: sse_decode_AnyhowException,
+        )), serializer); }
+
+@protected void sse_encode_String(String self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_list_prim_u_8_strict(utf8.encoder.convert(self), serializer); }
+
+@protected void sse_encode_bool(bool self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+serializer.buffer.putUint8(self ? 1 : 0); }
+
+@protected void sse_encode_box_autoadd_bool(bool self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_bool(self, serializer); }
+
+@protected void sse_encode_box_autoadd_convert_error_code(ConvertErrorCode self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_convert_error_code(self, serializer); }
+
+@protected void sse_encode_box_autoadd_convert_error_details(ConvertErrorDetails self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_convert_error_details(self, serializer); }
+
+@protected void sse_encode_box_autoadd_convert_options(ConvertOptions self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_convert_options(self, serializer); }
+
+@protected void sse_encode_box_autoadd_crop_rect(CropRect self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_crop_rect(self, serializer); }
+
+@protected void sse_encode_box_autoadd_directory_filter(DirectoryFilter self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_directory_filter(self, serializer); }
+
+@protected void sse_encode_box_autoadd_f_64(double self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_f_64(self, serializer); }
+
+@protected void sse_encode_box_autoadd_file_type(FileType self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_file_type(self, serializer); }
+
+@protected void sse_encode_box_autoadd_flip(Flip self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_flip(self, serializer); }
+
+@protected void sse_encode_box_autoadd_i_32(int self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self, serializer); }
+
+@protected void sse_encode_box_autoadd_icc_profile_mode(IccProfileMode self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_icc_profile_mode(self, serializer); }
+
+@protected void sse_encode_box_autoadd_metadata_mode(MetadataMode self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_metadata_mode(self, serializer); }
+
+@protected void sse_encode_box_autoadd_on_conflict(OnConflict self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_on_conflict(self, serializer); }
+
+@protected void sse_encode_box_autoadd_pdf_page_size(PdfPageSize self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_pdf_page_size(self, serializer); }
+
+@protected void sse_encode_box_autoadd_png_filter(PngFilter self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_png_filter(self, serializer); }
+
+@protected void sse_encode_box_autoadd_resize_filter(ResizeFilter self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_resize_filter(self, serializer); }
+
+@protected void sse_encode_box_autoadd_resize_fit(ResizeFit self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_resize_fit(self, serializer); }
+
+@protected void sse_encode_box_autoadd_tiff_compression(TiffCompression self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_tiff_compression(self, serializer); }
+
+@protected void sse_encode_box_autoadd_watermark_position(WatermarkPosition self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_watermark_position(self, serializer); }
+
+@protected void sse_encode_convert_error_code(ConvertErrorCode self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.index, serializer); }
+
+@protected void sse_encode_convert_error_details(ConvertErrorDetails self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_opt_String(self.tool, serializer);
+sse_encode_opt_String(self.from, serializer);
+sse_encode_opt_String(self.to, serializer);
+sse_encode_opt_box_autoadd_i_32(self.exitCode, serializer);
+sse_encode_opt_String(self.stderr, serializer);
+ }
+
+@protected void sse_encode_convert_options(ConvertOptions self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_String(self.outputFormat, serializer);
+sse_encode_opt_box_autoadd_i_32(self.imageQuality, serializer);
+sse_encode_opt_box_autoadd_i_32(self.audioQuality, serializer);
+sse_encode_opt_box_autoadd_i_32(self.audioBitrate, serializer);
+sse_encode_opt_box_autoadd_i_32(self.audioSampleRate, serializer);
+sse_encode_opt_box_autoadd_i_32(self.videoCrf, serializer);
+sse_encode_opt_box_autoadd_i_32(self.videoBitrate, serializer);
+sse_encode_opt_box_autoadd_i_32(self.videoWidth, serializer);
+sse_encode_opt_box_autoadd_i_32(self.videoHeight, serializer);
+sse_encode_opt_String(self.ffmpegPath, serializer);
+sse_encode_opt_String(self.taskId, serializer);
+sse_encode_opt_box_autoadd_i_32(self.maxParallel, serializer);
+sse_encode_opt_box_autoadd_i_32(self.maxParallelExternal, serializer);
+sse_encode_opt_box_autoadd_on_conflict(self.onConflict, serializer);
+sse_encode_opt_box_autoadd_i_32(self.imageWidth, serializer);
+sse_encode_opt_box_autoadd_i_32(self.imageHeight, serializer);
+sse_encode_opt_box_autoadd_resize_fit(self.resizeFit, serializer);
+sse_encode_opt_box_autoadd_resize_filter(self.resizeFilter, serializer);
+sse_encode_opt_box_autoadd_crop_rect(self.crop, serializer);
+sse_encode_opt_box_autoadd_i_32(self.rotate, serializer);
+sse_encode_opt_box_autoadd_flip(self.flip, serializer);
+sse_encode_opt_box_autoadd_metadata_mode(self.metadata, serializer);
+sse_encode_opt_box_autoadd_i_32(self.svgDpi, serializer);
+sse_encode_opt_String(self.background, serializer);
+sse_encode_opt_String(self.svgFontFamily, serializer);
+sse_encode_opt_box_autoadd_i_32(self.frame, serializer);
+sse_encode_opt_box_autoadd_bool(self.extractFrames, serializer);
+sse_encode_opt_list_prim_i_32_strict(self.icoSizes, serializer);
+sse_encode_opt_box_autoadd_i_32(self.icoSize, serializer);
+sse_encode_opt_box_autoadd_bool(self.webpLossless, serializer);
+sse_encode_opt_box_autoadd_i_32(self.webpAlphaQuality, serializer);
+sse_encode_opt_box_autoadd_i_32(self.pngCompression, serializer);
+sse_encode_opt_box_autoadd_png_filter(self.pngFilter, serializer);
+sse_encode_opt_box_autoadd_i_32(self.pngColors, serializer);
+sse_encode_opt_box_autoadd_bool(self.pngDither, serializer);
+sse_encode_opt_box_autoadd_bool(self.pngOptimize, serializer);
+sse_encode_opt_box_autoadd_i_32(self.avifSpeed, serializer);
+sse_encode_opt_box_autoadd_tiff_compression(self.tiffCompression, serializer);
+sse_encode_opt_box_autoadd_i_32(self.targetSizeBytes, serializer);
+sse_encode_opt_box_autoadd_bool(self.targetSizeDownscale, serializer);
+sse_encode_opt_box_autoadd_pdf_page_size(self.pdfPageSize, serializer);
+sse_encode_opt_box_autoadd_i_32(self.pdfMarginMm, serializer);
+sse_encode_opt_box_autoadd_resize_fit(self.pdfFit, serializer);
+sse_encode_opt_box_autoadd_i_32(self.pdfJpegQuality, serializer);
+sse_encode_opt_String(self.watermarkText, serializer);
+sse_encode_opt_String(self.watermarkImage, serializer);
+sse_encode_opt_String(self.watermarkFontFamily, serializer);
+sse_encode_opt_box_autoadd_i_32(self.watermarkFontSize, serializer);
+sse_encode_opt_box_autoadd_i_32(self.watermarkImageWidth, serializer);
+sse_encode_opt_String(self.watermarkColor, serializer);
+sse_encode_opt_box_autoadd_i_32(self.watermarkOpacity, serializer);
+sse_encode_opt_box_autoadd_watermark_position(self.watermarkPosition, serializer);
+sse_encode_opt_box_autoadd_i_32(self.watermarkMargin, serializer);
+sse_encode_opt_box_autoadd_bool(self.watermarkTile, serializer);
+sse_encode_opt_box_autoadd_bool(self.grayscale, serializer);
+sse_encode_opt_box_autoadd_i_32(self.brightness, serializer);
+sse_encode_opt_box_autoadd_i_32(self.contrast, serializer);
+sse_encode_opt_box_autoadd_i_32(self.hueRotate, serializer);
+sse_encode_opt_box_autoadd_f_64(self.gamma, serializer);
+sse_encode_opt_box_autoadd_bool(self.invert, serializer);
+sse_encode_opt_box_autoadd_f_64(self.blur, serializer);
+sse_encode_opt_box_autoadd_f_64(self.sharpen, serializer);
+sse_encode_opt_box_autoadd_i_32(self.sharpenThreshold, serializer);
+sse_encode_opt_box_autoadd_i_32(self.sheetColumns, serializer);
+sse_encode_opt_box_autoadd_i_32(self.sheetCellWidth, serializer);
+sse_encode_opt_box_autoadd_i_32(self.sheetCellHeight, serializer);
+sse_encode_opt_box_autoadd_i_32(self.sheetSpacing, serializer);
+sse_encode_opt_String(self.sheetBackground, serializer);
+sse_encode_opt_box_autoadd_bool(self.sheetCaptions, serializer);
+sse_encode_opt_box_autoadd_icc_profile_mode(self.iccProfile, serializer);
+ }
+
+@protected void sse_encode_convert_progress(ConvertProgress self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_String(self.taskId, serializer);
+sse_encode_String(self.inputPath, serializer);
+sse_encode_i_32(self.fileIndex, serializer);
+sse_encode_i_32(self.fileCount, serializer);
+sse_encode_i_32(self.progress, serializer);
+sse_encode_String(self.status, serializer);
+ }
+
+@protected void sse_encode_convert_result(ConvertResult self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_bool(self.success, serializer);
+sse_encode_opt_String(self.outputPath, serializer);
+sse_encode_opt_String(self.error, serializer);
+sse_encode_opt_box_autoadd_convert_error_code(self.errorCode, serializer);
+sse_encode_opt_box_autoadd_convert_error_details(self.errorDetails, serializer);
+sse_encode_bool(self.skipped, serializer);
+sse_encode_list_String(self.route, serializer);
+sse_encode_list_String(self.outputPaths, serializer);
+sse_encode_list_String(self.warnings, serializer);
+sse_encode_opt_box_autoadd_i_32(self.imageQuality, serializer);
+ }
+
+@protected void sse_encode_crop_rect(CropRect self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.x, serializer);
+sse_encode_i_32(self.y, serializer);
+sse_encode_i_32(self.width, serializer);
+sse_encode_i_32(self.height, serializer);
+ }
+
+@protected void sse_encode_detection_confidence(DetectionConfidence self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.index, serializer); }
+
+@protected void sse_encode_directory_file_result(DirectoryFileResult self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_String(self.inputPath, serializer);
+sse_encode_String(self.relativePath, serializer);
+sse_encode_convert_result(self.result, serializer);
+ }
+
+@protected void sse_encode_directory_filter(DirectoryFilter self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_list_String(self.include, serializer);
+sse_encode_list_String(self.exclude, serializer);
+sse_encode_list_file_type(self.fileTypes, serializer);
+ }
+
+@protected void sse_encode_f_64(double self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+serializer.buffer.putFloat64(self); }
+
+@protected void sse_encode_file_detection(FileDetection self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_opt_box_autoadd_file_type(self.fileType, serializer);
+sse_encode_opt_String(self.format, serializer);
+sse_encode_opt_String(self.extension_, serializer);
+sse_encode_detection_confidence(self.confidence, serializer);
+sse_encode_bool(self.extensionMismatch, serializer);
+ }
+
+@protected void sse_encode_file_type(FileType self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.index, serializer); }
+
+@protected void sse_encode_flip(Flip self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.index, serializer); }
+
+@protected void sse_encode_i_32(int self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+serializer.buffer.putInt32(self); }
+
+@protected void sse_encode_icc_profile_mode(IccProfileMode self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.index, serializer); }
+
+@protected void sse_encode_list_String(List<String> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_String(item, serializer); } }
+
+@protected void sse_encode_list_convert_result(List<ConvertResult> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_convert_result(item, serializer); } }
+
+@protected void sse_encode_list_directory_file_result(List<DirectoryFileResult> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_directory_file_result(item, serializer); } }
+
+@protected void sse_encode_list_file_type(List<FileType> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_file_type(item, serializer); } }
+
+@protected void sse_encode_list_prim_i_32_strict(Int32List self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+                    serializer.buffer.putInt32List(self); }
+
+@protected void sse_encode_list_prim_u_8_strict(Uint8List self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+                    serializer.buffer.putUint8List(self); }
+
+@protected void sse_encode_metadata_mode(MetadataMode self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.index, serializer); }
+
+@protected void sse_encode_on_conflict(OnConflict self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.index, serializer); }
+
+@protected void sse_encode_opt_String(String? self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+                sse_encode_bool(self != null, serializer);
+                if (self != null) {
+                    sse_encode_String(self, serializer);
+                }
+                 }
+
+@protected void sse_encode_opt_box_autoadd_bool(bool? self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+                sse_encode_bool(self != null, serializer);
+                if (self != null) {
+                    sse_encode_box_autoadd_bool(self, serializer);
+                }
+                 }
+
+@protected void sse_encode_opt_box_autoadd_convert_error_code(ConvertErrorCode? self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+                sse_encode_bool(self != null, serializer);
+                if (self != null) {
+                    sse_encode_box_autoadd_convert_error_code(self, serializer);
+                }
+                 }
+
+@protected void sse_encode_opt_box_autoadd_convert_error_details(ConvertErrorDetails? self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+                sse_encode_bool(self != null, serializer);
+                if (self != null) {
+                    sse_encode_box_autoadd_convert_error_details(self, serializer);
+                }
+                 }
+
+@protected void sse_encode_opt_box_autoadd_crop_rect(CropRect? self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+                sse_encode_bool(self != null, serializer);
+                if (self != null) {
+                    sse_encode_box_autoadd_crop_rect(self, serializer);
+                }
+                 }
+
+@protected void sse_encode_opt_box_autoadd_f_64(double? self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+                sse_encode_bool(self != null, serializer);
+                if (self != null) {
+                    sse_encode_box_autoadd_f_64(self, serializer);
+                }
+                 }
+
+@protected void sse_encode_opt_box_autoadd_file_type(FileType? self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+                sse_encode_bool(self != null, serializer);
+                if (self != null) {
+                    sse_encode_box_autoadd_file_type(self, serializer);
+                }
+                 }
+
+@protected void sse_encode_opt_box_autoadd_flip(Flip? self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+                sse_encode_bool(self != null, serializer);
+                if (self != null) {
+                    sse_encode_box_autoadd_flip(self, serializer);
+                }
+                 }
+
+@protected void sse_encode_opt_box_autoadd_i_32(int? self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+                sse_encode_bool(self != null, serializer);
+                if (self != null) {
+                    sse_encode_box_autoadd_i_32(self, serializer);
+                }
+                 }
+
+@protected void sse_encode_opt_box_autoadd_icc_profile_mode(IccProfileMode? self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+                sse_encode_bool(self != null, serializer);
+                if (self != null) {
+                    sse_encode_box_autoadd_icc_profile_mode(self, serializer);
+                }
+                 }
+
+@protected void sse_encode_opt_box_autoadd_metadata_mode(MetadataMode? self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+                sse_encode_bool(self != null, serializer);
+                if (self != null) {
+                    sse_encode_box_autoadd_metadata_mode(self, serializer);
+                }
+                 }
+
+@protected void sse_encode_opt_box_autoadd_on_conflict(OnConflict? self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+                sse_encode_bool(self != null, serializer);
+                if (self != null) {
+                    sse_encode_box_autoadd_on_conflict(self, serializer);
+                }
+                 }
+
+@protected void sse_encode_opt_box_autoadd_pdf_page_size(PdfPageSize? self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+                sse_encode_bool(self != null, serializer);
+                if (self != null) {
+                    sse_encode_box_autoadd_pdf_page_size(self, serializer);
+                }
+                 }
+
+@protected void sse_encode_opt_box_autoadd_png_filter(PngFilter? self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+                sse_encode_bool(self != null, serializer);
+                if (self != null) {
+                    sse_encode_box_autoadd_png_filter(self, serializer);
+                }
+                 }
+
+@protected void sse_encode_opt_box_autoadd_resize_filter(ResizeFilter? self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+                sse_encode_bool(self != null, serializer);
+                if (self != null) {
+                    sse_encode_box_autoadd_resize_filter(self, serializer);
+                }
+                 }
+
+@protected void sse_encode_opt_box_autoadd_resize_fit(ResizeFit? self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+                sse_encode_bool(self != null, serializer);
+                if (self != null) {
+                    sse_encode_box_autoadd_resize_fit(self, serializer);
+                }
+                 }
+
+@protected void sse_encode_opt_box_autoadd_tiff_compression(TiffCompression? self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+                sse_encode_bool(self != null, serializer);
+                if (self != null) {
+                    sse_encode_box_autoadd_tiff_compression(self, serializer);
+                }
+                 }
+
+@protected void sse_encode_opt_box_autoadd_watermark_position(WatermarkPosition? self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+                sse_encode_bool(self != null, serializer);
+                if (self != null) {
+                    sse_encode_box_autoadd_watermark_position(self, serializer);
+                }
+                 }
+
+@protected void sse_encode_opt_list_prim_i_32_strict(Int32List? self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+                sse_encode_bool(self != null, serializer);
+                if (self != null) {
+                    sse_encode_list_prim_i_32_strict(self, serializer);
+                }
+                 }
+
+@protected void sse_encode_pdf_page_size(PdfPageSize self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.index, serializer); }
+
+@protected void sse_encode_png_filter(PngFilter self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.index, serializer); }
+
+@protected void sse_encode_resize_filter(ResizeFilter self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.index, serializer); }
+
+@protected void sse_encode_resize_fit(ResizeFit self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.index, serializer); }
+
+@protected void sse_encode_tiff_compression(TiffCompression self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.index, serializer); }
+
+@protected void sse_encode_u_8(int self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+serializer.buffer.putUint8(self); }
+
+@protected void sse_encode_unit(void self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+ }
+
+@protected void sse_encode_watermark_position(WatermarkPosition self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.index, serializer); }
+                }
+                
@@ -26,107 +26,443 @@ import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated_web.dart';
 
                   
 
-                  @protected String dco_decode_String(dynamic raw);
+                  @protected AnyhowException dco_decode_AnyhowException(dynamic raw);
+
+@protected RustStreamSink<ConvertProgress> dco_decode_StreamSink_convert_progress_Sse(dynamic raw);
+
+@protected String dco_decode_String(dynamic raw);
 
 @protected bool dco_decode_bool(dynamic raw);
 
+@protected bool dco_decode_box_autoadd_bool(dynamic raw);
+
+@protected ConvertErrorCode dco_decode_box_autoadd_convert_error_code(dynamic raw);
+
+@protected ConvertErrorDetails dco_decode_box_autoadd_convert_error_details(dynamic raw);
+
 @protected ConvertOptions dco_decode_box_autoadd_convert_options(dynamic raw);
 
+@protected CropRect dco_decode_box_autoadd_crop_rect(dynamic raw);
+
+@protected DirectoryFilter dco_decode_box_autoadd_directory_filter(dynamic raw);
+
+@protected double dco_decode_box_autoadd_f_64(dynamic raw);
+
 @protected FileType dco_decode_box_autoadd_file_type(dynamic raw);
 
+@protected Flip dco_decode_box_autoadd_flip(dynamic raw);
+
 @protected int dco_decode_box_autoadd_i_32(dynamic raw);
 
+@protected IccProfileMode dco_decode_box_autoadd_icc_profile_mode(dynamic raw);
+
+@protected MetadataMode dco_decode_box_autoadd_metadata_mode(dynamic raw);
+
+@protected OnConflict dco_decode_box_autoadd_on_conflict(dynamic raw);
+
+@protected PdfPageSize dco_decode_box_autoadd_pdf_page_size(dynamic raw);
+
+@protected PngFilter dco_decode_box_autoadd_png_filter(dynamic raw);
+
+@protected ResizeFilter dco_decode_box_autoadd_resize_filter(dynamic raw);
+
+@protected ResizeFit dco_decode_box_autoadd_resize_fit(dynamic raw);
+
+@protected TiffCompression dco_decode_box_autoadd_tiff_compression(dynamic raw);
+
+@protected WatermarkPosition dco_decode_box_autoadd_watermark_position(dynamic raw);
+
+@protected ConvertErrorCode dco_decode_convert_error_code(dynamic raw);
+
+@protected ConvertErrorDetails dco_decode_convert_error_details(dynamic raw);
+
 @protected ConvertOptions dco_decode_convert_options(dynamic raw);
 
+@protected ConvertProgress dco_decode_convert_progress(dynamic raw);
+
 @protected ConvertResult dco_decode_convert_result(dynamic raw);
 
+@protected CropRect dco_decode_crop_rect(dynamic raw);
+
+@protected DetectionConfidence dco_decode_detection_confidence(dynamic raw);
+
+@protected DirectoryFileResult dco_decode_directory_file_result(dynamic raw);
+
+@protected DirectoryFilter dco_decode_directory_filter(dynamic raw);
+
+@protected double dco_decode_f_64(dynamic raw);
+
+@protected FileDetection dco_decode_file_detection(dynamic raw);
+
 @protected FileType dco_decode_file_type(dynamic raw);
 
+@protected Flip dco_decode_flip(dynamic raw);
+
 @protected int dco_decode_i_32(dynamic raw);
 
+@protected IccProfileMode dco_decode_icc_profile_mode(dynamic raw);
+
 @protected List<String> dco_decode_list_String(dynamic raw);
 
 @protected List<ConvertResult> dco_decode_list_convert_result(dynamic raw);
 
+@protected List<DirectoryFileResult> dco_decode_list_directory_file_result(dynamic raw);
+
+@protected List<FileType> dco_decode_list_file_type(dynamic raw);
+
+@protected Int32List dco_decode_list_prim_i_32_strict(dynamic raw);
+
 @protected Uint8List dco_decode_list_prim_u_8_strict(dynamic raw);
 
+@protected MetadataMode dco_decode_metadata_mode(dynamic raw);
+
+@protected OnConflict dco_decode_on_conflict(dynamic raw);
+
 @protected String? dco_decode_opt_String(dynamic raw);
 
+@protected bool? dco_decode_opt_box_autoadd_bool(dynamic raw);
+
+@protected ConvertErrorCode? dco_decode_opt_box_autoadd_convert_error_code(dynamic raw);
+
+@protected ConvertErrorDetails? dco_decode_opt_box_autoadd_convert_error_details(dynamic raw);
+
+@protected CropRect? dco_decode_opt_box_autoadd_crop_rect(dynamic raw);
+
+@protected double? dco_decode_opt_box_autoadd_f_64(dynamic raw);
+
 @protected FileType? dco_decode_opt_box_autoadd_file_type(dynamic raw);
 
+@protected Flip? dco_decode_opt_box_autoadd_flip(dynamic raw);
+
 @protected int? dco_decode_opt_box_autoadd_i_32(dynamic raw);
 
+@protected IccProfileMode? dco_decode_opt_box_autoadd_icc_profile_mode(dynamic raw);
+
+@protected MetadataMode? dco_decode_opt_box_autoadd_metadata_mode(dynamic raw);
+
+@protected OnConflict? dco_decode_opt_box_autoadd_on_conflict(dynamic raw);
+
+@protected PdfPageSize? dco_decode_opt_box_autoadd_pdf_page_size(dynamic raw);
+
+@protected PngFilter? dco_decode_opt_box_autoadd_png_filter(dynamic raw);
+
+@protected ResizeFilter? dco_decode_opt_box_autoadd_resize_filter(dynamic raw);
+
+@protected ResizeFit? dco_decode_opt_box_autoadd_resize_fit(dynamic raw);
+
+@protected TiffCompression? dco_decode_opt_box_autoadd_tiff_compression(dynamic raw);
+
+@protected WatermarkPosition? dco_decode_opt_box_autoadd_watermark_position(dynamic raw);
+
+@protected Int32List? dco_decode_opt_list_prim_i_32_strict(dynamic raw);
+
+@protected PdfPageSize dco_decode_pdf_page_size(dynamic raw);
+
+@protected PngFilter dco_decode_png_filter(dynamic raw);
+
+@protected ResizeFilter dco_decode_resize_filter(dynamic raw);
+
+@protected ResizeFit dco_decode_resize_fit(dynamic raw);
+
+@protected TiffCompression dco_decode_tiff_compression(dynamic raw);
+
 @protected int dco_decode_u_8(dynamic raw);
 
 @protected void dco_decode_unit(dynamic raw);
 
+@protected WatermarkPosition dco_decode_watermark_position(dynamic raw);
+
+@protected AnyhowException sse_decode_AnyhowException(SseDeserializer deserializer);
+
+@protected RustStreamSink<ConvertProgress> sse_decode_StreamSink_convert_progress_Sse(SseDeserializer deserializer);
+
 @protected String sse_decode_String(SseDeserializer deserializer);
 
 @protected bool sse_decode_bool(SseDeserializer deserializer);
 
+@protected bool sse_decode_box_autoadd_bool(SseDeserializer deserializer);
+
+@protected ConvertErrorCode sse_decode_box_autoadd_convert_error_code(SseDeserializer deserializer);
+
+@protected ConvertErrorDetails sse_decode_box_autoadd_convert_error_details(SseDeserializer deserializer);
+
 @protected ConvertOptions sse_decode_box_autoadd_convert_options(SseDeserializer deserializer);
 
+@protected CropRect sse_decode_box_autoadd_crop_rect(SseDeserializer deserializer);
+
+@protected DirectoryFilter sse_decode_box_autoadd_directory_filter(SseDeserializer deserializer);
+
+@protected double sse_decode_box_autoadd_f_64(SseDeserializer deserializer);
+
 @protected FileType sse_decode_box_autoadd_file_type(SseDeserializer deserializer);
 
+@protected Flip sse_decode_box_autoadd_flip(SseDeserializer deserializer);
+
 @protected int sse_decode_box_autoadd_i_32(SseDeserializer deserializer);
 
+@protected IccProfileMode sse_decode_box_autoadd_icc_profile_mode(SseDeserializer deserializer);
+
+@protected MetadataMode sse_decode_box_autoadd_metadata_mode(SseDeserializer deserializer);
+
+@protected OnConflict sse_decode_box_autoadd_on_conflict(SseDeserializer deserializer);
+
+@protected PdfPageSize sse_decode_box_autoadd_pdf_page_size(SseDeserializer deserializer);
+
+@protected PngFilter sse_decode_box_autoadd_png_filter(SseDeserializer deserializer);
+
+@protected ResizeFilter sse_decode_box_autoadd_resize_filter(SseDeserializer deserializer);
+
+@protected ResizeFit sse_decode_box_autoadd_resize_fit(SseDeserializer deserializer);
+
+@protected TiffCompression sse_decode_box_autoadd_tiff_compression(SseDeserializer deserializer);
+
+@protected WatermarkPosition sse_decode_box_autoadd_watermark_position(SseDeserializer deserializer);
+
+@protected ConvertErrorCode sse_decode_convert_error_code(SseDeserializer deserializer);
+
+@protected ConvertErrorDetails sse_decode_convert_error_details(SseDeserializer deserializer);
+
 @protected ConvertOptions sse_decode_convert_options(SseDeserializer deserializer);
 
+@protected ConvertProgress sse_decode_convert_progress(SseDeserializer deserializer);
+
 @protected ConvertResult sse_decode_convert_result(SseDeserializer deserializer);
 
+@protected CropRect sse_decode_crop_rect(SseDeserializer deserializer);
+
+@protected DetectionConfidence sse_decode_detection_confidence(SseDeserializer deserializer);
+
+@protected DirectoryFileResult sse_decode_directory_file_result(SseDeserializer deserializer);
+
+@protected DirectoryFilter sse_decode_directory_filter(SseDeserializer deserializer);
+
+@protected double sse_decode_f_64(SseDeserializer deserializer);
+
+@protected FileDetection sse_decode_file_detection(SseDeserializer deserializer);
+
 @protected FileType sse_decode_file_type(SseDeserializer deserializer);
 
+@protected Flip sse_decode_flip(SseDeserializer deserializer);
+
 @protected int sse_decode_i_32(SseDeserializer deserializer);
 
+@protected IccProfileMode sse_decode_icc_profile_mode(SseDeserializer deserializer);
+
 @protected List<String> sse_decode_list_String(SseDeserializer deserializer);
 
 @protected List<ConvertResult> sse_decode_list_convert_result(SseDeserializer deserializer);
 
+@protected List<DirectoryFileResult> sse_decode_list_directory_file_result(SseDeserializer deserializer);
+
+@protected List<FileType> sse_decode_list_file_type(SseDeserializer deserializer);
+
+@protected Int32List sse_decode_list_prim_i_32_strict(SseDeserializer deserializer);
+
 @protected Uint8List sse_decode_list_prim_u_8_strict(SseDeserializer deserializer);
 
+@protected MetadataMode sse_decode_metadata_mode(SseDeserializer deserializer);
+
+@protected OnConflict sse_decode_on_conflict(SseDeserializer deserializer);
+
 @protected String? sse_decode_opt_String(SseDeserializer deserializer);
 
+@protected bool? sse_decode_opt_box_autoadd_bool(SseDeserializer deserializer);
+
+@protected ConvertErrorCode? sse_decode_opt_box_autoadd_convert_error_code(SseDeserializer deserializer);
+
+@protected ConvertErrorDetails? sse_decode_opt_box_autoadd_convert_error_details(SseDeserializer deserializer);
+
+@protected CropRect? sse_decode_opt_box_autoadd_crop_rect(SseDeserializer deserializer);
+
+@protected double? sse_decode_opt_box_autoadd_f_64(SseDeserializer deserializer);
+
 @protected FileType? sse_decode_opt_box_autoadd_file_type(SseDeserializer deserializer);
 
+@protected Flip? sse_decode_opt_box_autoadd_flip(SseDeserializer deserializer);
+
 @protected int? sse_decode_opt_box_autoadd_i_32(SseDeserializer deserializer);
 
+@protected IccProfileMode? sse_decode_opt_box_autoadd_icc_profile_mode(SseDeserializer deserializer);
+
+@protected MetadataMode? sse_decode_opt_box_autoadd_metadata_mode(SseDeserializer deserializer);
+
+@protected OnConflict? sse_decode_opt_box_autoadd_on_conflict(SseDeserializer deserializer);
+
+@protected PdfPageSize? sse_decode_opt_box_autoadd_pdf_page_size(SseDeserializer deserializer);
+
+@protected PngFilter? sse_decode_opt_box_autoadd_png_filter(SseDeserializer deserializer);
+
+@protected ResizeFilter? sse_decode_opt_box_autoadd_resize_filter(SseDeserializer deserializer);
+
+@protected ResizeFit? sse_decode_opt_box_autoadd_resize_fit(SseDeserializer deserializer);
+
+@protected TiffCompression? sse_decode_opt_box_autoadd_tiff_compression(SseDeserializer deserializer);
+
+@protected WatermarkPosition? sse_decode_opt_box_autoadd_watermark_position(SseDeserializer deserializer);
+
+@protected Int32List? sse_decode_opt_list_prim_i_32_strict(SseDeserializer deserializer);
+
+@protected PdfPageSize sse_decode_pdf_page_size(SseDeserializer deserializer);
+
+@protected PngFilter sse_decode_png_filter(SseDeserializer deserializer);
+
+@protected ResizeFilter sse_decode_resize_filter(SseDeserializer deserializer);
+
+@protected ResizeFit sse_decode_resize_fit(SseDeserializer deserializer);
+
+@protected TiffCompression sse_decode_tiff_compression(SseDeserializer deserializer);
+
 @protected int sse_decode_u_8(SseDeserializer deserializer);
 
 @protected void sse_decode_unit(SseDeserializer deserializer);
 
+@protected WatermarkPosition sse_decode_watermark_position(SseDeserializer deserializer);
+
+@protected void sse_encode_AnyhowException(AnyhowException self, SseSerializer serializer);
+
+@protected void sse_encode_StreamSink_convert_progress_Sse(RustStreamSink<ConvertProgress> self, SseSerializer serializer);
+
 @protected void sse_encode_String(String self, SseSerializer serializer);
 
 @protected void sse_encode_bool(bool self, SseSerializer serializer);
 
+@protected void sse_encode_box_autoadd_bool(bool self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_convert_error_code(ConvertErrorCode self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_convert_error_details(ConvertErrorDetails self, SseSerializer serializer);
+
 @protected void sse_encode_box_autoadd_convert_options(ConvertOptions self, SseSerializer serializer);
 
+@protected void sse_encode_box_autoadd_crop_rect(CropRect self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_directory_filter(DirectoryFilter self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_f_64(double self, SseSerializer serializer);
+
 @protected void sse_encode_box_autoadd_file_type(FileType self, SseSerializer serializer);
 
+@protected void sse_encode_box_autoadd_flip(Flip self, SseSerializer serializer);
+
 @protected void sse_encode_box_autoadd_i_32(int self, SseSerializer serializer);
 
+@protected void sse_encode_box_autoadd_icc_profile_mode(IccProfileMode self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_metadata_mode(MetadataMode self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_on_conflict(OnConflict self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_pdf_page_size(PdfPageSize self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_png_filter(PngFilter self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_resize_filter(ResizeFilter self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_resize_fit(ResizeFit self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_tiff_compression(TiffCompression self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_watermark_position(WatermarkPosition self, SseSerializer serializer);
+
+@protected void sse_encode_convert_error_code(ConvertErrorCode self, SseSerializer serializer);
+
+@protected void sse_encode_convert_error_details(ConvertErrorDetails self, SseSerializer serializer);
+
 @protected void sse_encode_convert_options(ConvertOptions self, SseSerializer serializer);
 
+@protected void sse_encode_convert_progress(ConvertProgress self, SseSerializer serializer);
+
 @protected void sse_encode_convert_result(ConvertResult self, SseSerializer serializer);
 
+@protected void sse_encode_crop_rect(CropRect self, SseSerializer serializer);
+
+@protected void sse_encode_detection_confidence(DetectionConfidence self, SseSerializer serializer);
+
+@protected void sse_encode_directory_file_result(DirectoryFileResult self, SseSerializer serializer);
+
+@protected void sse_encode_directory_filter(DirectoryFilter self, SseSerializer serializer);
+
+@protected void sse_encode_f_64(double self, SseSerializer serializer);
+
+@protected void sse_encode_file_detection(FileDetection self, SseSerializer serializer);
+
 @protected void sse_encode_file_type(FileType self, SseSerializer serializer);
 
+@protected void sse_encode_flip(Flip self, SseSerializer serializer);
+
 @protected void sse_encode_i_32(int self, SseSerializer serializer);
 
+@protected void sse_encode_icc_profile_mode(IccProfileMode self, SseSerializer serializer);
+
 @protected void sse_encode_list_String(List<String> self, SseSerializer serializer);
 
 @protected void sse_encode_list_convert_result(List<ConvertResult> self, SseSerializer serializer);
 
+@protected void sse_encode_list_directory_file_result(List<DirectoryFileResult> self, SseSerializer serializer);
+
+@protected void sse_encode_list_file_type(List<FileType> self, SseSerializer serializer);
+
+@protected void sse_encode_list_prim_i_32_strict(Int32List self, SseSerializer serializer);
+
 @protected void sse_encode_list_prim_u_8_strict(Uint8List self, SseSerializer serializer);
 
+@protected void sse_encode_metadata_mode(MetadataMode self, SseSerializer serializer);
+
+@protected void sse_encode_on_conflict(OnConflict self, SseSerializer serializer);
+
 @protected void sse_encode_opt_String(String? self, SseSerializer serializer);
 
+@protected void sse_encode_opt_box_autoadd_bool(bool? self, SseSerializer serializer);
+
+@protected void sse_encode_opt_box_autoadd_convert_error_code(ConvertErrorCode? self, SseSerializer serializer);
+
+@protected void sse_encode_opt_box_autoadd_convert_error_details(ConvertErrorDetails? self, SseSerializer serializer);
+
+@protected void sse_encode_opt_box_autoadd_crop_rect(CropRect? self, SseSerializer serializer);
+
+@protected void sse_encode_opt_box_autoadd_f_64(double? self, SseSerializer serializer);
+
 @protected void sse_encode_opt_box_autoadd_file_type(FileType? self, SseSerializer serializer);
 
+@protected void sse_encode_opt_box_autoadd_flip(Flip? self, SseSerializer serializer);
+
 @protected void sse_encode_opt_box_autoadd_i_32(int? self, SseSerializer serializer);
 
+@protected void sse_encode_opt_box_autoadd_icc_profile_mode(IccProfileMode? self, SseSerializer serializer);
+
+@protected void sse_encode_opt_box_autoadd_metadata_mode(MetadataMode? self, SseSerializer serializer);
+
+@protected void sse_encode_opt_box_autoadd_on_conflict(OnConflict? self, SseSerializer serializer);
+
+@protected void sse_encode_opt_box_autoadd_pdf_page_size(PdfPageSize? self, SseSerializer serializer);
+
+@protected void sse_encode_opt_box_autoadd_png_filter(PngFilter? self, SseSerializer serializer);
+
+@protected void sse_encode_opt_box_autoadd_resize_filter(ResizeFilter? self, SseSerializer serializer);
+
+@protected void sse_encode_opt_box_autoadd_resize_fit(ResizeFit? self, SseSerializer serializer);
+
+@protected void sse_encode_opt_box_autoadd_tiff_compression(TiffCompression? self, SseSerializer serializer);
+
+@protected void sse_encode_opt_box_autoadd_watermark_position(WatermarkPosition? self, SseSerializer serializer);
+
+@protected void sse_encode_opt_list_prim_i_32_strict(Int32List? self, SseSerializer serializer);
+
+@protected void sse_encode_pdf_page_size(PdfPageSize self, SseSerializer serializer);
+
+@protected void sse_encode_png_filter(PngFilter self, SseSerializer serializer);
+
+@protected void sse_encode_resize_filter(ResizeFilter self, SseSerializer serializer);
+
+@protected void sse_encode_resize_fit(ResizeFit self, SseSerializer serializer);
+
+@protected void sse_encode_tiff_compression(TiffCompression self, SseSerializer serializer);
+
 @protected void sse_encode_u_8(int self, SseSerializer serializer);
 
 @protected void sse_encode_unit(void self, SseSerializer serializer);
+
+@protected void sse_encode_watermark_position(WatermarkPosition self, SseSerializer serializer);
                 }
                 
 
@@ -259,6 +259,12 @@ class ConvertPanel extends ConsumerWidget {
       'bmp': 'Uncompressed',
       'ico': 'Windows icon',
       'gif': 'Animated support',
+      'avif': 'Next-gen, smallest',
+      'tif': 'Print, lossless',
+      'tiff': 'Print, lossless',
+      'tga': 'Game textures',
+      'qoi': 'Fast lossless',
+      'pnm': 'Netpbm bitmap',
       // Document formats
       'pdf': 'Document standard',
       'html': 'Web format',
@@ -377,6 +383,12 @@ class ConvertPanel extends ConsumerWidget {
       'bmp',
       'ico',
       'gif',
+      'avif',
+      'tif',
+      'tiff',
+      'tga',
+      'qoi',
+      'pnm',
     ].contains(outputFormat.toLowerCase());
 
     final isAudioFormat = [
@@ -220,6 +220,12 @@ class FileDropZone extends ConsumerWidget {
       case 'webp':
       case 'gif':
       case 'bmp':
+      case 'avif':
+      case 'tif':
+      case 'tiff':
+      case 'tga':
+      case 'qoi':
+      case 'pnm':
         return Icons.image;
       case 'pdf':
         return Icons.picture_as_pdf;
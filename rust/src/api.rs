@@ -1,8 +1,10 @@
+use crate::frb_generated::StreamSink;
 use flutter_rust_bridge::frb;
+use serde::Serialize;
 
 /// File type enum
 #[frb]
-#[derive(Debug, Clone, Copy, PartialEq)]
+#[derive(Debug, Clone, Copy, PartialEq, Serialize)]
 pub enum FileType {
     Image,
     Document,
@@ -11,13 +13,43 @@ pub enum FileType {
     Config,
 }
 
+/// How the file type was determined
+#[frb]
+#[derive(Debug, Clone, Copy, PartialEq, Serialize)]
+pub enum DetectionConfidence {
+    /// Matched a binary file signature (magic bytes)
+    High,
+    /// Matched a text heuristic (e.g. parses as JSON/YAML)
+    Medium,
+    /// Only the file extension is known
+    Low,
+    /// Could not be determined
+    Unknown,
+}
+
+/// File detection result
+#[frb]
+#[derive(Debug, Clone, Serialize)]
+pub struct FileDetection {
+    /// Detected file type
+    pub file_type: Option<FileType>,
+    /// Detected format as an extension (e.g., "png", "docx"); falls back to the file extension
+    pub format: Option<String>,
+    /// File extension (lowercase)
+    pub extension: Option<String>,
+    /// How reliable the detection is
+    pub confidence: DetectionConfidence,
+    /// The content does not match the file extension (misnamed file)
+    pub extension_mismatch: bool,
+}
+
 /// Convert options
 #[frb]
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Default)]
 pub struct ConvertOptions {
     /// Output format (e.g., "png", "jpg", "pdf", "mp4")
     pub output_format: String,
-    /// Image quality (1-100, for JPEG/WebP compression)
+    /// Image quality (1-100, for JPEG/WebP/AVIF compression)
     pub image_quality: Option<i32>,
     /// Audio quality (0-9 for MP3/OGG, lower is better; or bitrate like 192 for AAC)
     pub audio_quality: Option<i32>,
@@ -35,11 +67,293 @@ pub struct ConvertOptions {
     pub video_height: Option<i32>,
     /// FFmpeg executable path (if provided by Dart side)
     pub ffmpeg_path: Option<String>,
+    /// Task ID used by `cancel_conversion` (generated if not provided)
+    pub task_id: Option<String>,
+    /// Batch: maximum number of files converted in parallel in-process (images, configs, ...);
+    /// defaults to the number of CPU cores
+    pub max_parallel: Option<i32>,
+    /// Batch: maximum number of external tool jobs (FFmpeg, pandoc, ...) run in parallel;
+    /// defaults to 2
+    pub max_parallel_external: Option<i32>,
+    /// What to do when the output file already exists (default: overwrite)
+    pub on_conflict: Option<OnConflict>,
+    /// Image target width in pixels (resize; see `resize_fit`)
+    pub image_width: Option<i32>,
+    /// Image target height in pixels (resize; see `resize_fit`)
+    pub image_height: Option<i32>,
+    /// How the image is fitted into `image_width` x `image_height` (default: contain)
+    pub resize_fit: Option<ResizeFit>,
+    /// Resampling filter used for resizing (default: Lanczos3)
+    pub resize_filter: Option<ResizeFilter>,
+    /// Crop rectangle, applied before rotating and resizing
+    pub crop: Option<CropRect>,
+    /// Clockwise rotation in degrees (90, 180 or 270)
+    pub rotate: Option<i32>,
+    /// Mirror the image (applied after rotating)
+    pub flip: Option<Flip>,
+    /// Which EXIF/XMP metadata is carried into JPEG, PNG and WebP outputs (default: strip GPS)
+    pub metadata: Option<MetadataMode>,
+    /// SVG input: rasterization DPI (default 96, the SVG's own pixel size); ignored when
+    /// `image_width` / `image_height` set the size
+    pub svg_dpi: Option<i32>,
+    /// CSS color painted behind SVG input (default: transparent) and behind transparent
+    /// images written to formats without alpha, such as JPEG (default: white);
+    /// e.g. `#ffffff` or `white`
+    pub background: Option<String>,
+    /// SVG input: font family used for text whose fonts are not installed
+    /// (default: any sans-serif font)
+    pub svg_font_family: Option<String>,
+    /// Animated input: convert only this frame (0-based) into a still image
+    pub frame: Option<i32>,
+    /// Write every frame as a numbered image, `name_001.png`, `name_002.png`, ...
+    /// (listed in `ConvertResult::output_paths`)
+    pub extract_frames: Option<bool>,
+    /// ICO output: icon sizes in pixels, 1-256 (default: 16, 24, 32, 48, 64, 128, 256,
+    /// up to the source size)
+    pub ico_sizes: Option<Vec<i32>>,
+    /// ICO input: the embedded icon size to convert (default: the largest)
+    pub ico_size: Option<i32>,
+    /// WebP output: lossless instead of lossy `image_quality` encoding (default: lossy)
+    pub webp_lossless: Option<bool>,
+    /// WebP output: quality of the alpha channel, 0-100 (default: 100, lossless alpha)
+    pub webp_alpha_quality: Option<i32>,
+    /// PNG output: zlib compression level, 0-9 (default: 6)
+    pub png_compression: Option<i32>,
+    /// PNG output: row filter (default: adaptive)
+    pub png_filter: Option<PngFilter>,
+    /// PNG output: quantize to a palette of this many colors, 2-256 (lossy; default: off)
+    pub png_colors: Option<i32>,
+    /// PNG output: dither when quantizing with `png_colors` (default: true)
+    pub png_dither: Option<bool>,
+    /// PNG output: losslessly optimize the file (smallest color type, best filters and
+    /// compression); slower, pixels are unchanged
+    pub png_optimize: Option<bool>,
+    /// AVIF output: encoder speed, 1 (slowest, smallest) to 10 (default: 4)
+    pub avif_speed: Option<i32>,
+    /// TIFF output: compression (default: LZW)
+    pub tiff_compression: Option<TiffCompression>,
+    /// JPEG, lossy WebP and AVIF output: pick the highest quality (capped by
+    /// `image_quality`) whose file fits in this many bytes
+    pub target_size_bytes: Option<i32>,
+    /// With `target_size_bytes`: shrink the image rather than going below quality 50
+    /// (default: false)
+    pub target_size_downscale: Option<bool>,
+    /// PDF output: page size (default: each page is the size of its image at 96 DPI)
+    pub pdf_page_size: Option<PdfPageSize>,
+    /// PDF output: blank space around the image on every side, in millimeters (default: 0)
+    pub pdf_margin_mm: Option<i32>,
+    /// PDF output: how the image is fitted into the page inside the margins (default: contain)
+    pub pdf_fit: Option<ResizeFit>,
+    /// PDF output: re-encode every image as JPEG with this quality, 1-100 (default: JPEG
+    /// input is embedded as it is, other images losslessly)
+    pub pdf_jpeg_quality: Option<i32>,
+    /// Text stamped onto image outputs, after the transforms (lines separated by `\n`)
+    pub watermark_text: Option<String>,
+    /// Path of an image (e.g. a PNG logo) stamped onto image outputs instead of text
+    pub watermark_image: Option<String>,
+    /// Watermark text font family (default: sans-serif)
+    pub watermark_font_family: Option<String>,
+    /// Watermark text size in pixels (default: 1/20 of the image's shorter side)
+    pub watermark_font_size: Option<i32>,
+    /// Watermark image width in pixels, keeping its aspect ratio (default: its own size)
+    pub watermark_image_width: Option<i32>,
+    /// CSS color of the watermark text (default: white)
+    pub watermark_color: Option<String>,
+    /// Watermark opacity, 0-100 (default: 50)
+    pub watermark_opacity: Option<i32>,
+    /// Where the watermark is placed (default: bottom right)
+    pub watermark_position: Option<WatermarkPosition>,
+    /// Distance in pixels from the image edges, and between tiles with `watermark_tile`
+    /// (default: 1/50 of the image's shorter side)
+    pub watermark_margin: Option<i32>,
+    /// Repeat the watermark across the whole image (`watermark_position` is ignored)
+    pub watermark_tile: Option<bool>,
+    /// Convert the image to grayscale
+    pub grayscale: Option<bool>,
+    /// Brightness change in percent, -100 (black) to 100 (twice as bright)
+    pub brightness: Option<i32>,
+    /// Contrast change in percent, -100 (flat gray) to 100 (twice the contrast)
+    pub contrast: Option<i32>,
+    /// Rotate the hue of every pixel by this many degrees
+    pub hue_rotate: Option<i32>,
+    /// Gamma correction (greater than 1 brightens the midtones, less than 1 darkens them)
+    pub gamma: Option<f64>,
+    /// Invert the colors (alpha is kept)
+    pub invert: Option<bool>,
+    /// Gaussian blur radius (sigma) in pixels
+    pub blur: Option<f64>,
+    /// Unsharp-mask sharpening radius (sigma) in pixels, e.g. 1.0
+    pub sharpen: Option<f64>,
+    /// With `sharpen`: only sharpen where the difference to the blurred image is greater
+    /// than this, 0-255 (default: 0)
+    pub sharpen_threshold: Option<i32>,
+    /// Contact sheet columns (default: enough for a roughly square grid)
+    pub sheet_columns: Option<i32>,
+    /// Contact sheet cell width in pixels; thumbnails are shrunk to fit (default: 200)
+    pub sheet_cell_width: Option<i32>,
+    /// Contact sheet cell height in pixels (default: 200)
+    pub sheet_cell_height: Option<i32>,
+    /// Pixels between the contact sheet cells and around them (default: 10)
+    pub sheet_spacing: Option<i32>,
+    /// CSS color of the contact sheet background (default: white)
+    pub sheet_background: Option<String>,
+    /// Write each file name under its thumbnail
+    pub sheet_captions: Option<bool>,
+    /// What happens to the input's embedded ICC color profile (default: preserve)
+    pub icc_profile: Option<IccProfileMode>,
+}
+
+/// How an image is resized to the target width/height
+///
+/// With only one of width/height set, the other follows from the aspect ratio.
+#[frb]
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub enum ResizeFit {
+    /// Scale to fit inside the box, keeping the aspect ratio
+    #[default]
+    Contain,
+    /// Scale to fill the box, keeping the aspect ratio and cropping the overflow
+    Cover,
+    /// Stretch to exactly the given size
+    Exact,
+    /// Like `Contain`, but only ever shrinks (e.g. "cap at 1920px wide")
+    MaxDimension,
+}
+
+/// Resampling filter
+#[frb]
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub enum ResizeFilter {
+    Nearest,
+    /// Bilinear
+    Triangle,
+    CatmullRom,
+    Gaussian,
+    #[default]
+    Lanczos3,
+}
+
+/// PNG row filter, applied before compression
+#[frb]
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub enum PngFilter {
+    NoFilter,
+    Sub,
+    Up,
+    Average,
+    Paeth,
+    /// Picks the best filter per row
+    #[default]
+    Adaptive,
+}
+
+/// TIFF compression scheme (all lossless)
+#[frb]
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub enum TiffCompression {
+    Uncompressed,
+    #[default]
+    Lzw,
+    Deflate,
+    PackBits,
+}
+
+/// PDF page size; fixed sizes turn to landscape for images wider than tall
+#[frb]
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub enum PdfPageSize {
+    /// The size of the image (plus margins)
+    #[default]
+    Image,
+    A3,
+    A4,
+    A5,
+    Letter,
+    Legal,
+}
+
+/// Watermark anchor within the image
+#[frb]
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub enum WatermarkPosition {
+    TopLeft,
+    Top,
+    TopRight,
+    Left,
+    Center,
+    Right,
+    BottomLeft,
+    Bottom,
+    #[default]
+    BottomRight,
+}
+
+/// Handling of an image's embedded ICC color profile (e.g. Adobe RGB, Display P3)
+#[frb]
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub enum IccProfileMode {
+    /// Embed the profile in JPEG, PNG, WebP, TIFF and PDF output; other formats cannot carry
+    /// one, so their colors are converted to sRGB (with a warning). Grayscale images and
+    /// `grayscale` output are always converted.
+    #[default]
+    Preserve,
+    /// Convert the colors to sRGB and leave the profile out
+    ConvertToSrgb,
+}
+
+/// Rectangle in pixels
+#[frb]
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct CropRect {
+    pub x: i32,
+    pub y: i32,
+    pub width: i32,
+    pub height: i32,
+}
+
+/// Mirror direction
+#[frb]
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Flip {
+    Horizontal,
+    Vertical,
+    Both,
+}
+
+/// Image metadata handling
+///
+/// The EXIF orientation is always applied to the pixels and reset in the kept EXIF.
+/// ICC color profiles are color data, not metadata, and are kept in every mode.
+#[frb]
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub enum MetadataMode {
+    /// Drop all EXIF and XMP metadata
+    StripAll,
+    /// Keep EXIF and XMP, except GPS location
+    #[default]
+    StripGps,
+    /// Keep EXIF and XMP as they are
+    Preserve,
+}
+
+/// What to do when the output file already exists
+#[frb]
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub enum OnConflict {
+    /// Replace the existing file
+    #[default]
+    Overwrite,
+    /// Keep the existing file and do not convert
+    Skip,
+    /// Write to `name_1.ext`, `name_2.ext`, ... instead
+    Rename,
+    /// Report an `OutputExists` error
+    Fail,
 }
 
 /// Conversion result
 #[frb]
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize)]
 pub struct ConvertResult {
     /// Whether the conversion was successful
     pub success: bool,
@@ -47,30 +361,126 @@ pub struct ConvertResult {
     pub output_path: Option<String>,
     /// Error message
     pub error: Option<String>,
+    /// Stable error code (for matching on the Dart side instead of the message)
+    pub error_code: Option<ConvertErrorCode>,
+    /// Structured error fields
+    pub error_details: Option<ConvertErrorDetails>,
+    /// The output file already existed and was kept (`OnConflict::Skip`)
+    pub skipped: bool,
+    /// Formats the file went through, e.g. `["docx", "md", "pdf"]` for a two-step conversion
+    /// (empty if no route was found)
+    pub route: Vec<String>,
+    /// Every file written; several when extracting animation frames (`output_path` is the first)
+    pub output_paths: Vec<String>,
+    /// Non-fatal issues, e.g. transparency removed for a format without alpha
+    pub warnings: Vec<String>,
+    /// Quality chosen to fit `ConvertOptions::target_size_bytes`
+    pub image_quality: Option<i32>,
+}
+
+/// Error code
+#[frb]
+#[derive(Debug, Clone, Copy, PartialEq, Serialize)]
+pub enum ConvertErrorCode {
+    /// A required external tool is not installed / not found
+    ToolMissing,
+    /// The input/output format pair is not supported
+    UnsupportedPair,
+    /// The input file type is not recognized
+    UnsupportedFileType,
+    /// Failed to decode / parse the input
+    Decode,
+    /// Failed to encode / serialize the output
+    Encode,
+    /// File system error
+    Io,
+    /// An external tool exited with an error
+    ExternalToolFailed,
+    /// Unexpected internal error
+    Internal,
+    /// The conversion was cancelled (see `cancel_conversion`)
+    Cancelled,
+    /// The output file already exists (`OnConflict::Fail`)
+    OutputExists,
+    /// An option value is invalid (e.g. an unsupported rotation angle)
+    InvalidOption,
+}
+
+/// Structured error fields (only the ones relevant to the error code are set)
+#[frb]
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct ConvertErrorDetails {
+    /// External tool name (e.g., "ffmpeg", "pandoc")
+    pub tool: Option<String>,
+    /// Source format
+    pub from: Option<String>,
+    /// Target format
+    pub to: Option<String>,
+    /// External tool exit code
+    pub exit_code: Option<i32>,
+    /// External tool stderr output
+    pub stderr: Option<String>,
 }
 
 /// Conversion progress
 #[frb]
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize)]
 pub struct ConvertProgress {
     /// Task ID
     pub task_id: String,
-    /// Progress percentage (0-100)
+    /// Input file being converted
+    pub input_path: String,
+    /// Index of the file within the batch (0 for single-file conversions)
+    pub file_index: i32,
+    /// Number of files in the batch (1 for single-file conversions)
+    pub file_count: i32,
+    /// Progress percentage of the current file (0-100)
     pub progress: i32,
     /// Current status
     pub status: String,
 }
 
-/// Detect file type
+/// Which files `convert_directory` picks up
+#[frb]
+#[derive(Debug, Clone, Default)]
+pub struct DirectoryFilter {
+    /// Glob patterns (`*`, `**`, `?`, `[...]`) a file must match (empty = all files).
+    /// Patterns without `/` match the file name, others the path relative to the input directory.
+    pub include: Vec<String>,
+    /// Glob patterns of files and folders to skip
+    pub exclude: Vec<String>,
+    /// File types to convert (empty = every file that can be converted to the output format)
+    pub file_types: Vec<FileType>,
+}
+
+/// Result of one file of a directory conversion
+#[frb]
+#[derive(Debug, Clone, Serialize)]
+pub struct DirectoryFileResult {
+    /// Input file path
+    pub input_path: String,
+    /// Path relative to the input directory (`/`-separated)
+    pub relative_path: String,
+    /// Conversion result
+    pub result: ConvertResult,
+}
+
+/// Detect file type (by content, falling back to the extension)
 #[frb]
 pub fn detect_file_type(file_path: String) -> Option<FileType> {
-    let ext = std::path::Path::new(&file_path)
-        .extension()?
-        .to_str()?
-        .to_lowercase();
+    crate::detect::detect(std::path::Path::new(&file_path)).file_type
+}
+
+/// Detect file type and format, reporting whether the content matches the extension
+#[frb]
+pub fn detect_file(file_path: String) -> FileDetection {
+    crate::detect::detect(std::path::Path::new(&file_path))
+}
 
-    match ext.as_str() {
-        "png" | "jpg" | "jpeg" | "webp" | "bmp" | "ico" | "svg" | "gif" => Some(FileType::Image),
+pub(crate) fn file_type_for_extension(ext: &str) -> Option<FileType> {
+    match ext {
+        "png" | "jpg" | "jpeg" | "webp" | "bmp" | "ico" | "svg" | "gif" | "avif" | "tif"
+        | "tiff" | "tga" | "qoi" | "pnm" => Some(FileType::Image),
         "pdf" | "md" | "markdown" | "html" | "htm" | "txt" | "doc" | "docx" | "ppt" | "pptx"
         | "epub" => Some(FileType::Document),
         "mp3" | "wav" | "flac" | "aac" | "ogg" | "m4a" => Some(FileType::Audio),
@@ -81,44 +491,26 @@ pub fn detect_file_type(file_path: String) -> Option<FileType> {
 }
 
 /// Get supported output formats
+///
+/// This is the union of the outputs reachable (directly or through intermediate formats)
+/// from every registered input format of this type.
 #[frb]
 pub fn get_supported_output_formats(file_type: FileType) -> Vec<String> {
-    match file_type {
-        FileType::Image => vec![
-            "png".to_string(),
-            "jpg".to_string(),
-            "jpeg".to_string(),
-            "webp".to_string(),
-            "bmp".to_string(),
-            "ico".to_string(),
-            "gif".to_string(),
-        ],
-        FileType::Document => vec![
-            "html".to_string(),
-            "txt".to_string(),
-            // PDF is only supported for certain inputs (e.g., EPUB) via external tools.
-            "pdf".to_string(),
-        ],
-        FileType::Audio => vec![
-            "mp3".to_string(),
-            "wav".to_string(),
-            "flac".to_string(),
-            "aac".to_string(),
-            "ogg".to_string(),
-        ],
-        FileType::Video => vec![
-            "mp4".to_string(),
-            "avi".to_string(),
-            "mkv".to_string(),
-            "webm".to_string(),
-        ],
-        FileType::Config => vec![
-            "yaml".to_string(),
-            "yml".to_string(),
-            "properties".to_string(),
-            "json".to_string(),
-        ],
+    let registry = crate::converters::registry();
+    let mut outputs: Vec<String> = Vec::new();
+    for converter in registry.converters() {
+        for input in converter.inputs() {
+            if file_type_for_extension(input) != Some(file_type) {
+                continue;
+            }
+            for output in reachable_outputs(input) {
+                if !outputs.contains(&output) {
+                    outputs.push(output);
+                }
+            }
+        }
     }
+    outputs
 }
 
 /// Get supported output formats for a specific file (based on extension/type)
@@ -126,61 +518,21 @@ pub fn get_supported_output_formats(file_type: FileType) -> Vec<String> {
 /// Note: This is stricter than `get_supported_output_formats(FileType)` to avoid showing invalid options in the UI.
 #[frb]
 pub fn get_supported_output_formats_for_file(file_path: String) -> Vec<String> {
-    let ext = std::path::Path::new(&file_path)
-        .extension()
-        .and_then(|e| e.to_str())
-        .unwrap_or("")
-        .to_lowercase();
-
-    match ext.as_str() {
-        // Images
-        "png" | "jpg" | "jpeg" | "webp" | "bmp" | "ico" | "svg" | "gif" => {
-            get_supported_output_formats(FileType::Image)
-        }
+    let detection = crate::detect::detect(std::path::Path::new(&file_path));
+    let format = detection.format.unwrap_or_default();
+
+    reachable_outputs(&format)
+}
 
-        // Markdown -> (html/txt/docx/pptx/pdf)
-        "md" | "markdown" => vec![
-            "html".to_string(),
-            "txt".to_string(),
-            "docx".to_string(),
-            "pptx".to_string(),
-            "pdf".to_string(),
-        ],
-
-        // Documents: plain-text-ish -> html/txt
-        "html" | "htm" | "txt" => vec!["html".to_string(), "txt".to_string()],
-
-        // Office -> Markdown (via pandoc)
-        "docx" | "pptx" => vec!["md".to_string()],
-
-        // Documents: epub -> pdf (requires external tools)
-        "epub" => vec!["pdf".to_string()],
-
-        // PDF input (conversion not implemented)
-        "pdf" => vec![],
-
-        // Audio: not implemented yet
-        "mp3" | "wav" | "flac" | "aac" | "ogg" | "m4a" => vec![],
-
-        // Video -> Audio (via FFmpeg)
-        "mp4" | "avi" | "mkv" | "mov" | "webm" | "flv" => vec![
-            "mp3".to_string(),
-            "wav".to_string(),
-            "aac".to_string(),
-            "flac".to_string(),
-        ],
-
-        // Config files: YAML <-> Properties, YAML <-> JSON, Properties <-> JSON
-        "yaml" | "yml" => vec!["properties".to_string(), "json".to_string()],
-        "properties" => vec!["yaml".to_string(), "yml".to_string(), "json".to_string()],
-        "json" => vec![
-            "yaml".to_string(),
-            "yml".to_string(),
-            "properties".to_string(),
-        ],
-
-        _ => vec![],
+/// Direct outputs first, then formats reachable through intermediate conversions
+fn reachable_outputs(input: &str) -> Vec<String> {
+    let mut outputs = crate::converters::registry().outputs_for(input);
+    for output in crate::converters::graph::graph().reachable_from(input) {
+        if !outputs.contains(&output) {
+            outputs.push(output);
+        }
     }
+    outputs
 }
 
 /// Convert single file
@@ -193,6 +545,22 @@ pub fn convert_file(
     crate::converters::convert_single(&input_path, &output_dir, &options)
 }
 
+/// Convert single file, streaming progress events
+#[frb]
+pub fn convert_file_with_progress(
+    input_path: String,
+    output_dir: String,
+    options: ConvertOptions,
+    sink: StreamSink<ConvertProgress>,
+) -> ConvertResult {
+    crate::converters::convert_single_with_progress(
+        &input_path,
+        &output_dir,
+        &options,
+        progress_callback(sink),
+    )
+}
+
 /// Batch convert files
 #[frb]
 pub fn convert_files(
@@ -200,10 +568,129 @@ pub fn convert_files(
     output_dir: String,
     options: ConvertOptions,
 ) -> Vec<ConvertResult> {
-    input_paths
-        .iter()
-        .map(|path| crate::converters::convert_single(path, &output_dir, &options))
-        .collect()
+    crate::converters::convert_batch(&input_paths, &output_dir, &options, None)
+}
+
+/// Batch convert files, streaming per-file progress events
+#[frb]
+pub fn convert_files_with_progress(
+    input_paths: Vec<String>,
+    output_dir: String,
+    options: ConvertOptions,
+    sink: StreamSink<ConvertProgress>,
+) -> Vec<ConvertResult> {
+    crate::converters::convert_batch(
+        &input_paths,
+        &output_dir,
+        &options,
+        Some(progress_callback(sink)),
+    )
+}
+
+/// Put images, in the given order, into one multi-page PDF in `output_dir`
+///
+/// The PDF is named after the first image; see the `pdf_*` options for the page layout.
+#[frb]
+pub fn combine_images_to_pdf(
+    input_paths: Vec<String>,
+    output_dir: String,
+    options: ConvertOptions,
+) -> ConvertResult {
+    crate::converters::combine_pdf(&input_paths, &output_dir, &options, None)
+}
+
+/// Put images into one multi-page PDF, streaming progress events
+#[frb]
+pub fn combine_images_to_pdf_with_progress(
+    input_paths: Vec<String>,
+    output_dir: String,
+    options: ConvertOptions,
+    sink: StreamSink<ConvertProgress>,
+) -> ConvertResult {
+    crate::converters::combine_pdf(
+        &input_paths,
+        &output_dir,
+        &options,
+        Some(progress_callback(sink)),
+    )
+}
+
+/// Lay images out, in the given order, as a grid of thumbnails in one image in `output_dir`
+///
+/// The image is named `contact_sheet.<output_format>`; see the `sheet_*` options for the layout.
+#[frb]
+pub fn create_contact_sheet(
+    input_paths: Vec<String>,
+    output_dir: String,
+    options: ConvertOptions,
+) -> ConvertResult {
+    crate::converters::contact_sheet(&input_paths, &output_dir, &options, None)
+}
+
+/// Lay images out as a contact sheet, streaming progress events
+#[frb]
+pub fn create_contact_sheet_with_progress(
+    input_paths: Vec<String>,
+    output_dir: String,
+    options: ConvertOptions,
+    sink: StreamSink<ConvertProgress>,
+) -> ConvertResult {
+    crate::converters::contact_sheet(
+        &input_paths,
+        &output_dir,
+        &options,
+        Some(progress_callback(sink)),
+    )
+}
+
+/// Convert all matching files of a directory tree, mirroring its folder structure under `output_dir`
+#[frb]
+pub fn convert_directory(
+    input_dir: String,
+    output_dir: String,
+    options: ConvertOptions,
+    filter: DirectoryFilter,
+) -> Vec<DirectoryFileResult> {
+    crate::converters::directory::convert_directory(
+        &input_dir,
+        &output_dir,
+        &options,
+        &filter,
+        None,
+    )
+}
+
+/// Convert a directory tree, streaming per-file progress events
+#[frb]
+pub fn convert_directory_with_progress(
+    input_dir: String,
+    output_dir: String,
+    options: ConvertOptions,
+    filter: DirectoryFilter,
+    sink: StreamSink<ConvertProgress>,
+) -> Vec<DirectoryFileResult> {
+    crate::converters::directory::convert_directory(
+        &input_dir,
+        &output_dir,
+        &options,
+        &filter,
+        Some(progress_callback(sink)),
+    )
+}
+
+fn progress_callback(sink: StreamSink<ConvertProgress>) -> crate::progress::ProgressCallback {
+    std::sync::Arc::new(move |progress| {
+        // The Dart side may have stopped listening; the conversion keeps going regardless
+        let _ = sink.add(progress);
+    })
+}
+
+/// Cancel a running conversion (single file or batch) by task ID
+///
+/// Returns false if no conversion with this task ID is running.
+#[frb]
+pub fn cancel_conversion(task_id: String) -> bool {
+    crate::task::cancel(&task_id)
 }
 
 /// Open folder
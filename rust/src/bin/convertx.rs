@@ -0,0 +1,679 @@
+//! `convertx` command-line interface
+//!
+//! Every command prints JSON to stdout; `--progress` streams `ConvertProgress` events to
+//! stderr as JSON lines.
+
+use clap::{Args, Parser, Subcommand};
+use convertx_core::api::{
+    self, ConvertErrorCode, ConvertOptions, ConvertResult, CropRect, DirectoryFilter, FileType,
+    Flip, IccProfileMode, MetadataMode, OnConflict, PdfPageSize, PngFilter, ResizeFilter,
+    ResizeFit, TiffCompression, WatermarkPosition,
+};
+use convertx_core::progress::ProgressCallback;
+use serde::Serialize;
+use std::process::ExitCode;
+use std::sync::Arc;
+
+/// All conversions succeeded (or were skipped)
+const EXIT_OK: u8 = 0;
+/// At least one conversion failed
+const EXIT_FAILED: u8 = 1;
+/// Invalid command-line arguments (clap's own exit code)
+const EXIT_USAGE: u8 = 2;
+/// The input type or format pair is not supported
+const EXIT_UNSUPPORTED: u8 = 3;
+/// A required external tool (FFmpeg, pandoc, ...) is missing
+const EXIT_TOOL_MISSING: u8 = 4;
+/// The conversion was cancelled
+const EXIT_CANCELLED: u8 = 5;
+/// An output file already exists (`--on-conflict fail`)
+const EXIT_OUTPUT_EXISTS: u8 = 6;
+/// An option value is invalid (e.g. an unsupported rotation angle)
+const EXIT_INVALID_OPTION: u8 = 7;
+
+#[derive(Parser)]
+#[command(name = "convertx", version, about = "ConvertX file converter")]
+#[command(
+    after_help = "Exit codes: 0 success, 1 conversion failed, 2 invalid arguments, \
+3 unsupported file type or format pair, 4 required tool missing, 5 cancelled, \
+6 output file exists, 7 invalid option value. With several files, the first failure decides."
+)]
+struct Cli {
+    /// Pretty-print the JSON output
+    #[arg(long, global = true)]
+    pretty: bool,
+
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Convert one file (prints a result object) or several files (prints an array)
+    Convert {
+        /// Input files
+        #[arg(required = true)]
+        inputs: Vec<String>,
+        /// Output directory
+        #[arg(short, long)]
+        output_dir: String,
+        /// Put all input images, in order, into one multi-page PDF (prints a result object)
+        #[arg(long)]
+        combine: bool,
+        /// Lay all input images out as thumbnails in one contact_sheet.<format> image (prints
+        /// a result object)
+        #[arg(long, conflicts_with = "combine")]
+        contact_sheet: bool,
+        #[command(flatten)]
+        options: OptionArgs,
+    },
+    /// Convert every matching file of a directory tree, mirroring its folders
+    ConvertDir {
+        /// Input directory
+        input_dir: String,
+        /// Output directory
+        #[arg(short, long)]
+        output_dir: String,
+        /// Only convert files matching this glob (repeatable)
+        #[arg(long = "include", value_name = "GLOB")]
+        include: Vec<String>,
+        /// Skip files and folders matching this glob (repeatable)
+        #[arg(long = "exclude", value_name = "GLOB")]
+        exclude: Vec<String>,
+        /// Only convert files of this type (repeatable)
+        #[arg(long = "type", value_name = "TYPE", value_parser = parse_file_type)]
+        file_types: Vec<FileType>,
+        #[command(flatten)]
+        options: OptionArgs,
+    },
+    /// Detect the type and format of files from their content
+    Detect {
+        /// Files to inspect
+        #[arg(required = true)]
+        files: Vec<String>,
+    },
+    /// List supported output formats for a file type or a specific file
+    Formats {
+        /// File type (image, document, audio, video, config)
+        #[arg(
+            long = "type",
+            value_name = "TYPE",
+            value_parser = parse_file_type,
+            conflicts_with = "file",
+            required_unless_present = "file"
+        )]
+        file_type: Option<FileType>,
+        /// File to list the output formats for
+        #[arg(long)]
+        file: Option<String>,
+    },
+}
+
+/// Flags mapping to `ConvertOptions`
+#[derive(Args)]
+struct OptionArgs {
+    /// Output format (e.g. png, pdf, mp3)
+    #[arg(short = 'f', long = "format")]
+    output_format: String,
+    /// Image quality (1-100, JPEG/WebP)
+    #[arg(long)]
+    image_quality: Option<i32>,
+    /// Audio quality (0-9 for MP3/OGG, lower is better)
+    #[arg(long)]
+    audio_quality: Option<i32>,
+    /// Audio bitrate in kbps
+    #[arg(long)]
+    audio_bitrate: Option<i32>,
+    /// Audio sample rate in Hz
+    #[arg(long)]
+    audio_sample_rate: Option<i32>,
+    /// Video CRF (0-51, lower is better)
+    #[arg(long)]
+    video_crf: Option<i32>,
+    /// Video bitrate in kbps
+    #[arg(long)]
+    video_bitrate: Option<i32>,
+    /// Video width
+    #[arg(long)]
+    video_width: Option<i32>,
+    /// Video height
+    #[arg(long)]
+    video_height: Option<i32>,
+    /// FFmpeg executable path
+    #[arg(long)]
+    ffmpeg_path: Option<String>,
+    /// Task ID reported in progress events
+    #[arg(long)]
+    task_id: Option<String>,
+    /// Maximum number of in-process conversions run in parallel
+    #[arg(long)]
+    max_parallel: Option<i32>,
+    /// Maximum number of external tool jobs run in parallel
+    #[arg(long)]
+    max_parallel_external: Option<i32>,
+    /// What to do when the output file exists (overwrite, skip, rename, fail)
+    #[arg(long, value_parser = parse_on_conflict)]
+    on_conflict: Option<OnConflict>,
+    /// Image target width in pixels
+    #[arg(long)]
+    image_width: Option<i32>,
+    /// Image target height in pixels
+    #[arg(long)]
+    image_height: Option<i32>,
+    /// Resize fit mode (contain, cover, exact, max-dimension)
+    #[arg(long, value_parser = parse_resize_fit)]
+    resize_fit: Option<ResizeFit>,
+    /// Resize filter (nearest, triangle, catmull-rom, gaussian, lanczos3)
+    #[arg(long, value_parser = parse_resize_filter)]
+    resize_filter: Option<ResizeFilter>,
+    /// Crop rectangle in pixels
+    #[arg(long, value_name = "X,Y,WIDTH,HEIGHT", value_parser = parse_crop)]
+    crop: Option<CropRect>,
+    /// Clockwise rotation in degrees (90, 180, 270)
+    #[arg(long)]
+    rotate: Option<i32>,
+    /// Mirror the image (horizontal, vertical, both)
+    #[arg(long, value_parser = parse_flip)]
+    flip: Option<Flip>,
+    /// Image metadata to keep (strip-all, strip-gps, preserve)
+    #[arg(long, value_parser = parse_metadata)]
+    metadata: Option<MetadataMode>,
+    /// SVG rasterization DPI (96 keeps the SVG's own size)
+    #[arg(long)]
+    svg_dpi: Option<i32>,
+    /// Background color for SVG rendering and for flattening transparency (e.g. white, #ff8800)
+    #[arg(long)]
+    background: Option<String>,
+    /// Font family for SVG text whose fonts are not installed
+    #[arg(long)]
+    svg_font_family: Option<String>,
+    /// Convert only this frame (0-based) of an animated input
+    #[arg(long)]
+    frame: Option<i32>,
+    /// Write every frame as a numbered image (name_001.png, ...)
+    #[arg(long)]
+    extract_frames: bool,
+    /// Icon sizes written to ICO output
+    #[arg(long, value_name = "SIZES", value_delimiter = ',')]
+    ico_sizes: Option<Vec<i32>>,
+    /// Embedded icon size to read from ICO input
+    #[arg(long)]
+    ico_size: Option<i32>,
+    /// Encode WebP losslessly (image quality is ignored)
+    #[arg(long)]
+    webp_lossless: bool,
+    /// WebP alpha channel quality (0-100, 100 is lossless)
+    #[arg(long)]
+    webp_alpha_quality: Option<i32>,
+    /// PNG compression level (0-9)
+    #[arg(long)]
+    png_compression: Option<i32>,
+    /// PNG row filter (none, sub, up, average, paeth, adaptive)
+    #[arg(long, value_parser = parse_png_filter)]
+    png_filter: Option<PngFilter>,
+    /// Quantize PNG output to this many colors (2-256, lossy)
+    #[arg(long)]
+    png_colors: Option<i32>,
+    /// Do not dither when quantizing PNG output
+    #[arg(long)]
+    no_png_dither: bool,
+    /// Losslessly optimize PNG output (slower)
+    #[arg(long)]
+    png_optimize: bool,
+    /// AVIF encoder speed (1-10, slower is smaller)
+    #[arg(long)]
+    avif_speed: Option<i32>,
+    /// TIFF compression (none, lzw, deflate, packbits)
+    #[arg(long, value_parser = parse_tiff_compression)]
+    tiff_compression: Option<TiffCompression>,
+    /// Pick the highest JPEG/WebP/AVIF quality that fits in this many bytes
+    #[arg(long)]
+    target_size_bytes: Option<i32>,
+    /// Downscale instead of going below quality 50 to reach --target-size-bytes
+    #[arg(long)]
+    target_size_downscale: bool,
+    /// PDF page size (image, a3, a4, a5, letter, legal)
+    #[arg(long, value_parser = parse_pdf_page_size)]
+    pdf_page_size: Option<PdfPageSize>,
+    /// PDF page margin in millimeters
+    #[arg(long)]
+    pdf_margin_mm: Option<i32>,
+    /// How images are fitted into PDF pages (contain, cover, exact, max-dimension)
+    #[arg(long, value_parser = parse_resize_fit)]
+    pdf_fit: Option<ResizeFit>,
+    /// Re-encode every image of a PDF as JPEG with this quality (1-100)
+    #[arg(long)]
+    pdf_jpeg_quality: Option<i32>,
+    /// Watermark text stamped onto image outputs
+    #[arg(long, conflicts_with = "watermark_image")]
+    watermark_text: Option<String>,
+    /// Watermark image (e.g. a PNG logo) stamped onto image outputs
+    #[arg(long)]
+    watermark_image: Option<String>,
+    /// Watermark text font family
+    #[arg(long)]
+    watermark_font_family: Option<String>,
+    /// Watermark text size in pixels
+    #[arg(long)]
+    watermark_font_size: Option<i32>,
+    /// Watermark image width in pixels
+    #[arg(long)]
+    watermark_image_width: Option<i32>,
+    /// Watermark text color (CSS color, default white)
+    #[arg(long)]
+    watermark_color: Option<String>,
+    /// Watermark opacity (0-100, default 50)
+    #[arg(long)]
+    watermark_opacity: Option<i32>,
+    /// Watermark position (top-left, top, top-right, left, center, right, bottom-left,
+    /// bottom, bottom-right)
+    #[arg(long, value_parser = parse_watermark_position)]
+    watermark_position: Option<WatermarkPosition>,
+    /// Watermark distance from the edges (and between tiles) in pixels
+    #[arg(long)]
+    watermark_margin: Option<i32>,
+    /// Repeat the watermark across the whole image
+    #[arg(long)]
+    watermark_tile: bool,
+    /// Convert images to grayscale
+    #[arg(long)]
+    grayscale: bool,
+    /// Brightness change in percent (-100 to 100)
+    #[arg(long, allow_hyphen_values = true)]
+    brightness: Option<i32>,
+    /// Contrast change in percent (-100 to 100)
+    #[arg(long, allow_hyphen_values = true)]
+    contrast: Option<i32>,
+    /// Rotate the hue by this many degrees
+    #[arg(long, allow_hyphen_values = true)]
+    hue_rotate: Option<i32>,
+    /// Gamma correction (e.g. 1.2)
+    #[arg(long)]
+    gamma: Option<f64>,
+    /// Invert the colors
+    #[arg(long)]
+    invert: bool,
+    /// Gaussian blur radius (sigma) in pixels
+    #[arg(long)]
+    blur: Option<f64>,
+    /// Unsharp-mask sharpening radius (sigma) in pixels
+    #[arg(long)]
+    sharpen: Option<f64>,
+    /// Minimum difference (0-255) sharpened by --sharpen
+    #[arg(long)]
+    sharpen_threshold: Option<i32>,
+    /// Contact sheet columns
+    #[arg(long)]
+    sheet_columns: Option<i32>,
+    /// Contact sheet cell width in pixels
+    #[arg(long)]
+    sheet_cell_width: Option<i32>,
+    /// Contact sheet cell height in pixels
+    #[arg(long)]
+    sheet_cell_height: Option<i32>,
+    /// Pixels between and around the contact sheet cells
+    #[arg(long)]
+    sheet_spacing: Option<i32>,
+    /// Contact sheet background color (CSS, e.g. "black" or "#202020")
+    #[arg(long)]
+    sheet_background: Option<String>,
+    /// Write each file name under its contact sheet thumbnail
+    #[arg(long)]
+    sheet_captions: bool,
+    /// Embedded ICC color profile: preserve or convert-to-srgb
+    #[arg(long, value_parser = parse_icc_profile_mode)]
+    icc_profile: Option<IccProfileMode>,
+    /// Stream progress events to stderr as JSON lines
+    #[arg(long)]
+    progress: bool,
+}
+
+impl OptionArgs {
+    fn to_options(&self) -> ConvertOptions {
+        ConvertOptions {
+            output_format: self.output_format.clone(),
+            image_quality: self.image_quality,
+            audio_quality: self.audio_quality,
+            audio_bitrate: self.audio_bitrate,
+            audio_sample_rate: self.audio_sample_rate,
+            video_crf: self.video_crf,
+            video_bitrate: self.video_bitrate,
+            video_width: self.video_width,
+            video_height: self.video_height,
+            ffmpeg_path: self.ffmpeg_path.clone(),
+            task_id: self.task_id.clone(),
+            max_parallel: self.max_parallel,
+            max_parallel_external: self.max_parallel_external,
+            on_conflict: self.on_conflict,
+            image_width: self.image_width,
+            image_height: self.image_height,
+            resize_fit: self.resize_fit,
+            resize_filter: self.resize_filter,
+            crop: self.crop,
+            rotate: self.rotate,
+            flip: self.flip,
+            metadata: self.metadata,
+            svg_dpi: self.svg_dpi,
+            background: self.background.clone(),
+            svg_font_family: self.svg_font_family.clone(),
+            frame: self.frame,
+            extract_frames: self.extract_frames.then_some(true),
+            ico_sizes: self.ico_sizes.clone(),
+            ico_size: self.ico_size,
+            webp_lossless: self.webp_lossless.then_some(true),
+            webp_alpha_quality: self.webp_alpha_quality,
+            png_compression: self.png_compression,
+            png_filter: self.png_filter,
+            png_colors: self.png_colors,
+            png_dither: self.no_png_dither.then_some(false),
+            png_optimize: self.png_optimize.then_some(true),
+            avif_speed: self.avif_speed,
+            tiff_compression: self.tiff_compression,
+            target_size_bytes: self.target_size_bytes,
+            target_size_downscale: self.target_size_downscale.then_some(true),
+            pdf_page_size: self.pdf_page_size,
+            pdf_margin_mm: self.pdf_margin_mm,
+            pdf_fit: self.pdf_fit,
+            pdf_jpeg_quality: self.pdf_jpeg_quality,
+            watermark_text: self.watermark_text.clone(),
+            watermark_image: self.watermark_image.clone(),
+            watermark_font_family: self.watermark_font_family.clone(),
+            watermark_font_size: self.watermark_font_size,
+            watermark_image_width: self.watermark_image_width,
+            watermark_color: self.watermark_color.clone(),
+            watermark_opacity: self.watermark_opacity,
+            watermark_position: self.watermark_position,
+            watermark_margin: self.watermark_margin,
+            watermark_tile: self.watermark_tile.then_some(true),
+            grayscale: self.grayscale.then_some(true),
+            brightness: self.brightness,
+            contrast: self.contrast,
+            hue_rotate: self.hue_rotate,
+            gamma: self.gamma,
+            invert: self.invert.then_some(true),
+            blur: self.blur,
+            sharpen: self.sharpen,
+            sharpen_threshold: self.sharpen_threshold,
+            sheet_columns: self.sheet_columns,
+            sheet_cell_width: self.sheet_cell_width,
+            sheet_cell_height: self.sheet_cell_height,
+            sheet_spacing: self.sheet_spacing,
+            sheet_background: self.sheet_background.clone(),
+            sheet_captions: self.sheet_captions.then_some(true),
+            icc_profile: self.icc_profile,
+        }
+    }
+
+    fn progress_callback(&self) -> Option<ProgressCallback> {
+        self.progress.then(|| -> ProgressCallback {
+            Arc::new(|progress| {
+                if let Ok(line) = serde_json::to_string(&progress) {
+                    eprintln!("{}", line);
+                }
+            })
+        })
+    }
+}
+
+fn parse_file_type(value: &str) -> Result<FileType, String> {
+    match value.to_lowercase().as_str() {
+        "image" => Ok(FileType::Image),
+        "document" => Ok(FileType::Document),
+        "audio" => Ok(FileType::Audio),
+        "video" => Ok(FileType::Video),
+        "config" => Ok(FileType::Config),
+        _ => Err("expected image, document, audio, video or config".to_string()),
+    }
+}
+
+fn parse_on_conflict(value: &str) -> Result<OnConflict, String> {
+    match value.to_lowercase().as_str() {
+        "overwrite" => Ok(OnConflict::Overwrite),
+        "skip" => Ok(OnConflict::Skip),
+        "rename" => Ok(OnConflict::Rename),
+        "fail" => Ok(OnConflict::Fail),
+        _ => Err("expected overwrite, skip, rename or fail".to_string()),
+    }
+}
+
+fn parse_resize_fit(value: &str) -> Result<ResizeFit, String> {
+    match value.to_lowercase().as_str() {
+        "contain" => Ok(ResizeFit::Contain),
+        "cover" => Ok(ResizeFit::Cover),
+        "exact" => Ok(ResizeFit::Exact),
+        "max-dimension" => Ok(ResizeFit::MaxDimension),
+        _ => Err("expected contain, cover, exact or max-dimension".to_string()),
+    }
+}
+
+fn parse_resize_filter(value: &str) -> Result<ResizeFilter, String> {
+    match value.to_lowercase().as_str() {
+        "nearest" => Ok(ResizeFilter::Nearest),
+        "triangle" => Ok(ResizeFilter::Triangle),
+        "catmull-rom" => Ok(ResizeFilter::CatmullRom),
+        "gaussian" => Ok(ResizeFilter::Gaussian),
+        "lanczos3" => Ok(ResizeFilter::Lanczos3),
+        _ => Err("expected nearest, triangle, catmull-rom, gaussian or lanczos3".to_string()),
+    }
+}
+
+fn parse_crop(value: &str) -> Result<CropRect, String> {
+    let parts = value
+        .split(',')
+        .map(|part| part.trim().parse::<i32>())
+        .collect::<Result<Vec<_>, _>>()
+        .map_err(|e| e.to_string())?;
+    match parts.as_slice() {
+        &[x, y, width, height] => Ok(CropRect {
+            x,
+            y,
+            width,
+            height,
+        }),
+        _ => Err("expected X,Y,WIDTH,HEIGHT".to_string()),
+    }
+}
+
+fn parse_flip(value: &str) -> Result<Flip, String> {
+    match value.to_lowercase().as_str() {
+        "horizontal" => Ok(Flip::Horizontal),
+        "vertical" => Ok(Flip::Vertical),
+        "both" => Ok(Flip::Both),
+        _ => Err("expected horizontal, vertical or both".to_string()),
+    }
+}
+
+fn parse_metadata(value: &str) -> Result<MetadataMode, String> {
+    match value.to_lowercase().as_str() {
+        "strip-all" => Ok(MetadataMode::StripAll),
+        "strip-gps" => Ok(MetadataMode::StripGps),
+        "preserve" => Ok(MetadataMode::Preserve),
+        _ => Err("expected strip-all, strip-gps or preserve".to_string()),
+    }
+}
+
+fn parse_png_filter(value: &str) -> Result<PngFilter, String> {
+    match value.to_lowercase().as_str() {
+        "none" => Ok(PngFilter::NoFilter),
+        "sub" => Ok(PngFilter::Sub),
+        "up" => Ok(PngFilter::Up),
+        "average" => Ok(PngFilter::Average),
+        "paeth" => Ok(PngFilter::Paeth),
+        "adaptive" => Ok(PngFilter::Adaptive),
+        _ => Err("expected none, sub, up, average, paeth or adaptive".to_string()),
+    }
+}
+
+fn parse_tiff_compression(value: &str) -> Result<TiffCompression, String> {
+    match value.to_lowercase().as_str() {
+        "none" => Ok(TiffCompression::Uncompressed),
+        "lzw" => Ok(TiffCompression::Lzw),
+        "deflate" => Ok(TiffCompression::Deflate),
+        "packbits" => Ok(TiffCompression::PackBits),
+        _ => Err("expected none, lzw, deflate or packbits".to_string()),
+    }
+}
+
+fn parse_pdf_page_size(value: &str) -> Result<PdfPageSize, String> {
+    match value.to_lowercase().as_str() {
+        "image" => Ok(PdfPageSize::Image),
+        "a3" => Ok(PdfPageSize::A3),
+        "a4" => Ok(PdfPageSize::A4),
+        "a5" => Ok(PdfPageSize::A5),
+        "letter" => Ok(PdfPageSize::Letter),
+        "legal" => Ok(PdfPageSize::Legal),
+        _ => Err("expected image, a3, a4, a5, letter or legal".to_string()),
+    }
+}
+
+fn parse_watermark_position(value: &str) -> Result<WatermarkPosition, String> {
+    match value.to_lowercase().as_str() {
+        "top-left" => Ok(WatermarkPosition::TopLeft),
+        "top" => Ok(WatermarkPosition::Top),
+        "top-right" => Ok(WatermarkPosition::TopRight),
+        "left" => Ok(WatermarkPosition::Left),
+        "center" => Ok(WatermarkPosition::Center),
+        "right" => Ok(WatermarkPosition::Right),
+        "bottom-left" => Ok(WatermarkPosition::BottomLeft),
+        "bottom" => Ok(WatermarkPosition::Bottom),
+        "bottom-right" => Ok(WatermarkPosition::BottomRight),
+        _ => Err(
+            "expected top-left, top, top-right, left, center, right, bottom-left, \
+                  bottom or bottom-right"
+                .to_string(),
+        ),
+    }
+}
+
+fn parse_icc_profile_mode(value: &str) -> Result<IccProfileMode, String> {
+    match value.to_lowercase().as_str() {
+        "preserve" => Ok(IccProfileMode::Preserve),
+        "convert-to-srgb" | "srgb" => Ok(IccProfileMode::ConvertToSrgb),
+        _ => Err("expected preserve or convert-to-srgb".to_string()),
+    }
+}
+
+fn main() -> ExitCode {
+    let cli = match Cli::try_parse() {
+        Ok(cli) => cli,
+        Err(e) => {
+            let _ = e.print();
+            return ExitCode::from(if e.use_stderr() { EXIT_USAGE } else { EXIT_OK });
+        }
+    };
+
+    let exit_code = match &cli.command {
+        Command::Convert {
+            inputs,
+            output_dir,
+            combine,
+            contact_sheet,
+            options,
+        } => {
+            let convert_options = options.to_options();
+            let callback = options.progress_callback();
+            if *combine {
+                if !convert_options.output_format.eq_ignore_ascii_case("pdf") {
+                    eprintln!("error: --combine requires --format pdf");
+                    return ExitCode::from(EXIT_USAGE);
+                }
+                let result =
+                    convertx_core::combine_pdf(inputs, output_dir, &convert_options, callback);
+                print_json(&result, cli.pretty);
+                exit_code_for([&result])
+            } else if *contact_sheet {
+                let result =
+                    convertx_core::contact_sheet(inputs, output_dir, &convert_options, callback);
+                print_json(&result, cli.pretty);
+                exit_code_for([&result])
+            } else if let [input] = inputs.as_slice() {
+                let result = match callback {
+                    Some(callback) => convertx_core::convert_single_with_progress(
+                        input,
+                        output_dir,
+                        &convert_options,
+                        callback,
+                    ),
+                    None => api::convert_file(input.clone(), output_dir.clone(), convert_options),
+                };
+                print_json(&result, cli.pretty);
+                exit_code_for([&result])
+            } else {
+                let results =
+                    convertx_core::convert_batch(inputs, output_dir, &convert_options, callback);
+                print_json(&results, cli.pretty);
+                exit_code_for(&results)
+            }
+        }
+        Command::ConvertDir {
+            input_dir,
+            output_dir,
+            include,
+            exclude,
+            file_types,
+            options,
+        } => {
+            let filter = DirectoryFilter {
+                include: include.clone(),
+                exclude: exclude.clone(),
+                file_types: file_types.clone(),
+            };
+            let results = convertx_core::converters::directory::convert_directory(
+                input_dir,
+                output_dir,
+                &options.to_options(),
+                &filter,
+                options.progress_callback(),
+            );
+            print_json(&results, cli.pretty);
+            exit_code_for(results.iter().map(|r| &r.result))
+        }
+        Command::Detect { files } => {
+            let detections: Vec<_> = files
+                .iter()
+                .map(|file| api::detect_file(file.clone()))
+                .collect();
+            print_json(&detections, cli.pretty);
+            EXIT_OK
+        }
+        Command::Formats { file_type, file } => {
+            let formats = match (file_type, file) {
+                (Some(file_type), _) => api::get_supported_output_formats(*file_type),
+                (None, Some(file)) => api::get_supported_output_formats_for_file(file.clone()),
+                (None, None) => Vec::new(),
+            };
+            print_json(&formats, cli.pretty);
+            EXIT_OK
+        }
+    };
+    ExitCode::from(exit_code)
+}
+
+fn print_json(value: &impl Serialize, pretty: bool) {
+    let json = if pretty {
+        serde_json::to_string_pretty(value)
+    } else {
+        serde_json::to_string(value)
+    };
+    match json {
+        Ok(json) => println!("{}", json),
+        Err(e) => eprintln!("Failed to serialize output: {}", e),
+    }
+}
+
+/// Exit code of the first failed conversion
+fn exit_code_for<'a>(results: impl IntoIterator<Item = &'a ConvertResult>) -> u8 {
+    let Some(failed) = results.into_iter().find(|r| !r.success) else {
+        return EXIT_OK;
+    };
+    match failed.error_code {
+        Some(ConvertErrorCode::UnsupportedFileType | ConvertErrorCode::UnsupportedPair) => {
+            EXIT_UNSUPPORTED
+        }
+        Some(ConvertErrorCode::ToolMissing) => EXIT_TOOL_MISSING,
+        Some(ConvertErrorCode::Cancelled) => EXIT_CANCELLED,
+        Some(ConvertErrorCode::OutputExists) => EXIT_OUTPUT_EXISTS,
+        Some(ConvertErrorCode::InvalidOption) => EXIT_INVALID_OPTION,
+        _ => EXIT_FAILED,
+    }
+}
@@ -0,0 +1,208 @@
+use super::{finish_reporting, plan_conversion, run_conversion, task_id_for};
+use crate::api::{ConvertOptions, ConvertResult};
+use crate::error::ConvertError;
+use crate::progress::{ProgressCallback, ProgressReporter};
+use crate::task::TaskHandle;
+use std::sync::Arc;
+use tokio::sync::Semaphore;
+
+/// Default number of external tool jobs run in parallel
+///
+/// FFmpeg, pandoc and Chromium are multi-threaded themselves, so running many at once
+/// mostly adds memory pressure.
+const DEFAULT_MAX_PARALLEL_EXTERNAL: usize = 2;
+
+/// Concurrency limits of a batch
+struct BatchLimits {
+    /// CPU-bound conversions done in-process (images, configs, ...)
+    in_process: usize,
+    /// Conversions running an external tool
+    external: usize,
+}
+
+impl BatchLimits {
+    fn from_options(options: &ConvertOptions) -> Self {
+        let cores = std::thread::available_parallelism().map_or(1, |n| n.get());
+        Self {
+            in_process: limit(options.max_parallel, cores),
+            external: limit(options.max_parallel_external, DEFAULT_MAX_PARALLEL_EXTERNAL),
+        }
+    }
+}
+
+fn limit(value: Option<i32>, default: usize) -> usize {
+    value.filter(|&n| n > 0).map_or(default, |n| n as usize)
+}
+
+/// Convert a batch of files, reporting per-file progress through `on_progress`
+///
+/// Files are converted on a worker pool (see `ConvertOptions::max_parallel` and
+/// `max_parallel_external`). Results are returned in input order and a failing file
+/// does not affect the others.
+pub fn convert_batch(
+    input_paths: &[String],
+    output_dir: &str,
+    options: &ConvertOptions,
+    on_progress: Option<ProgressCallback>,
+) -> Vec<ConvertResult> {
+    let files: Vec<_> = input_paths
+        .iter()
+        .map(|path| (path.clone(), output_dir.to_string()))
+        .collect();
+    convert_batch_to(&files, options, on_progress)
+}
+
+/// Like [`convert_batch`], with an output directory per file (`(input_path, output_dir)`)
+pub(crate) fn convert_batch_to(
+    files: &[(String, String)],
+    options: &ConvertOptions,
+    on_progress: Option<ProgressCallback>,
+) -> Vec<ConvertResult> {
+    let task = TaskHandle::register(&task_id_for(options));
+    let limits = BatchLimits::from_options(options);
+
+    let runtime = match tokio::runtime::Builder::new_multi_thread()
+        .worker_threads(1)
+        .max_blocking_threads(limits.in_process + limits.external)
+        .thread_name("convertx-worker")
+        .build()
+    {
+        Ok(runtime) => runtime,
+        Err(e) => {
+            return files
+                .iter()
+                .map(|_| {
+                    ConvertResult::err(ConvertError::Internal(format!(
+                        "Failed to start conversion workers: {}",
+                        e
+                    )))
+                })
+                .collect()
+        }
+    };
+
+    // Output paths are chosen in input order before any file starts, so which file keeps
+    // the plain name does not depend on timing. The claims are held until the batch ends:
+    // a later file must not overwrite the output of one that already finished.
+    let plans: Vec<_> = files
+        .iter()
+        .map(|(path, output_dir)| plan_conversion(path, output_dir, options).map(Arc::new))
+        .collect();
+    let claims: Vec<_> = plans
+        .iter()
+        .filter_map(|plan| plan.as_ref().ok().cloned())
+        .collect();
+
+    let in_process = Arc::new(Semaphore::new(limits.in_process));
+    let external = Arc::new(Semaphore::new(limits.external));
+    let options = Arc::new(options.clone());
+
+    let handles: Vec<_> = files
+        .iter()
+        .zip(plans)
+        .enumerate()
+        .map(|(index, ((path, _), plan))| {
+            let reporter = match &on_progress {
+                Some(callback) => ProgressReporter::new(
+                    callback.clone(),
+                    task.task_id(),
+                    path,
+                    index,
+                    files.len(),
+                ),
+                None => ProgressReporter::silent(task.task_id(), path),
+            };
+            let options = options.clone();
+            let cancel = task.token().clone();
+            let in_process = in_process.clone();
+            let external = external.clone();
+
+            runtime.spawn(async move {
+                let plan = match plan {
+                    Ok(plan) => plan,
+                    Err(e) => return finish_reporting(&reporter, ConvertResult::err(e)),
+                };
+
+                // Wait for a free slot of the matching kind
+                let semaphore = if plan.uses_external_tool() {
+                    external
+                } else {
+                    in_process
+                };
+                let _permit = semaphore.acquire_owned().await;
+
+                tokio::task::spawn_blocking(move || {
+                    reporter.report(0, "Starting");
+                    let result = run_conversion(&plan, &options, &reporter, &cancel);
+                    finish_reporting(&reporter, result)
+                })
+                .await
+                .unwrap_or_else(panicked)
+            })
+        })
+        .collect();
+
+    let results = runtime.block_on(async {
+        let mut results = Vec::with_capacity(handles.len());
+        for handle in handles {
+            results.push(handle.await.unwrap_or_else(panicked));
+        }
+        results
+    });
+    drop(claims);
+    results
+}
+
+fn panicked(e: tokio::task::JoinError) -> ConvertResult {
+    ConvertResult::err(ConvertError::Internal(format!("Conversion crashed: {}", e)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::api::OnConflict;
+    use image::{Rgb, RgbImage};
+    use std::path::Path;
+
+    #[test]
+    fn files_sharing_a_stem_get_names_in_input_order() {
+        let dir = tempfile::tempdir().unwrap();
+        let image = RgbImage::from_pixel(8, 8, Rgb([200, 30, 30]));
+        let inputs: Vec<_> = ["photo.png", "photo.jpg", "photo.bmp"]
+            .iter()
+            .map(|name| {
+                let path = dir.path().join(name);
+                image.save(&path).unwrap();
+                path.to_string_lossy().to_string()
+            })
+            .collect();
+        let output_dir = dir.path().join("out");
+        let output_dir = output_dir.to_str().unwrap();
+        let options = ConvertOptions {
+            output_format: "webp".to_string(),
+            on_conflict: Some(OnConflict::Overwrite),
+            ..Default::default()
+        };
+
+        // Rerunning overwrites the same files instead of numbering further
+        for _ in 0..3 {
+            let outputs: Vec<_> = convert_batch(&inputs, output_dir, &options, None)
+                .into_iter()
+                .map(|result| {
+                    assert!(result.success, "{:?}", result.error);
+                    result.output_path.unwrap()
+                })
+                .collect();
+            let expected: Vec<_> = ["photo.webp", "photo_1.webp", "photo_2.webp"]
+                .iter()
+                .map(|name| {
+                    Path::new(output_dir)
+                        .join(name)
+                        .to_string_lossy()
+                        .to_string()
+                })
+                .collect();
+            assert_eq!(outputs, expected);
+        }
+    }
+}
@@ -0,0 +1,186 @@
+use super::image::{
+    contact_sheet::write_contact_sheet, pdf::write_pdf, AvifInputConverter, ImageConverter,
+};
+use super::output::{resolve_output_path, OutputPath};
+use super::{finish_reporting, task_id_for, ConvertJob, Converter};
+use crate::api::{ConvertOptions, ConvertResult};
+use crate::error::ConvertError;
+use crate::progress::{ProgressCallback, ProgressReporter};
+use crate::task::{CancelToken, TaskHandle};
+use std::path::{Path, PathBuf};
+use std::sync::Mutex;
+
+/// Writes the images of the jobs into one output file
+type CombineFn = fn(&[ConvertJob], &Path) -> Result<(), ConvertError>;
+
+/// Put images, in the given order, into one multi-page PDF named after the first image
+///
+/// Progress is reported as a single file (`Page i/n`). `options.output_format` is ignored.
+pub fn combine_pdf(
+    input_paths: &[String],
+    output_dir: &str,
+    options: &ConvertOptions,
+    on_progress: Option<ProgressCallback>,
+) -> ConvertResult {
+    combine(
+        input_paths,
+        output_dir,
+        options,
+        on_progress,
+        None,
+        "pdf",
+        write_pdf,
+    )
+}
+
+/// Lay images out, in the given order, as thumbnails in a grid written to one
+/// `contact_sheet.<output_format>` image
+///
+/// Progress is reported as a single file (`Image i/n`).
+pub fn contact_sheet(
+    input_paths: &[String],
+    output_dir: &str,
+    options: &ConvertOptions,
+    on_progress: Option<ProgressCallback>,
+) -> ConvertResult {
+    combine(
+        input_paths,
+        output_dir,
+        options,
+        on_progress,
+        Some("contact_sheet"),
+        &options.output_format.to_lowercase(),
+        write_contact_sheet,
+    )
+}
+
+/// Combine the inputs into one `output_format` file with `write`, named `stem` (default:
+/// after the first input)
+fn combine(
+    input_paths: &[String],
+    output_dir: &str,
+    options: &ConvertOptions,
+    on_progress: Option<ProgressCallback>,
+    stem: Option<&str>,
+    output_format: &str,
+    write: CombineFn,
+) -> ConvertResult {
+    let task = TaskHandle::register(&task_id_for(options));
+    let first = input_paths.first().map_or("", String::as_str);
+    let reporter = match on_progress {
+        Some(callback) => ProgressReporter::new(callback, task.task_id(), first, 0, 1),
+        None => ProgressReporter::silent(task.task_id(), first),
+    };
+
+    reporter.report(0, "Starting");
+    let result = match plan_inputs(input_paths, output_dir, options, stem, output_format) {
+        Ok((inputs, output)) => {
+            let target = Target {
+                output: &output,
+                output_format,
+                write,
+            };
+            write_combined(&inputs, &target, options, &reporter, task.token())
+        }
+        Err(e) => ConvertResult::err(e),
+    };
+    finish_reporting(&reporter, result)
+}
+
+/// Where and how the combined file is written
+struct Target<'a> {
+    output: &'a OutputPath,
+    output_format: &'a str,
+    write: CombineFn,
+}
+
+/// Detect the input formats and choose the output path
+fn plan_inputs(
+    input_paths: &[String],
+    output_dir: &str,
+    options: &ConvertOptions,
+    stem: Option<&str>,
+    output_format: &str,
+) -> Result<(Vec<(PathBuf, String)>, OutputPath), ConvertError> {
+    let Some(first) = input_paths.first() else {
+        return Err(ConvertError::invalid_option(
+            "input_paths",
+            "no images given",
+        ));
+    };
+    std::fs::create_dir_all(output_dir)
+        .map_err(|e| ConvertError::io("Failed to create output directory", e))?;
+
+    let mut inputs = Vec::with_capacity(input_paths.len());
+    for path in input_paths {
+        let path = Path::new(path);
+        let detection = crate::detect::detect(path);
+        let format = detection.format.unwrap_or_default();
+        let is_image = ImageConverter.inputs().contains(&format.as_str())
+            || AvifInputConverter.inputs().contains(&format.as_str());
+        if !is_image {
+            if detection.file_type.is_none() {
+                return Err(ConvertError::UnsupportedFileType { extension: format });
+            }
+            return Err(ConvertError::unsupported_pair(format, output_format));
+        }
+        inputs.push((path.to_path_buf(), format));
+    }
+
+    let stem = stem.unwrap_or_else(|| {
+        Path::new(first)
+            .file_stem()
+            .and_then(|s| s.to_str())
+            .unwrap_or("output")
+    });
+    let output = resolve_output_path(
+        Path::new(output_dir),
+        stem,
+        output_format,
+        options.on_conflict.unwrap_or_default(),
+    )?;
+    Ok((inputs, output))
+}
+
+fn write_combined(
+    inputs: &[(PathBuf, String)],
+    target: &Target,
+    options: &ConvertOptions,
+    reporter: &ProgressReporter,
+    cancel: &CancelToken,
+) -> ConvertResult {
+    let output = target.output;
+    if output.skip() {
+        return ConvertResult::skipped(output.path());
+    }
+
+    let outputs = Mutex::new(Vec::new());
+    let warnings = Mutex::new(output.warning().map(str::to_string).into_iter().collect());
+    let chosen_quality = Mutex::new(None);
+    let jobs: Vec<_> = inputs
+        .iter()
+        .map(|(path, format)| ConvertJob {
+            input_path: path,
+            input_format: format,
+            output_path: output.path(),
+            output_format: target.output_format,
+            options,
+            progress: reporter,
+            cancel,
+            outputs: &outputs,
+            warnings: &warnings,
+            chosen_quality: &chosen_quality,
+        })
+        .collect();
+    // The file is only created once every input is ready, so a failure leaves nothing behind
+    let mut result: ConvertResult = (target.write)(&jobs, output.path())
+        .map(|()| output.path())
+        .into();
+    if result.success {
+        result.warnings = warnings.into_inner().unwrap_or_else(|e| e.into_inner());
+        result.image_quality = chosen_quality
+            .into_inner()
+            .unwrap_or_else(|e| e.into_inner());
+    }
+    result
+}
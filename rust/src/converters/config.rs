@@ -1,73 +1,194 @@
-use crate::api::{ConvertOptions, ConvertResult};
+use super::{ConvertJob, Converter, ConverterRegistry};
+use crate::error::ConvertError;
 use serde_json::Value as JsonValue;
 use serde_yaml::Value as YamlValue;
 use std::collections::HashMap;
 use std::path::Path;
 
-/// Convert configuration files (YAML <-> Properties, YAML <-> JSON, Properties <-> JSON)
-pub fn convert_config(
-    input_path: &str,
-    output_dir: &str,
-    options: &ConvertOptions,
-) -> ConvertResult {
-    let input = Path::new(input_path);
-    let stem = input
-        .file_stem()
-        .and_then(|s| s.to_str())
-        .unwrap_or("output");
-    let output_ext = options.output_format.to_lowercase();
-    let output_name = format!("{}.{}", stem, output_ext);
-    let output_path = Path::new(output_dir).join(&output_name);
-
-    let input_ext = input
-        .extension()
-        .and_then(|e| e.to_str())
-        .unwrap_or("")
-        .to_lowercase();
-
-    match (input_ext.as_str(), output_ext.as_str()) {
-        ("yaml" | "yml", "properties") => yaml_to_properties(input, &output_path),
-        ("properties", "yaml" | "yml") => properties_to_yaml(input, &output_path),
-        ("yaml" | "yml", "json") => yaml_to_json(input, &output_path),
-        ("json", "yaml" | "yml") => json_to_yaml(input, &output_path),
-        ("properties", "json") => properties_to_json(input, &output_path),
-        ("json", "properties") => json_to_properties(input, &output_path),
-        _ => ConvertResult {
-            success: false,
-            output_path: None,
-            error: Some(format!(
-                "Unsupported conversion: {} -> {}",
-                input_ext, output_ext
-            )),
-        },
+/// Configuration files (YAML <-> Properties, YAML <-> JSON, Properties <-> JSON)
+pub struct ConfigConverter {
+    pub inputs: &'static [&'static str],
+    pub outputs: &'static [&'static str],
+    /// Whether the outputs lose structure (Properties turns lists into maps and drops key
+    /// order), which makes them a poor intermediate step
+    pub lossy: bool,
+}
+
+impl Converter for ConfigConverter {
+    fn name(&self) -> &'static str {
+        "config"
+    }
+
+    fn inputs(&self) -> &[&'static str] {
+        self.inputs
+    }
+
+    fn outputs(&self) -> &[&'static str] {
+        self.outputs
+    }
+
+    fn supports(&self, input_ext: &str, output_ext: &str) -> bool {
+        // yaml <-> yml only renames; copying a file onto its own extension is pointless
+        input_ext != output_ext
+            && self.inputs.contains(&input_ext)
+            && self.outputs.contains(&output_ext)
+    }
+
+    fn cost(&self) -> u32 {
+        if self.lossy {
+            8
+        } else {
+            1
+        }
+    }
+
+    fn convert(&self, job: &ConvertJob) -> Result<(), ConvertError> {
+        convert_config(job)
     }
 }
 
-fn yaml_to_properties(input_path: &Path, output_path: &Path) -> ConvertResult {
-    let yaml_content = match std::fs::read_to_string(input_path) {
-        Ok(content) => content,
-        Err(e) => {
-            return ConvertResult {
-                success: false,
-                output_path: None,
-                error: Some(format!("Failed to read YAML file: {}", e)),
-            }
+pub fn register(registry: &mut ConverterRegistry) {
+    registry.register(ConfigConverter {
+        inputs: &["yaml", "yml"],
+        outputs: &["yaml", "yml", "json"],
+        lossy: false,
+    });
+    registry.register(ConfigConverter {
+        inputs: &["yaml", "yml"],
+        outputs: &["properties"],
+        lossy: true,
+    });
+    registry.register(ConfigConverter {
+        inputs: &["properties"],
+        outputs: &["yaml", "yml", "json"],
+        lossy: false,
+    });
+    registry.register(ConfigConverter {
+        inputs: &["json"],
+        outputs: &["yaml", "yml"],
+        lossy: false,
+    });
+    registry.register(ConfigConverter {
+        inputs: &["json"],
+        outputs: &["properties"],
+        lossy: true,
+    });
+}
+
+/// Convert configuration files
+pub fn convert_config(job: &ConvertJob) -> Result<(), ConvertError> {
+    let (input, output_path) = (job.input_path, job.output_path);
+
+    match (job.input_format, job.output_format) {
+        ("yaml" | "yml", "yaml" | "yml") => copy_yaml(input, output_path),
+        ("yaml" | "yml", "properties") => yaml_to_properties(input, output_path),
+        ("properties", "yaml" | "yml") => properties_to_yaml(input, output_path),
+        ("yaml" | "yml", "json") => yaml_to_json(input, output_path),
+        ("json", "yaml" | "yml") => json_to_yaml(input, output_path),
+        ("properties", "json") => properties_to_json(input, output_path),
+        ("json", "properties") => json_to_properties(input, output_path),
+        (input_format, output_format) => {
+            Err(ConvertError::unsupported_pair(input_format, output_format))
         }
-    };
-
-    let yaml_value: YamlValue = match serde_yaml::from_str(&yaml_content) {
-        Ok(value) => value,
-        Err(e) => {
-            return ConvertResult {
-                success: false,
-                output_path: None,
-                error: Some(format!("YAML parsing failed: {}", e)),
-            }
+    }
+}
+
+/// Same format under the other extension: checked, then copied as is to keep comments
+fn copy_yaml(input_path: &Path, output_path: &Path) -> Result<(), ConvertError> {
+    read_yaml(input_path)?;
+    std::fs::copy(input_path, output_path)
+        .map(|_| ())
+        .map_err(|e| ConvertError::io("Failed to write YAML file", e))
+}
+
+fn yaml_to_properties(input_path: &Path, output_path: &Path) -> Result<(), ConvertError> {
+    let yaml_value = read_yaml(input_path)?;
+    write_properties(&yaml_value, output_path)
+}
+
+fn properties_to_yaml(input_path: &Path, output_path: &Path) -> Result<(), ConvertError> {
+    let yaml_value = read_properties(input_path)?;
+    write_yaml(&yaml_value, output_path)
+}
+
+fn yaml_to_json(input_path: &Path, output_path: &Path) -> Result<(), ConvertError> {
+    let yaml_value = read_yaml(input_path)?;
+    write_json(&yaml_value, output_path)
+}
+
+fn json_to_yaml(input_path: &Path, output_path: &Path) -> Result<(), ConvertError> {
+    let yaml_value = read_json(input_path)?;
+    write_yaml(&yaml_value, output_path)
+}
+
+fn properties_to_json(input_path: &Path, output_path: &Path) -> Result<(), ConvertError> {
+    let yaml_value = read_properties(input_path)?;
+    write_json(&yaml_value, output_path)
+}
+
+fn json_to_properties(input_path: &Path, output_path: &Path) -> Result<(), ConvertError> {
+    let yaml_value = read_json(input_path)?;
+    write_properties(&yaml_value, output_path)
+}
+
+fn read_yaml(input_path: &Path) -> Result<YamlValue, ConvertError> {
+    let yaml_content = std::fs::read_to_string(input_path)
+        .map_err(|e| ConvertError::io("Failed to read YAML file", e))?;
+
+    serde_yaml::from_str(&yaml_content).map_err(|e| ConvertError::decode("yaml", e))
+}
+
+fn read_json(input_path: &Path) -> Result<YamlValue, ConvertError> {
+    let json_content = std::fs::read_to_string(input_path)
+        .map_err(|e| ConvertError::io("Failed to read JSON file", e))?;
+
+    let json_value: JsonValue =
+        serde_json::from_str(&json_content).map_err(|e| ConvertError::decode("json", e))?;
+
+    serde_yaml::to_value(&json_value).map_err(|e| ConvertError::decode("json", e))
+}
+
+fn read_properties(input_path: &Path) -> Result<YamlValue, ConvertError> {
+    let properties_content = std::fs::read_to_string(input_path)
+        .map_err(|e| ConvertError::io("Failed to read Properties file", e))?;
+
+    let mut properties = HashMap::new();
+    for line in properties_content.lines() {
+        let line = line.trim();
+        if line.is_empty() || line.starts_with('#') || line.starts_with('!') {
+            continue;
+        }
+
+        if let Some((key, value)) = parse_properties_line(line) {
+            properties.insert(key, value);
         }
-    };
+    }
+
+    Ok(unflatten_properties(&properties))
+}
+
+fn write_yaml(yaml_value: &YamlValue, output_path: &Path) -> Result<(), ConvertError> {
+    let yaml_content =
+        serde_yaml::to_string(yaml_value).map_err(|e| ConvertError::encode("yaml", e))?;
+
+    std::fs::write(output_path, yaml_content)
+        .map_err(|e| ConvertError::io("Failed to write YAML file", e))
+}
+
+fn write_json(yaml_value: &YamlValue, output_path: &Path) -> Result<(), ConvertError> {
+    let json_value: JsonValue =
+        serde_json::to_value(yaml_value).map_err(|e| ConvertError::encode("json", e))?;
+
+    let json_content =
+        serde_json::to_string_pretty(&json_value).map_err(|e| ConvertError::encode("json", e))?;
 
+    std::fs::write(output_path, json_content)
+        .map_err(|e| ConvertError::io("Failed to write JSON file", e))
+}
+
+fn write_properties(yaml_value: &YamlValue, output_path: &Path) -> Result<(), ConvertError> {
     let mut properties = HashMap::new();
-    flatten_yaml(&yaml_value, "", &mut properties);
+    flatten_yaml(yaml_value, "", &mut properties);
 
     let mut keys: Vec<&String> = properties.keys().collect();
     keys.sort();
@@ -85,68 +206,8 @@ fn yaml_to_properties(input_path: &Path, output_path: &Path) -> ConvertResult {
         .collect::<Vec<_>>()
         .join("\n");
 
-    match std::fs::write(output_path, properties_content) {
-        Ok(()) => ConvertResult {
-            success: true,
-            output_path: Some(output_path.to_string_lossy().to_string()),
-            error: None,
-        },
-        Err(e) => ConvertResult {
-            success: false,
-            output_path: None,
-            error: Some(format!("Failed to write Properties file: {}", e)),
-        },
-    }
-}
-
-fn properties_to_yaml(input_path: &Path, output_path: &Path) -> ConvertResult {
-    let properties_content = match std::fs::read_to_string(input_path) {
-        Ok(content) => content,
-        Err(e) => {
-            return ConvertResult {
-                success: false,
-                output_path: None,
-                error: Some(format!("Failed to read Properties file: {}", e)),
-            }
-        }
-    };
-
-    let mut properties = HashMap::new();
-    for line in properties_content.lines() {
-        let line = line.trim();
-        if line.is_empty() || line.starts_with('#') || line.starts_with('!') {
-            continue;
-        }
-
-        if let Some((key, value)) = parse_properties_line(line) {
-            properties.insert(key, value);
-        }
-    }
-
-    let yaml_value = unflatten_properties(&properties);
-    let yaml_content = match serde_yaml::to_string(&yaml_value) {
-        Ok(content) => content,
-        Err(e) => {
-            return ConvertResult {
-                success: false,
-                output_path: None,
-                error: Some(format!("YAML serialization failed: {}", e)),
-            }
-        }
-    };
-
-    match std::fs::write(output_path, yaml_content) {
-        Ok(()) => ConvertResult {
-            success: true,
-            output_path: Some(output_path.to_string_lossy().to_string()),
-            error: None,
-        },
-        Err(e) => ConvertResult {
-            success: false,
-            output_path: None,
-            error: Some(format!("Failed to write YAML file: {}", e)),
-        },
-    }
+    std::fs::write(output_path, properties_content)
+        .map_err(|e| ConvertError::io("Failed to write Properties file", e))
 }
 
 fn flatten_yaml(value: &YamlValue, prefix: &str, map: &mut HashMap<String, String>) {
@@ -217,7 +278,7 @@ fn insert_value(map: &mut serde_yaml::Mapping, key: &str, value: &str) {
                 None
             }
         })
-        .unwrap_or_else(|| serde_yaml::Mapping::new());
+        .unwrap_or_default();
 
     let mut nested_map = nested_map;
     insert_value(&mut nested_map, remaining_key, value);
@@ -229,17 +290,14 @@ fn parse_properties_line(line: &str) -> Option<(String, String)> {
     let mut value = String::new();
     let mut in_escape = false;
     let mut in_key = true;
-    let mut chars = line.chars().peekable();
 
-    while let Some(c) = chars.next() {
+    for c in line.chars() {
         if in_escape {
             key.push(c);
             in_escape = false;
         } else if c == '\\' {
             in_escape = true;
-        } else if c == '=' && in_key {
-            in_key = false;
-        } else if c == ':' && in_key {
+        } else if (c == '=' || c == ':') && in_key {
             in_key = false;
         } else if c == ' ' && in_key && key.is_empty() {
             continue;
@@ -317,249 +375,3 @@ fn unescape_properties_value(value: &str) -> String {
 
     result
 }
-
-fn yaml_to_json(input_path: &Path, output_path: &Path) -> ConvertResult {
-    let yaml_content = match std::fs::read_to_string(input_path) {
-        Ok(content) => content,
-        Err(e) => {
-            return ConvertResult {
-                success: false,
-                output_path: None,
-                error: Some(format!("Failed to read YAML file: {}", e)),
-            }
-        }
-    };
-
-    let yaml_value: YamlValue = match serde_yaml::from_str(&yaml_content) {
-        Ok(value) => value,
-        Err(e) => {
-            return ConvertResult {
-                success: false,
-                output_path: None,
-                error: Some(format!("YAML parsing failed: {}", e)),
-            }
-        }
-    };
-
-    let json_value: JsonValue = match serde_json::to_value(&yaml_value) {
-        Ok(value) => value,
-        Err(e) => {
-            return ConvertResult {
-                success: false,
-                output_path: None,
-                error: Some(format!("Conversion to JSON failed: {}", e)),
-            }
-        }
-    };
-
-    let json_content = match serde_json::to_string_pretty(&json_value) {
-        Ok(content) => content,
-        Err(e) => {
-            return ConvertResult {
-                success: false,
-                output_path: None,
-                error: Some(format!("JSON serialization failed: {}", e)),
-            }
-        }
-    };
-
-    match std::fs::write(output_path, json_content) {
-        Ok(()) => ConvertResult {
-            success: true,
-            output_path: Some(output_path.to_string_lossy().to_string()),
-            error: None,
-        },
-        Err(e) => ConvertResult {
-            success: false,
-            output_path: None,
-            error: Some(format!("Failed to write JSON file: {}", e)),
-        },
-    }
-}
-
-fn json_to_yaml(input_path: &Path, output_path: &Path) -> ConvertResult {
-    let json_content = match std::fs::read_to_string(input_path) {
-        Ok(content) => content,
-        Err(e) => {
-            return ConvertResult {
-                success: false,
-                output_path: None,
-                error: Some(format!("Failed to read JSON file: {}", e)),
-            }
-        }
-    };
-
-    let json_value: JsonValue = match serde_json::from_str(&json_content) {
-        Ok(value) => value,
-        Err(e) => {
-            return ConvertResult {
-                success: false,
-                output_path: None,
-                error: Some(format!("JSON parsing failed: {}", e)),
-            }
-        }
-    };
-
-    let yaml_value: YamlValue = match serde_yaml::to_value(&json_value) {
-        Ok(value) => value,
-        Err(e) => {
-            return ConvertResult {
-                success: false,
-                output_path: None,
-                error: Some(format!("Conversion to YAML failed: {}", e)),
-            }
-        }
-    };
-
-    let yaml_content = match serde_yaml::to_string(&yaml_value) {
-        Ok(content) => content,
-        Err(e) => {
-            return ConvertResult {
-                success: false,
-                output_path: None,
-                error: Some(format!("YAML serialization failed: {}", e)),
-            }
-        }
-    };
-
-    match std::fs::write(output_path, yaml_content) {
-        Ok(()) => ConvertResult {
-            success: true,
-            output_path: Some(output_path.to_string_lossy().to_string()),
-            error: None,
-        },
-        Err(e) => ConvertResult {
-            success: false,
-            output_path: None,
-            error: Some(format!("Failed to write YAML file: {}", e)),
-        },
-    }
-}
-
-fn properties_to_json(input_path: &Path, output_path: &Path) -> ConvertResult {
-    let properties_content = match std::fs::read_to_string(input_path) {
-        Ok(content) => content,
-        Err(e) => {
-            return ConvertResult {
-                success: false,
-                output_path: None,
-                error: Some(format!("Failed to read Properties file: {}", e)),
-            }
-        }
-    };
-
-    let mut properties = HashMap::new();
-    for line in properties_content.lines() {
-        let line = line.trim();
-        if line.is_empty() || line.starts_with('#') || line.starts_with('!') {
-            continue;
-        }
-
-        if let Some((key, value)) = parse_properties_line(line) {
-            properties.insert(key, value);
-        }
-    }
-
-    let yaml_value = unflatten_properties(&properties);
-    let json_value: JsonValue = match serde_json::to_value(&yaml_value) {
-        Ok(value) => value,
-        Err(e) => {
-            return ConvertResult {
-                success: false,
-                output_path: None,
-                error: Some(format!("Conversion to JSON failed: {}", e)),
-            }
-        }
-    };
-
-    let json_content = match serde_json::to_string_pretty(&json_value) {
-        Ok(content) => content,
-        Err(e) => {
-            return ConvertResult {
-                success: false,
-                output_path: None,
-                error: Some(format!("JSON serialization failed: {}", e)),
-            }
-        }
-    };
-
-    match std::fs::write(output_path, json_content) {
-        Ok(()) => ConvertResult {
-            success: true,
-            output_path: Some(output_path.to_string_lossy().to_string()),
-            error: None,
-        },
-        Err(e) => ConvertResult {
-            success: false,
-            output_path: None,
-            error: Some(format!("Failed to write JSON file: {}", e)),
-        },
-    }
-}
-
-fn json_to_properties(input_path: &Path, output_path: &Path) -> ConvertResult {
-    let json_content = match std::fs::read_to_string(input_path) {
-        Ok(content) => content,
-        Err(e) => {
-            return ConvertResult {
-                success: false,
-                output_path: None,
-                error: Some(format!("Failed to read JSON file: {}", e)),
-            }
-        }
-    };
-
-    let json_value: JsonValue = match serde_json::from_str(&json_content) {
-        Ok(value) => value,
-        Err(e) => {
-            return ConvertResult {
-                success: false,
-                output_path: None,
-                error: Some(format!("JSON parsing failed: {}", e)),
-            }
-        }
-    };
-
-    let yaml_value: YamlValue = match serde_yaml::to_value(&json_value) {
-        Ok(value) => value,
-        Err(e) => {
-            return ConvertResult {
-                success: false,
-                output_path: None,
-                error: Some(format!("Conversion to YAML failed: {}", e)),
-            }
-        }
-    };
-
-    let mut properties = HashMap::new();
-    flatten_yaml(&yaml_value, "", &mut properties);
-
-    let mut keys: Vec<&String> = properties.keys().collect();
-    keys.sort();
-
-    let properties_content = keys
-        .iter()
-        .map(|k| {
-            let v = &properties[*k];
-            format!(
-                "{}={}",
-                escape_properties_key(k),
-                escape_properties_value(v)
-            )
-        })
-        .collect::<Vec<_>>()
-        .join("\n");
-
-    match std::fs::write(output_path, properties_content) {
-        Ok(()) => ConvertResult {
-            success: true,
-            output_path: Some(output_path.to_string_lossy().to_string()),
-            error: None,
-        },
-        Err(e) => ConvertResult {
-            success: false,
-            output_path: None,
-            error: Some(format!("Failed to write Properties file: {}", e)),
-        },
-    }
-}
@@ -0,0 +1,323 @@
+use super::batch::convert_batch_to;
+use super::graph::graph;
+use crate::api::{ConvertOptions, ConvertResult, DirectoryFileResult, DirectoryFilter};
+use crate::error::ConvertError;
+use crate::progress::ProgressCallback;
+use regex::Regex;
+use std::path::{Path, PathBuf};
+use walkdir::WalkDir;
+
+/// Convert every matching file below `input_dir`, mirroring the folder structure under `output_dir`
+///
+/// Errors that prevent walking the tree (missing input directory, invalid glob) are
+/// reported as a single failed entry for `input_dir`. Entries are in walk order, with
+/// unreadable entries as failed results in their place.
+pub fn convert_directory(
+    input_dir: &str,
+    output_dir: &str,
+    options: &ConvertOptions,
+    filter: &DirectoryFilter,
+    on_progress: Option<ProgressCallback>,
+) -> Vec<DirectoryFileResult> {
+    let entries = match collect_files(input_dir, output_dir, options, filter) {
+        Ok(collected) => collected,
+        Err(e) => {
+            return vec![DirectoryFileResult {
+                input_path: input_dir.to_string(),
+                relative_path: String::new(),
+                result: ConvertResult::err(e),
+            }]
+        }
+    };
+
+    let jobs: Vec<_> = entries
+        .iter()
+        .filter_map(|entry| match entry {
+            WalkEntry::File(file) => Some(file),
+            WalkEntry::Failed(_) => None,
+        })
+        .map(|file| {
+            let dir = match file.relative.parent() {
+                Some(parent) => Path::new(output_dir).join(parent),
+                None => PathBuf::from(output_dir),
+            };
+            (
+                file.path.to_string_lossy().to_string(),
+                dir.to_string_lossy().to_string(),
+            )
+        })
+        .collect();
+    let mut results = convert_batch_to(&jobs, options, on_progress).into_iter();
+
+    entries
+        .into_iter()
+        .map(|entry| match entry {
+            WalkEntry::File(file) => DirectoryFileResult {
+                input_path: file.path.to_string_lossy().to_string(),
+                relative_path: relative_string(&file.relative),
+                result: results.next().expect("one result per file"),
+            },
+            WalkEntry::Failed(failure) => *failure,
+        })
+        .collect()
+}
+
+struct SourceFile {
+    path: PathBuf,
+    /// Path relative to the input directory
+    relative: PathBuf,
+}
+
+enum WalkEntry {
+    File(SourceFile),
+    /// An entry that could not be read
+    Failed(Box<DirectoryFileResult>),
+}
+
+/// Walk `input_dir` and pick the files to convert
+fn collect_files(
+    input_dir: &str,
+    output_dir: &str,
+    options: &ConvertOptions,
+    filter: &DirectoryFilter,
+) -> Result<Vec<WalkEntry>, ConvertError> {
+    let root = Path::new(input_dir);
+    let metadata = std::fs::metadata(root)
+        .map_err(|e| ConvertError::io(format!("Cannot read {}", input_dir), e))?;
+    if !metadata.is_dir() {
+        return Err(ConvertError::invalid_option(
+            "input_dir",
+            format!("{} is not a directory", input_dir),
+        ));
+    }
+
+    let include = compile_globs("include", &filter.include)?;
+    let exclude = compile_globs("exclude", &filter.exclude)?;
+    let output_format = options.output_format.to_lowercase();
+    // Do not pick up our own output when converting into a subfolder of the input
+    let output_root = std::fs::canonicalize(output_dir).ok();
+
+    let mut entries = Vec::new();
+    let walker = WalkDir::new(root)
+        .sort_by_file_name()
+        .into_iter()
+        .filter_entry(|entry| {
+            let relative = entry.path().strip_prefix(root).unwrap_or(entry.path());
+            if relative.as_os_str().is_empty() {
+                return true;
+            }
+            if matches_any(&exclude, relative) {
+                return false;
+            }
+            !entry.file_type().is_dir()
+                || output_root.is_none()
+                || std::fs::canonicalize(entry.path()).ok() != output_root
+        });
+
+    for entry in walker {
+        let entry = match entry {
+            Ok(entry) => entry,
+            Err(e) => {
+                let path = e.path().unwrap_or(root).to_path_buf();
+                let error = match e.into_io_error() {
+                    Some(io) => ConvertError::io("Failed to read directory entry", io),
+                    None => ConvertError::Internal("Filesystem loop detected".to_string()),
+                };
+                entries.push(WalkEntry::Failed(Box::new(DirectoryFileResult {
+                    input_path: path.to_string_lossy().to_string(),
+                    relative_path: relative_string(path.strip_prefix(root).unwrap_or(&path)),
+                    result: ConvertResult::err(error),
+                })));
+                continue;
+            }
+        };
+        if !entry.file_type().is_file() {
+            continue;
+        }
+
+        let relative = entry
+            .path()
+            .strip_prefix(root)
+            .unwrap_or(entry.path())
+            .to_path_buf();
+        if !include.is_empty() && !matches_any(&include, &relative) {
+            continue;
+        }
+
+        let detection = crate::detect::detect(entry.path());
+        let selected = if filter.file_types.is_empty() {
+            // Without a type filter, take every file that can become the output format,
+            // directly or through intermediate formats like `convert_single`
+            detection
+                .format
+                .is_some_and(|format| graph().route(&format, &output_format).is_some())
+        } else {
+            detection
+                .file_type
+                .is_some_and(|t| filter.file_types.contains(&t))
+        };
+        if selected {
+            entries.push(WalkEntry::File(SourceFile {
+                path: entry.into_path(),
+                relative,
+            }));
+        }
+    }
+
+    Ok(entries)
+}
+
+/// Relative path with `/` separators
+fn relative_string(path: &Path) -> String {
+    path.components()
+        .map(|c| c.as_os_str().to_string_lossy())
+        .collect::<Vec<_>>()
+        .join("/")
+}
+
+/// A glob without `/` matches the file name only, otherwise the whole relative path
+struct Glob {
+    regex: Regex,
+    match_path: bool,
+}
+
+fn compile_globs(option: &str, patterns: &[String]) -> Result<Vec<Glob>, ConvertError> {
+    patterns
+        .iter()
+        .map(|pattern| {
+            let pattern = pattern.trim().trim_start_matches("./");
+            let regex = Regex::new(&glob_to_regex(pattern)).map_err(|e| {
+                ConvertError::invalid_option(
+                    option,
+                    format!("invalid glob pattern '{}': {}", pattern, e),
+                )
+            })?;
+            Ok(Glob {
+                regex,
+                match_path: pattern.contains('/'),
+            })
+        })
+        .collect()
+}
+
+fn matches_any(globs: &[Glob], relative: &Path) -> bool {
+    let path = relative_string(relative);
+    let name = path.rsplit('/').next().unwrap_or(&path);
+    globs.iter().any(|glob| {
+        glob.regex
+            .is_match(if glob.match_path { &path } else { name })
+    })
+}
+
+/// Translate a glob (`*`, `**`, `?`, `[...]`) into an anchored, case-insensitive regex
+fn glob_to_regex(glob: &str) -> String {
+    let mut regex = String::from("(?i)^");
+    let mut chars = glob.chars().peekable();
+    while let Some(c) = chars.next() {
+        match c {
+            '*' if chars.peek() == Some(&'*') => {
+                chars.next();
+                if chars.peek() == Some(&'/') {
+                    // `**/` matches zero or more directories
+                    chars.next();
+                    regex.push_str("(?:.*/)?");
+                } else {
+                    regex.push_str(".*");
+                }
+            }
+            '*' => regex.push_str("[^/]*"),
+            '?' => regex.push_str("[^/]"),
+            '[' => {
+                regex.push('[');
+                if chars.peek() == Some(&'!') {
+                    chars.next();
+                    regex.push('^');
+                }
+                for c in chars.by_ref() {
+                    if c == ']' {
+                        break;
+                    }
+                    if c == '\\' {
+                        regex.push('\\');
+                    }
+                    regex.push(c);
+                }
+                regex.push(']');
+            }
+            c => regex.push_str(&regex::escape(&c.to_string())),
+        }
+    }
+    regex.push('$');
+    regex
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::api::ConvertErrorCode;
+
+    #[test]
+    fn picks_up_files_that_need_intermediate_formats() {
+        let dir = tempfile::tempdir().unwrap();
+        let input = dir.path().join("in");
+        std::fs::create_dir_all(input.join("sub")).unwrap();
+        std::fs::write(input.join("notes.yaml"), "a: 1\n").unwrap();
+        // docx -> md -> pdf
+        std::fs::write(input.join("report.docx"), b"PK\x03\x04word/document.xml").unwrap();
+        image::RgbImage::new(2, 2)
+            .save(input.join("sub").join("pic.png"))
+            .unwrap();
+
+        let options = ConvertOptions {
+            output_format: "pdf".to_string(),
+            ..Default::default()
+        };
+        let entries = convert_directory(
+            input.to_str().unwrap(),
+            dir.path().join("out").to_str().unwrap(),
+            &options,
+            &DirectoryFilter::default(),
+            None,
+        );
+        let relative: Vec<_> = entries.iter().map(|e| e.relative_path.as_str()).collect();
+        assert_eq!(relative, ["report.docx", "sub/pic.png"]);
+    }
+
+    #[test]
+    fn reports_bad_input_as_invalid_options() {
+        let dir = tempfile::tempdir().unwrap();
+        let file = dir.path().join("a.png");
+        std::fs::write(&file, b"").unwrap();
+        let output = dir.path().join("out");
+        let convert = |input: &Path, filter: &DirectoryFilter| {
+            let options = ConvertOptions {
+                output_format: "png".to_string(),
+                ..Default::default()
+            };
+            let entries = convert_directory(
+                input.to_str().unwrap(),
+                output.to_str().unwrap(),
+                &options,
+                filter,
+                None,
+            );
+            assert_eq!(entries.len(), 1);
+            (
+                entries[0].result.error_code,
+                entries[0].result.error.clone(),
+            )
+        };
+
+        let (code, error) = convert(&file, &DirectoryFilter::default());
+        assert_eq!(code, Some(ConvertErrorCode::InvalidOption));
+        assert!(error.unwrap().contains("input_dir"));
+
+        let filter = DirectoryFilter {
+            include: vec!["[".to_string()],
+            ..Default::default()
+        };
+        let (code, error) = convert(dir.path(), &filter);
+        assert_eq!(code, Some(ConvertErrorCode::InvalidOption));
+        assert!(error.unwrap().contains("include"));
+    }
+}
@@ -1,116 +1,159 @@
-use crate::api::{ConvertOptions, ConvertResult};
+use super::process::run_process;
+use super::{ConvertJob, Converter, ConverterRegistry};
+use crate::error::ConvertError;
+use crate::progress::ProgressReporter;
+use crate::task::CancelToken;
 use headless_chrome::Browser;
 use std::path::Path;
 
-/// Convert document
-pub fn convert_document(
-    input_path: &str,
-    output_dir: &str,
-    options: &ConvertOptions,
-) -> ConvertResult {
-    let input = Path::new(input_path);
-    let stem = input
-        .file_stem()
-        .and_then(|s| s.to_str())
-        .unwrap_or("output");
-    let output_ext = options.output_format.to_lowercase();
-    let output_name = format!("{}.{}", stem, output_ext);
-    let output_path = Path::new(output_dir).join(&output_name);
-
-    let input_ext = input
-        .extension()
-        .and_then(|e| e.to_str())
-        .unwrap_or("")
-        .to_lowercase();
-
-    // Special case: Markdown <-> Office (via pandoc)
-    // NOTE: docx/pptx are binary containers; do NOT attempt to read them as UTF-8 text.
-    if matches!(input_ext.as_str(), "md" | "markdown" | "docx" | "pptx")
-        && matches!(output_ext.as_str(), "md" | "docx" | "pptx")
-        && !(matches!(input_ext.as_str(), "docx" | "pptx")
-            && matches!(output_ext.as_str(), "docx" | "pptx"))
-    {
-        return convert_via_pandoc(input_path, &output_path);
+/// Markdown <-> Office (via pandoc)
+///
+/// NOTE: docx/pptx are binary containers; do NOT attempt to read them as UTF-8 text.
+pub struct PandocConverter {
+    pub inputs: &'static [&'static str],
+    pub outputs: &'static [&'static str],
+}
+
+impl Converter for PandocConverter {
+    fn name(&self) -> &'static str {
+        "pandoc"
     }
 
-    // Special case: Markdown -> PDF (via Chromium)
-    if matches!(input_ext.as_str(), "md" | "markdown") && output_ext == "pdf" {
-        return convert_markdown_to_pdf(input_path, &output_path);
+    fn inputs(&self) -> &[&'static str] {
+        self.inputs
     }
 
-    // PDF input is not supported (do not attempt to read it as UTF-8 text)
-    if input_ext == "pdf" {
-        return ConvertResult {
-            success: false,
-            output_path: None,
-            error: Some("PDF input conversion is not supported.".to_string()),
-        };
+    fn outputs(&self) -> &[&'static str] {
+        self.outputs
     }
 
-    // Special case: EPUB -> PDF (via external tools)
-    // NOTE: EPUB is a binary (zip) container; do NOT attempt to read it as UTF-8 text.
-    if input_ext == "epub" {
-        return match output_ext.as_str() {
-            "pdf" => convert_epub_to_pdf(input_path, &output_path),
-            _ => ConvertResult {
-                success: false,
-                output_path: None,
-                error: Some("EPUB currently only supports PDF output".to_string()),
-            },
-        };
+    fn required_tools(&self) -> &[&'static str] {
+        &["pandoc"]
     }
 
-    // Read input file (only applicable for plain text documents)
-    let content = match std::fs::read_to_string(input_path) {
-        Ok(c) => c,
-        Err(e) => {
-            return ConvertResult {
-                success: false,
-                output_path: None,
-                error: Some(format!("Failed to read text file: {}", e)),
-            }
-        }
-    };
+    fn convert(&self, job: &ConvertJob) -> Result<(), ConvertError> {
+        convert_via_pandoc(
+            job.input_path,
+            job.input_format,
+            job.output_path,
+            job.progress,
+            job.cancel,
+        )
+    }
+}
 
-    match output_ext.as_str() {
-        "html" => {
-            convert_to_html(&content, &input_ext, &output_path)
-        }
-        "txt" => {
-            convert_to_txt(&content, &input_ext, &output_path)
+/// Markdown -> PDF (via Chromium)
+pub struct MarkdownPdfConverter;
+
+impl Converter for MarkdownPdfConverter {
+    fn name(&self) -> &'static str {
+        "chromium-pdf"
+    }
+
+    fn inputs(&self) -> &[&'static str] {
+        &["md", "markdown"]
+    }
+
+    fn outputs(&self) -> &[&'static str] {
+        &["pdf"]
+    }
+
+    fn required_tools(&self) -> &[&'static str] {
+        &["chromium"]
+    }
+
+    fn convert(&self, job: &ConvertJob) -> Result<(), ConvertError> {
+        convert_markdown_to_pdf(job.input_path, job.output_path, job.progress, job.cancel)
+    }
+}
+
+/// EPUB -> PDF (via pandoc, falling back to calibre's ebook-convert)
+///
+/// NOTE: EPUB is a binary (zip) container; do NOT attempt to read it as UTF-8 text.
+pub struct EpubPdfConverter;
+
+impl Converter for EpubPdfConverter {
+    fn name(&self) -> &'static str {
+        "epub-pdf"
+    }
+
+    fn inputs(&self) -> &[&'static str] {
+        &["epub"]
+    }
+
+    fn outputs(&self) -> &[&'static str] {
+        &["pdf"]
+    }
+
+    /// Either one of these is enough
+    fn required_tools(&self) -> &[&'static str] {
+        &["pandoc", "ebook-convert"]
+    }
+
+    fn convert(&self, job: &ConvertJob) -> Result<(), ConvertError> {
+        convert_epub_to_pdf(job.input_path, job.output_path, job.progress, job.cancel)
+    }
+}
+
+/// Plain-text-ish documents -> HTML/TXT
+pub struct TextConverter;
+
+impl Converter for TextConverter {
+    fn name(&self) -> &'static str {
+        "text"
+    }
+
+    fn inputs(&self) -> &[&'static str] {
+        &["md", "markdown", "html", "htm", "txt"]
+    }
+
+    fn outputs(&self) -> &[&'static str] {
+        &["html", "txt"]
+    }
+
+    fn convert(&self, job: &ConvertJob) -> Result<(), ConvertError> {
+        let content = std::fs::read_to_string(job.input_path)
+            .map_err(|e| ConvertError::io("Failed to read text file", e))?;
+
+        match job.output_format {
+            "html" => convert_to_html(&content, job.input_format, job.output_path),
+            _ => convert_to_txt(&content, job.input_format, job.output_path),
         }
-        "pdf" => ConvertResult {
-            success: false,
-            output_path: None,
-            error: Some(
-                "PDF output is not supported for this input type (only Markdown -> PDF and EPUB -> PDF are supported)."
-                    .to_string(),
-            ),
-        },
-        _ => ConvertResult {
-            success: false,
-            output_path: None,
-            error: Some(format!("Unsupported output format: {}", output_ext)),
-        },
     }
 }
 
-fn convert_markdown_to_pdf(input_path: &str, output_path: &Path) -> ConvertResult {
-    convert_via_chromium(input_path, output_path)
+pub fn register(registry: &mut ConverterRegistry) {
+    registry.register(TextConverter);
+    registry.register(PandocConverter {
+        inputs: &["md", "markdown"],
+        outputs: &["md", "docx", "pptx"],
+    });
+    registry.register(PandocConverter {
+        inputs: &["docx", "pptx"],
+        outputs: &["md"],
+    });
+    registry.register(MarkdownPdfConverter);
+    registry.register(EpubPdfConverter);
+}
+
+fn convert_markdown_to_pdf(
+    input_path: &Path,
+    output_path: &Path,
+    progress: &ProgressReporter,
+    cancel: &CancelToken,
+) -> Result<(), ConvertError> {
+    convert_via_chromium(input_path, output_path, progress, cancel)
 }
 
-fn convert_via_chromium(input_path: &str, output_path: &Path) -> ConvertResult {
+fn convert_via_chromium(
+    input_path: &Path,
+    output_path: &Path,
+    progress: &ProgressReporter,
+    cancel: &CancelToken,
+) -> Result<(), ConvertError> {
     // Read markdown file
-    let markdown_content = match std::fs::read_to_string(input_path) {
-        Ok(content) => content,
-        Err(e) => {
-            return ConvertResult {
-                success: false,
-                output_path: None,
-                error: Some(format!("Failed to read markdown file: {}", e)),
-            }
-        }
-    };
+    let markdown_content = std::fs::read_to_string(input_path)
+        .map_err(|e| ConvertError::io("Failed to read markdown file", e))?;
 
     // Convert markdown to HTML using comrak (enable GFM extensions we need)
     let mut options = comrak::ComrakOptions::default();
@@ -122,17 +165,8 @@ fn convert_via_chromium(input_path: &str, output_path: &Path) -> ConvertResult {
     // Comrak emits variants like:
     // - <input type="checkbox" disabled="" />
     // - <input type="checkbox" checked="" disabled="" />
-    let checkbox_re =
-        match regex::Regex::new(r#"(?i)<input[^>]*type=(?:\"checkbox\"|'checkbox')[^>]*>"#) {
-            Ok(re) => re,
-            Err(e) => {
-                return ConvertResult {
-                    success: false,
-                    output_path: None,
-                    error: Some(format!("Failed to build checkbox regex: {}", e)),
-                }
-            }
-        };
+    let checkbox_re = regex::Regex::new(r#"(?i)<input[^>]*type=(?:\"checkbox\"|'checkbox')[^>]*>"#)
+        .map_err(|e| ConvertError::Internal(format!("Failed to build checkbox regex: {}", e)))?;
     html_content = checkbox_re
         .replace_all(&html_content, |caps: &regex::Captures| {
             let tag = caps.get(0).map(|m| m.as_str()).unwrap_or("");
@@ -252,66 +286,66 @@ fn convert_via_chromium(input_path: &str, output_path: &Path) -> ConvertResult {
     );
 
     // Try to launch browser and convert to PDF
-    match Browser::default() {
-        Ok(browser) => {
-            match browser.new_tab() {
-                Ok(tab) => {
-                    // Navigate to data URL with HTML content
-                    let data_url = format!("data:text/html;charset=utf-8,{}", urlencoding::encode(&full_html));
-                    match tab.navigate_to(&data_url) {
-                        Ok(_) => {
-                            // Give page time to render
-                            std::thread::sleep(std::time::Duration::from_millis(1000));
-
-                            // Print to PDF
-                            match tab.print_to_pdf(None) {
-                                Ok(pdf_data) => {
-                                    match std::fs::write(output_path, pdf_data) {
-                                        Ok(_) => ConvertResult {
-                                            success: true,
-                                            output_path: Some(output_path.to_string_lossy().to_string()),
-                                            error: None,
-                                        },
-                                        Err(e) => ConvertResult {
-                                            success: false,
-                                            output_path: None,
-                                            error: Some(format!("Failed to write PDF file: {}", e)),
-                                        },
-                                    }
-                                }
-                                Err(e) => ConvertResult {
-                                    success: false,
-                                    output_path: None,
-                                    error: Some(format!("Failed to generate PDF: {}", e)),
-                                },
-                            }
-                        }
-                        Err(e) => ConvertResult {
-                            success: false,
-                            output_path: None,
-                            error: Some(format!("Failed to navigate to content: {}", e)),
-                        },
-                    }
-                }
-                Err(e) => ConvertResult {
-                    success: false,
-                    output_path: None,
-                    error: Some(format!("Failed to create browser tab: {}", e)),
-                },
-            }
+    // NOTE: Chromium is checked for cancellation between steps; dropping `browser` kills the process.
+    let check_cancelled = || {
+        if cancel.is_cancelled() {
+            Err(ConvertError::Cancelled)
+        } else {
+            Ok(())
         }
-        Err(e) => ConvertResult {
-            success: false,
-            output_path: None,
-            error: Some(format!(
+    };
+    check_cancelled()?;
+    progress.report(10, "Launching Chromium");
+    let browser = Browser::default().map_err(|e| {
+        ConvertError::tool_missing(
+            "Chromium",
+            format!(
                 "Chromium not available: {}. Install Chrome/Chromium and ensure it can be launched in this environment.",
                 e
-            )),
-        },
+            ),
+        )
+    })?;
+    let chromium_failed = |context: &str, e: &dyn std::fmt::Display| {
+        ConvertError::external_tool_failed("Chromium", None, format!("{}: {}", context, e))
+    };
+    let tab = browser
+        .new_tab()
+        .map_err(|e| chromium_failed("Failed to create browser tab", &e))?;
+
+    // Navigate to data URL with HTML content
+    let data_url = format!(
+        "data:text/html;charset=utf-8,{}",
+        urlencoding::encode(&full_html)
+    );
+    check_cancelled()?;
+    progress.report(30, "Rendering HTML in Chromium");
+    tab.navigate_to(&data_url)
+        .map_err(|e| chromium_failed("Failed to navigate to content", &e))?;
+
+    // Give page time to render
+    for _ in 0..10 {
+        check_cancelled()?;
+        std::thread::sleep(std::time::Duration::from_millis(100));
     }
+
+    // Print to PDF
+    progress.report(70, "Printing PDF");
+    let pdf_data = tab
+        .print_to_pdf(None)
+        .map_err(|e| chromium_failed("Failed to generate PDF", &e))?;
+    check_cancelled()?;
+
+    std::fs::write(output_path, pdf_data)
+        .map_err(|e| ConvertError::io("Failed to write PDF file", e))
 }
 
-fn convert_via_pandoc(input_path: &str, output_path: &Path) -> ConvertResult {
+fn convert_via_pandoc(
+    input_path: &Path,
+    input_format: &str,
+    output_path: &Path,
+    progress: &ProgressReporter,
+    cancel: &CancelToken,
+) -> Result<(), ConvertError> {
     fn tool_exists(cmd: &str) -> bool {
         std::process::Command::new(cmd)
             .arg("--version")
@@ -320,79 +354,45 @@ fn convert_via_pandoc(input_path: &str, output_path: &Path) -> ConvertResult {
     }
 
     if !tool_exists("pandoc") {
-        return ConvertResult {
-            success: false,
-            output_path: None,
-            error: Some(
-                "pandoc is required for Markdown conversions (Office/PDF).\n\nInstall pandoc:\n- https://pandoc.org/installing.html\n\nWindows (winget):\n- winget install Pandoc"
-                    .to_string(),
-            ),
-        };
+        return Err(ConvertError::tool_missing(
+            "pandoc",
+            "Install pandoc:\n- https://pandoc.org/installing.html\n\nWindows (winget):\n- winget install Pandoc",
+        ));
     }
 
-    let output_ext = output_path
-        .extension()
-        .and_then(|e| e.to_str())
-        .unwrap_or("")
-        .to_lowercase();
+    // Pass the (detected) input format explicitly: pandoc would otherwise guess from the extension
+    let from = match input_format {
+        "md" | "markdown" => "markdown",
+        other => other,
+    };
 
     let mut cmd = std::process::Command::new("pandoc");
-    cmd.arg(input_path).arg("-o").arg(output_path);
+    cmd.arg("-f")
+        .arg(from)
+        .arg(input_path)
+        .arg("-o")
+        .arg(output_path);
 
-    if output_ext == "pdf" {
-        cmd.arg("--pdf-engine=xelatex");
-    }
+    progress.stage("Running pandoc");
+    let out = run_process("pandoc", &mut cmd, cancel, |_| {}, |_| {})?;
 
-    match cmd.output() {
-        Ok(out) if out.status.success() => ConvertResult {
-            success: true,
-            output_path: Some(output_path.to_string_lossy().to_string()),
-            error: None,
-        },
-        Ok(out) => {
-            let stderr = String::from_utf8_lossy(&out.stderr).to_string();
-            let mut message = format!(
-                "Pandoc conversion failed (exit code: {:?}).\n{}",
-                out.status.code(),
-                stderr
-            );
-
-            let stderr_lc = stderr.to_lowercase();
-
-            if output_ext == "pdf" {
-                if stderr_lc.contains("unicode character")
-                    && stderr_lc.contains("not set up for use with latex")
-                {
-                    message.push_str(
-                        "\n\nUnicode/CJK character error detected:\n- ConvertX uses XeLaTeX for better Unicode support.\n- Ensure XeLaTeX is installed (usually included with MiKTeX/TeX Live).\n- For CJK text (Chinese/Japanese/Korean), install CJK fonts on your system.\n- On Windows with MiKTeX: open MiKTeX Console, run Updates, then retry.\n- If XeLaTeX is not available, install TeX Live or MiKTeX with full package support.\n",
-                    );
-                } else if stderr_lc.contains("pdflatex")
-                    || stderr_lc.contains("miktex")
-                    || stderr_lc.contains("xelatex")
-                    || stderr_lc.contains("lualatex")
-                    || stderr_lc.contains("latex")
-                {
-                    message.push_str(
-                        "\n\nPDF generation notes (Windows):\n- pandoc requires a PDF engine (e.g. MiKTeX/TeX Live with XeLaTeX).\n- If you use MiKTeX, open MiKTeX Console and run Updates, then retry.\n- Ensure XeLaTeX is installed and available in PATH.\n- For CJK support, install appropriate language packages and fonts.\n",
-                    );
-                }
-            }
-
-            ConvertResult {
-                success: false,
-                output_path: None,
-                error: Some(message),
-            }
-        }
-        Err(e) => ConvertResult {
-            success: false,
-            output_path: None,
-            error: Some(format!("Failed to execute pandoc: {e}")),
-        },
+    if out.status.success() {
+        return Ok(());
     }
+
+    Err(ConvertError::external_tool_failed(
+        "Pandoc",
+        out.status.code(),
+        out.stderr,
+    ))
 }
 
-fn convert_epub_to_pdf(input_path: &str, output_path: &Path) -> ConvertResult {
+fn convert_epub_to_pdf(
+    input_path: &Path,
+    output_path: &Path,
+    progress: &ProgressReporter,
+    cancel: &CancelToken,
+) -> Result<(), ConvertError> {
     fn tool_exists(cmd: &str) -> bool {
         std::process::Command::new(cmd)
             .arg("--version")
@@ -404,86 +404,73 @@ fn convert_epub_to_pdf(input_path: &str, output_path: &Path) -> ConvertResult {
     let has_ebook_convert = tool_exists("ebook-convert");
 
     if !has_pandoc && !has_ebook_convert {
-        return ConvertResult {
-            success: false,
-            output_path: None,
-            error: Some(
-                "Neither pandoc nor calibre (ebook-convert) was detected. Please install one of them:\n\n- pandoc: https://pandoc.org/installing.html\n- calibre: Provides the ebook-convert command after installation\n\nWindows (winget):\n- winget install Pandoc\n- winget install calibre.calibre".to_string(),
-            ),
-        };
+        return Err(ConvertError::tool_missing(
+            "pandoc or calibre (ebook-convert)",
+            "Please install one of them:\n\n- pandoc: https://pandoc.org/installing.html\n- calibre: Provides the ebook-convert command after installation\n\nWindows (winget):\n- winget install Pandoc\n- winget install calibre.calibre",
+        ));
     }
 
     // Prefer pandoc
+    let mut pandoc_error = None;
     if has_pandoc {
-        match std::process::Command::new("pandoc")
+        progress.stage("Running pandoc");
+        let mut cmd = std::process::Command::new("pandoc");
+        cmd.arg("-f")
+            .arg("epub")
             .arg(input_path)
             .arg("-o")
-            .arg(output_path)
-            .output()
-        {
-            Ok(out) if out.status.success() => {
-                return ConvertResult {
-                    success: true,
-                    output_path: Some(output_path.to_string_lossy().to_string()),
-                    error: None,
-                };
-            }
+            .arg(output_path);
+        match run_process("pandoc", &mut cmd, cancel, |_| {}, |_| {}) {
+            Ok(out) if out.status.success() => return Ok(()),
             Ok(out) => {
-                let stderr = String::from_utf8_lossy(&out.stderr).to_string();
                 // fallthrough to ebook-convert
-                if !stderr.trim().is_empty() && has_ebook_convert {
-                    // keep for later; nothing else
-                }
+                pandoc_error = Some(ConvertError::external_tool_failed(
+                    "Pandoc",
+                    out.status.code(),
+                    out.stderr,
+                ));
             }
-            Err(_) => {
+            Err(ConvertError::Cancelled) => return Err(ConvertError::Cancelled),
+            Err(e) => {
                 // fallthrough to ebook-convert
+                pandoc_error = Some(e);
             }
         }
     }
 
     // Fallback to calibre
-    if has_ebook_convert {
-        match std::process::Command::new("ebook-convert")
-            .arg(input_path)
-            .arg(output_path)
-            .output()
-        {
-            Ok(out) if out.status.success() => ConvertResult {
-                success: true,
-                output_path: Some(output_path.to_string_lossy().to_string()),
-                error: None,
-            },
-            Ok(out) => ConvertResult {
-                success: false,
-                output_path: None,
-                error: Some(format!(
-                    "EPUB->PDF conversion failed (ebook-convert exit code: {:?}).\n{}",
-                    out.status.code(),
-                    String::from_utf8_lossy(&out.stderr)
-                )),
-            },
-            Err(e) => ConvertResult {
-                success: false,
-                output_path: None,
-                error: Some(format!(
-                    "Failed to execute ebook-convert: {}. Please ensure calibre is installed and ebook-convert is in PATH.",
-                    e
-                )),
-            },
-        }
-    } else {
-        ConvertResult {
-            success: false,
-            output_path: None,
-            error: Some(
+    if !has_ebook_convert {
+        return Err(pandoc_error.unwrap_or_else(|| {
+            ConvertError::Internal(
                 "pandoc conversion failed and calibre (ebook-convert) was not detected. Please install calibre or check the pandoc output log.".to_string(),
-            ),
-        }
+            )
+        }));
+    }
+
+    progress.stage("Running calibre (ebook-convert)");
+    let mut cmd = std::process::Command::new("ebook-convert");
+    cmd.arg(input_path).arg(output_path);
+    let out = run_process("ebook-convert", &mut cmd, cancel, |_| {}, |_| {}).map_err(|e| match e {
+        ConvertError::Io { source, .. } => ConvertError::io(
+            "Failed to execute ebook-convert (please ensure calibre is installed and ebook-convert is in PATH)",
+            source,
+        ),
+        other => other,
+    })?;
+
+    if !out.status.success() {
+        return Err(ConvertError::external_tool_failed(
+            "ebook-convert",
+            out.status.code(),
+            out.stderr,
+        ));
     }
+
+    Ok(())
 }
 
 /// Convert to HTML
-fn convert_to_html(content: &str, input_ext: &str, output_path: &Path) -> ConvertResult {
+fn convert_to_html(content: &str, input_ext: &str, output_path: &Path) -> Result<(), ConvertError> {
     let html = match input_ext {
         "md" | "markdown" => {
             // Markdown to HTML
@@ -497,31 +484,14 @@ fn convert_to_html(content: &str, input_ext: &str, output_path: &Path) -> Conver
                 html_escape(content)
             )
         }
-        _ => {
-            return ConvertResult {
-                success: false,
-                output_path: None,
-                error: Some("Unsupported input format".to_string()),
-            }
-        }
+        _ => return Err(ConvertError::unsupported_pair(input_ext, "html")),
     };
 
-    match std::fs::write(output_path, html) {
-        Ok(()) => ConvertResult {
-            success: true,
-            output_path: Some(output_path.to_string_lossy().to_string()),
-            error: None,
-        },
-        Err(e) => ConvertResult {
-            success: false,
-            output_path: None,
-            error: Some(format!("Write failed: {}", e)),
-        },
-    }
+    std::fs::write(output_path, html).map_err(|e| ConvertError::io("Write failed", e))
 }
 
 /// Convert to plain text
-fn convert_to_txt(content: &str, input_ext: &str, output_path: &Path) -> ConvertResult {
+fn convert_to_txt(content: &str, input_ext: &str, output_path: &Path) -> Result<(), ConvertError> {
     let text = match input_ext {
         "md" | "markdown" => {
             // Simply remove Markdown markers
@@ -541,27 +511,10 @@ fn convert_to_txt(content: &str, input_ext: &str, output_path: &Path) -> Convert
             re.replace_all(content, "").to_string()
         }
         "txt" => content.to_string(),
-        _ => {
-            return ConvertResult {
-                success: false,
-                output_path: None,
-                error: Some("Unsupported input format".to_string()),
-            }
-        }
+        _ => return Err(ConvertError::unsupported_pair(input_ext, "txt")),
     };
 
-    match std::fs::write(output_path, text) {
-        Ok(()) => ConvertResult {
-            success: true,
-            output_path: Some(output_path.to_string_lossy().to_string()),
-            error: None,
-        },
-        Err(e) => ConvertResult {
-            success: false,
-            output_path: None,
-            error: Some(format!("Write failed: {}", e)),
-        },
-    }
+    std::fs::write(output_path, text).map_err(|e| ConvertError::io("Write failed", e))
 }
 
 /// HTML escape
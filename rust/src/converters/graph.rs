@@ -0,0 +1,236 @@
+use super::{registry, Converter, ConverterRegistry};
+use std::cmp::Reverse;
+use std::collections::{BinaryHeap, HashMap};
+use std::sync::OnceLock;
+
+/// One hop of a conversion route
+#[derive(Clone, Copy)]
+pub struct RouteStep<'a> {
+    pub converter: &'a dyn Converter,
+    pub from: &'a str,
+    pub to: &'a str,
+}
+
+struct Edge<'a> {
+    to: usize,
+    cost: u32,
+    converter: &'a dyn Converter,
+}
+
+/// Formats as nodes, registered conversions as edges
+///
+/// Used to reach formats no single converter handles by chaining conversions
+/// (e.g. docx -> md -> pdf).
+pub struct ConversionGraph<'a> {
+    formats: Vec<&'a str>,
+    edges: Vec<Vec<Edge<'a>>>,
+}
+
+impl<'a> ConversionGraph<'a> {
+    pub fn new(registry: &'a ConverterRegistry) -> Self {
+        let mut graph = Self {
+            formats: Vec::new(),
+            edges: Vec::new(),
+        };
+        let mut index: HashMap<&str, usize> = HashMap::new();
+        let mut node = |graph: &mut Self, format: &'a str| {
+            *index.entry(format).or_insert_with(|| {
+                graph.formats.push(format);
+                graph.edges.push(Vec::new());
+                graph.formats.len() - 1
+            })
+        };
+
+        for converter in registry.converters() {
+            for &input in converter.inputs() {
+                for &output in converter.outputs() {
+                    if !converter.supports(input, output) {
+                        continue;
+                    }
+                    let from = node(&mut graph, input);
+                    let to = node(&mut graph, output);
+                    // Like `ConverterRegistry::find`, the first registered converter wins
+                    if graph.edges[from].iter().all(|e| e.to != to) {
+                        graph.edges[from].push(Edge {
+                            to,
+                            cost: converter.cost(),
+                            converter,
+                        });
+                    }
+                }
+            }
+        }
+        graph
+    }
+
+    /// Conversions leading from `input_ext` to `output_ext`
+    ///
+    /// A direct conversion always wins (every extra step may lose information);
+    /// otherwise the cheapest chain through intermediate formats is used.
+    pub fn route(&self, input_ext: &str, output_ext: &str) -> Option<Vec<RouteStep<'a>>> {
+        let start = self.index_of(input_ext)?;
+        let target = self.index_of(output_ext)?;
+        if let Some(edge) = self.edges[start].iter().find(|e| e.to == target) {
+            return Some(vec![RouteStep {
+                converter: edge.converter,
+                from: self.formats[start],
+                to: self.formats[target],
+            }]);
+        }
+        let previous = self.shortest_paths(start).previous;
+
+        let mut steps = Vec::new();
+        let mut node = target;
+        while node != start {
+            let (from, edge) = previous[node]?;
+            let edge = &self.edges[from][edge];
+            steps.push(RouteStep {
+                converter: edge.converter,
+                from: self.formats[from],
+                to: self.formats[node],
+            });
+            node = from;
+        }
+        steps.reverse();
+        (!steps.is_empty()).then_some(steps)
+    }
+
+    /// Every format reachable from `input_ext`, cheapest first
+    pub fn reachable_from(&self, input_ext: &str) -> Vec<String> {
+        let Some(start) = self.index_of(input_ext) else {
+            return Vec::new();
+        };
+        let costs = self.shortest_paths(start).costs;
+        let mut reachable: Vec<_> = costs
+            .iter()
+            .enumerate()
+            .filter_map(|(node, cost)| {
+                let cost = (*cost)?;
+                let format = self.formats[node];
+                (node != start && !crate::detect::same_format(format, input_ext))
+                    .then_some((cost, node))
+            })
+            .collect();
+        // Ties keep registration order
+        reachable.sort();
+        reachable
+            .into_iter()
+            .map(|(_, node)| self.formats[node].to_string())
+            .collect()
+    }
+
+    fn index_of(&self, format: &str) -> Option<usize> {
+        self.formats.iter().position(|f| *f == format)
+    }
+
+    /// Dijkstra from `start`
+    fn shortest_paths(&self, start: usize) -> ShortestPaths {
+        let mut costs: Vec<Option<(u32, usize)>> = vec![None; self.formats.len()];
+        let mut previous = vec![None; self.formats.len()];
+        let mut queue = BinaryHeap::new();
+        costs[start] = Some((0, 0));
+        queue.push(Reverse((0, 0, start)));
+
+        while let Some(Reverse((cost, hops, node))) = queue.pop() {
+            if costs[node].is_some_and(|best| best < (cost, hops)) {
+                continue;
+            }
+            for (index, edge) in self.edges[node].iter().enumerate() {
+                // Same-format edges only serve direct routes
+                if crate::detect::same_format(self.formats[node], self.formats[edge.to]) {
+                    continue;
+                }
+                let next = (cost + edge.cost, hops + 1);
+                if costs[edge.to].is_none_or(|best| next < best) {
+                    costs[edge.to] = Some(next);
+                    previous[edge.to] = Some((node, index));
+                    queue.push(Reverse((next.0, next.1, edge.to)));
+                }
+            }
+        }
+        ShortestPaths { costs, previous }
+    }
+}
+
+struct ShortestPaths {
+    /// `(cost, hop count)` of the cheapest route to each node
+    costs: Vec<Option<(u32, usize)>>,
+    /// `(node, edge index)` the cheapest route arrives through
+    previous: Vec<Option<(usize, usize)>>,
+}
+
+/// Conversion graph of the global registry
+pub fn graph() -> &'static ConversionGraph<'static> {
+    static GRAPH: OnceLock<ConversionGraph<'static>> = OnceLock::new();
+    GRAPH.get_or_init(|| ConversionGraph::new(registry()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::converters::config::ConfigConverter;
+
+    fn formats(steps: &[RouteStep]) -> Vec<String> {
+        let mut formats = vec![steps[0].from.to_string()];
+        formats.extend(steps.iter().map(|step| step.to.to_string()));
+        formats
+    }
+
+    fn converter(
+        inputs: &'static [&'static str],
+        outputs: &'static [&'static str],
+        lossy: bool,
+    ) -> ConfigConverter {
+        ConfigConverter {
+            inputs,
+            outputs,
+            lossy,
+        }
+    }
+
+    #[test]
+    fn route_prefers_a_direct_conversion() {
+        let steps = graph().route("yaml", "yml").unwrap();
+        assert_eq!(formats(&steps), ["yaml", "yml"]);
+        assert_eq!(steps[0].converter.name(), "config");
+    }
+
+    #[test]
+    fn route_chains_converters() {
+        let steps = graph().route("docx", "pdf").unwrap();
+        assert_eq!(formats(&steps), ["docx", "md", "pdf"]);
+        assert!(graph().route("png", "mp3").is_none());
+        assert!(graph().route("nope", "png").is_none());
+    }
+
+    #[test]
+    fn route_avoids_lossy_intermediates() {
+        let mut registry = ConverterRegistry::new();
+        registry.register(converter(&["a"], &["lossy"], true));
+        registry.register(converter(&["lossy"], &["c"], false));
+        registry.register(converter(&["a"], &["b"], false));
+        registry.register(converter(&["b"], &["c"], false));
+        let graph = ConversionGraph::new(&registry);
+        assert_eq!(formats(&graph.route("a", "c").unwrap()), ["a", "b", "c"]);
+    }
+
+    #[test]
+    fn route_does_not_pass_through_same_format_edges() {
+        let mut registry = ConverterRegistry::new();
+        registry.register(converter(&["yaml"], &["yml"], false));
+        registry.register(converter(&["yml"], &["json"], false));
+        let graph = ConversionGraph::new(&registry);
+        assert!(graph.route("yaml", "yml").is_some());
+        assert!(graph.route("yaml", "json").is_none());
+    }
+
+    #[test]
+    fn reachable_from_lists_cheapest_first_without_the_input_format() {
+        assert_eq!(graph().reachable_from("yaml"), ["json", "properties"]);
+        assert_eq!(
+            graph().reachable_from("properties"),
+            ["yaml", "yml", "json"]
+        );
+        assert!(graph().reachable_from("nope").is_empty());
+    }
+}
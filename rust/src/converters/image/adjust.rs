@@ -0,0 +1,251 @@
+use crate::api::ConvertOptions;
+use crate::error::ConvertError;
+use image::DynamicImage;
+
+/// Apply the color adjustments and filters, in order: grayscale, hue rotation, brightness,
+/// contrast, gamma, invert, blur, sharpen
+pub fn apply_adjustments(
+    img: DynamicImage,
+    options: &ConvertOptions,
+) -> Result<DynamicImage, ConvertError> {
+    let mut img = img;
+    if options.grayscale == Some(true) {
+        img = img.grayscale();
+    }
+    if let Some(degrees) = options.hue_rotate {
+        if degrees.rem_euclid(360) != 0 {
+            img = img.huerotate(degrees.rem_euclid(360));
+        }
+    }
+
+    let brightness = percent(options.brightness, "brightness")?;
+    let contrast = percent(options.contrast, "contrast")?;
+    let gamma = match options.gamma {
+        Some(gamma) if !(gamma.is_finite() && gamma > 0.0) => {
+            return Err(ConvertError::invalid_option("gamma", "must be positive"))
+        }
+        gamma => gamma.map(|gamma| 1.0 / gamma as f32),
+    };
+    let invert = options.invert == Some(true);
+    if brightness.is_some() || contrast.is_some() || gamma.is_some() || invert {
+        apply_curve(&mut img, |mut value| {
+            if let Some(brightness) = brightness {
+                value *= 1.0 + brightness;
+            }
+            if let Some(contrast) = contrast {
+                value = (value - 0.5) * (1.0 + contrast) + 0.5;
+            }
+            value = value.clamp(0.0, 1.0);
+            if let Some(exponent) = gamma {
+                value = value.powf(exponent);
+            }
+            if invert {
+                value = 1.0 - value;
+            }
+            value
+        });
+    }
+
+    if let Some(sigma) = options.blur {
+        if !(sigma.is_finite() && sigma >= 0.0) {
+            return Err(ConvertError::invalid_option("blur", "must not be negative"));
+        }
+        if sigma > 0.0 {
+            img = img.blur(sigma as f32);
+        }
+    }
+    if let Some(sigma) = options.sharpen {
+        if !(sigma.is_finite() && sigma > 0.0) {
+            return Err(ConvertError::invalid_option("sharpen", "must be positive"));
+        }
+        let threshold = match options.sharpen_threshold {
+            None => 0,
+            Some(threshold @ 0..=255) => threshold,
+            Some(threshold) => {
+                return Err(ConvertError::invalid_option(
+                    "sharpen_threshold",
+                    format!("{} (must be 0-255)", threshold),
+                ))
+            }
+        };
+        img = sharpen(img, sigma as f32, threshold);
+    }
+    Ok(img)
+}
+
+/// `value` percent as a fraction, checked to be within -100..=100
+fn percent(value: Option<i32>, option: &str) -> Result<Option<f32>, ConvertError> {
+    match value {
+        None => Ok(None),
+        Some(value @ -100..=100) => Ok(Some(value as f32 / 100.0)),
+        Some(value) => Err(ConvertError::invalid_option(
+            option,
+            format!("{} (must be -100 to 100)", value),
+        )),
+    }
+}
+
+/// Map every color channel (not alpha) through `curve`, which works on 0.0-1.0 values
+fn apply_curve(img: &mut DynamicImage, curve: impl Fn(f32) -> f32) {
+    let lut8: Vec<u8> = (0..=u8::MAX)
+        .map(|v| (curve(v as f32 / 255.0).clamp(0.0, 1.0) * 255.0).round() as u8)
+        .collect();
+    let lut16 = || -> Vec<u16> {
+        (0..=u16::MAX)
+            .map(|v| (curve(v as f32 / 65535.0).clamp(0.0, 1.0) * 65535.0).round() as u16)
+            .collect()
+    };
+
+    match img {
+        DynamicImage::ImageLuma8(buffer) => map_samples(buffer, 1, |v| lut8[v as usize]),
+        DynamicImage::ImageLumaA8(buffer) => map_samples(buffer, 2, |v| lut8[v as usize]),
+        DynamicImage::ImageRgb8(buffer) => map_samples(buffer, 3, |v| lut8[v as usize]),
+        DynamicImage::ImageRgba8(buffer) => map_samples(buffer, 4, |v| lut8[v as usize]),
+        DynamicImage::ImageLuma16(buffer) => {
+            let lut = lut16();
+            map_samples(buffer, 1, |v| lut[v as usize])
+        }
+        DynamicImage::ImageLumaA16(buffer) => {
+            let lut = lut16();
+            map_samples(buffer, 2, |v| lut[v as usize])
+        }
+        DynamicImage::ImageRgb16(buffer) => {
+            let lut = lut16();
+            map_samples(buffer, 3, |v| lut[v as usize])
+        }
+        DynamicImage::ImageRgba16(buffer) => {
+            let lut = lut16();
+            map_samples(buffer, 4, |v| lut[v as usize])
+        }
+        DynamicImage::ImageRgb32F(buffer) => map_samples(buffer, 3, |v| curve(v.clamp(0.0, 1.0))),
+        DynamicImage::ImageRgba32F(buffer) => map_samples(buffer, 4, |v| curve(v.clamp(0.0, 1.0))),
+        _ => {}
+    }
+}
+
+/// Map the color samples of interleaved pixels with `channels` samples each; the alpha
+/// sample of 2 and 4 channel pixels is left alone
+fn map_samples<T: Copy>(samples: &mut [T], channels: usize, map: impl Fn(T) -> T) {
+    let color_channels = if channels.is_multiple_of(2) {
+        channels - 1
+    } else {
+        channels
+    };
+    for pixel in samples.chunks_exact_mut(channels) {
+        for sample in &mut pixel[..color_channels] {
+            *sample = map(*sample);
+        }
+    }
+}
+
+/// Unsharp mask; `threshold` is in 8-bit units
+fn sharpen(img: DynamicImage, sigma: f32, threshold: i32) -> DynamicImage {
+    match img {
+        // The `image` crate compares float samples as integers, which never sharpens
+        DynamicImage::ImageRgb32F(_) => {
+            DynamicImage::ImageRgb16(img.to_rgb16()).unsharpen(sigma, threshold * 257)
+        }
+        DynamicImage::ImageRgba32F(_) => {
+            DynamicImage::ImageRgba16(img.to_rgba16()).unsharpen(sigma, threshold * 257)
+        }
+        _ if img.color().bytes_per_pixel() / img.color().channel_count() == 2 => {
+            img.unsharpen(sigma, threshold * 257)
+        }
+        _ => img.unsharpen(sigma, threshold),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use image::{Rgba, RgbaImage};
+
+    /// The first pixel of a 2x1 `[100, 150, 200, 128]` / `[0, 0, 0, 255]` image after `options`
+    fn adjusted(options: ConvertOptions) -> [u8; 4] {
+        let mut img = RgbaImage::from_pixel(2, 1, Rgba([0, 0, 0, 255]));
+        img.put_pixel(0, 0, Rgba([100, 150, 200, 128]));
+        let img = apply_adjustments(DynamicImage::ImageRgba8(img), &options).unwrap();
+        img.to_rgba8().get_pixel(0, 0).0
+    }
+
+    #[test]
+    fn color_adjustments_map_the_channels_but_not_alpha() {
+        assert_eq!(
+            adjusted(ConvertOptions {
+                invert: Some(true),
+                ..Default::default()
+            }),
+            [155, 105, 55, 128]
+        );
+        assert_eq!(
+            adjusted(ConvertOptions {
+                brightness: Some(50),
+                ..Default::default()
+            }),
+            [150, 225, 255, 128]
+        );
+        assert_eq!(
+            adjusted(ConvertOptions {
+                contrast: Some(-100),
+                ..Default::default()
+            }),
+            [128, 128, 128, 128]
+        );
+        let [r, g, b, a] = adjusted(ConvertOptions {
+            grayscale: Some(true),
+            ..Default::default()
+        });
+        assert!(r == g && g == b && a == 128);
+        let [r, _, b, _] = adjusted(ConvertOptions {
+            hue_rotate: Some(180),
+            ..Default::default()
+        });
+        assert!(r > b, "{} <= {}", r, b);
+    }
+
+    #[test]
+    fn filters_change_edges() {
+        let blurred = adjusted(ConvertOptions {
+            blur: Some(1.0),
+            ..Default::default()
+        });
+        assert!(blurred[0] < 100);
+        let sharpened = adjusted(ConvertOptions {
+            sharpen: Some(1.0),
+            ..Default::default()
+        });
+        assert!(sharpened[0] > 100);
+        let unchanged = adjusted(ConvertOptions {
+            sharpen: Some(1.0),
+            sharpen_threshold: Some(255),
+            ..Default::default()
+        });
+        assert_eq!(unchanged[..3], [100, 150, 200]);
+    }
+
+    #[test]
+    fn out_of_range_adjustments_are_rejected() {
+        let invalid = [
+            ConvertOptions {
+                brightness: Some(101),
+                ..Default::default()
+            },
+            ConvertOptions {
+                gamma: Some(0.0),
+                ..Default::default()
+            },
+            ConvertOptions {
+                blur: Some(-1.0),
+                ..Default::default()
+            },
+            ConvertOptions {
+                sharpen: Some(1.0),
+                sharpen_threshold: Some(256),
+                ..Default::default()
+            },
+        ];
+        for options in invalid {
+            assert!(apply_adjustments(DynamicImage::new_rgb8(1, 1), &options).is_err());
+        }
+    }
+}
@@ -0,0 +1,533 @@
+use super::metadata::ImageMetadata;
+use super::transform::apply_transforms;
+use super::webp::{encode_image_chunks, riff, u24, vp8x, write_chunk, WebPSettings};
+use super::{write_image, MAX_PIXELS};
+use crate::converters::output::resolve_output_path;
+use crate::converters::ConvertJob;
+use crate::error::ConvertError;
+use image::codecs::gif::{GifDecoder, GifEncoder, Repeat};
+use image::codecs::webp::WebPDecoder;
+use image::{AnimationDecoder, Delay, DynamicImage, Frame, Frames, ImageFormat, RgbaImage};
+use image_webp::LoopCount;
+use std::fs::File;
+use std::io::{BufWriter, Cursor};
+use std::path::{Path, PathBuf};
+
+/// An animated GIF or WebP, decoded frame by frame while it is converted
+///
+/// Only one full-canvas frame is held in memory at a time, however long the animation is.
+pub struct Animation {
+    data: Vec<u8>,
+    format: &'static str,
+    count: usize,
+    /// How many times the animation plays (`None`: forever)
+    plays: Option<u32>,
+}
+
+impl Animation {
+    /// The frames in order, each covering the whole canvas
+    fn frames(&self) -> Result<Frames<'_>, ConvertError> {
+        let data = Cursor::new(self.data.as_slice());
+        let frames = match self.format {
+            "gif" => GifDecoder::new(data).map(|decoder| decoder.into_frames()),
+            _ => WebPDecoder::new(data).map(|decoder| decoder.into_frames()),
+        };
+        frames.map_err(|e| ConvertError::decode(self.format, e))
+    }
+
+    /// Frame `index` (0-based) as a still image, decoding no further than needed
+    pub fn into_frame(self, index: i32) -> Result<DynamicImage, ConvertError> {
+        let out_of_range = || {
+            ConvertError::invalid_option(
+                "frame",
+                format!(
+                    "{} is out of range (the input has {} frames)",
+                    index, self.count
+                ),
+            )
+        };
+        let index = usize::try_from(index)
+            .ok()
+            .filter(|&index| index < self.count)
+            .ok_or_else(out_of_range)?;
+        let frame = self
+            .frames()?
+            .nth(index)
+            .ok_or_else(out_of_range)?
+            .map_err(|e| ConvertError::decode(self.format, e))?;
+        Ok(DynamicImage::ImageRgba8(frame.into_buffer()))
+    }
+}
+
+/// Open an animated GIF or WebP, reading its frame count without decoding the frames
+///
+/// Returns `None` for single-frame images, which take the still image path.
+pub fn open_animation(path: &Path, input_ext: &str) -> Result<Option<Animation>, ConvertError> {
+    let format = match input_ext {
+        "gif" => "gif",
+        "webp" => "webp",
+        _ => return Ok(None),
+    };
+    let data = std::fs::read(path).map_err(|e| ConvertError::io("Failed to read file", e))?;
+
+    let (width, height, count, plays) = if format == "gif" {
+        let blocks = scan_gif(&data);
+        // Logical screen size, which every decoded frame covers
+        let size = |offset: usize| {
+            data.get(offset..offset + 2)
+                .map_or(0, |v| u16::from_le_bytes([v[0], v[1]]) as u32)
+        };
+        (size(6), size(8), blocks.frames, blocks.plays())
+    } else {
+        let decoder = image_webp::WebPDecoder::new(Cursor::new(data.as_slice()))
+            .map_err(|e| ConvertError::decode(format, e))?;
+        if !decoder.is_animated() {
+            return Ok(None);
+        }
+        let (width, height) = decoder.dimensions();
+        let plays = match decoder.loop_count() {
+            LoopCount::Forever => None,
+            LoopCount::Times(n) => Some(n.get() as u32),
+        };
+        (width, height, decoder.num_frames() as usize, plays)
+    };
+    if count <= 1 {
+        return Ok(None);
+    }
+    if width as u64 * height as u64 > MAX_PIXELS {
+        return Err(ConvertError::decode(
+            format,
+            format!(
+                "the {}x{} canvas is over the limit of {} megapixels",
+                width,
+                height,
+                MAX_PIXELS >> 20
+            ),
+        ));
+    }
+
+    Ok(Some(Animation {
+        data,
+        format,
+        count,
+        plays,
+    }))
+}
+
+/// What walking the blocks of a GIF finds
+#[derive(Default)]
+struct GifBlocks {
+    /// Repetition count of the NETSCAPE2.0 extension (0: forever)
+    repetitions: Option<u16>,
+    frames: usize,
+}
+
+impl GifBlocks {
+    /// How many times the GIF plays (`None`: forever; without the extension, once)
+    fn plays(&self) -> Option<u32> {
+        match self.repetitions {
+            Some(0) => None,
+            // The count is the number of repetitions after the first play
+            Some(n) => Some(n as u32 + 1),
+            None => Some(1),
+        }
+    }
+}
+
+/// Walk the blocks of a GIF up to its trailer (or where it is cut off)
+///
+/// The NETSCAPE2.0 extension may come after the first frame.
+fn scan_gif(data: &[u8]) -> GifBlocks {
+    let mut blocks = GifBlocks::default();
+    walk_gif(data, &mut blocks);
+    blocks
+}
+
+fn walk_gif(data: &[u8], blocks: &mut GifBlocks) -> Option<()> {
+    let color_table_size = |flags: u8| {
+        if flags & 0x80 != 0 {
+            3 << ((flags & 0x07) + 1)
+        } else {
+            0
+        }
+    };
+    // Header and logical screen descriptor, then the global color table
+    let mut pos = 13 + color_table_size(*data.get(10)?);
+    loop {
+        match *data.get(pos)? {
+            // Extension: label, then data sub-blocks
+            0x21 => {
+                let label = *data.get(pos + 1)?;
+                pos += 2;
+                let identifier = sub_block(data, pos)?;
+                if label == 0xFF && identifier == b"NETSCAPE2.0" {
+                    let loop_block = sub_block(data, pos + 1 + identifier.len())?;
+                    if let [1, low, high, ..] = *loop_block {
+                        blocks.repetitions = Some(u16::from_le_bytes([low, high]));
+                    }
+                }
+                pos = skip_sub_blocks(data, pos)?;
+            }
+            // Image: descriptor, local color table, LZW code size, then data sub-blocks
+            0x2C => {
+                let flags = *data.get(pos + 9)?;
+                pos = skip_sub_blocks(data, pos + 10 + color_table_size(flags) + 1)?;
+                blocks.frames += 1;
+            }
+            // Trailer
+            _ => return Some(()),
+        }
+    }
+}
+
+/// Contents of the sub-block at `pos`
+fn sub_block(data: &[u8], pos: usize) -> Option<&[u8]> {
+    let len = *data.get(pos)? as usize;
+    data.get(pos + 1..pos + 1 + len)
+}
+
+/// Position after the sub-blocks starting at `pos` and their terminator
+fn skip_sub_blocks(data: &[u8], mut pos: usize) -> Option<usize> {
+    loop {
+        let len = *data.get(pos)? as usize;
+        pos += 1 + len;
+        if len == 0 {
+            return Some(pos);
+        }
+    }
+}
+
+/// Convert an animation: keep it animated (GIF, WebP), write every frame (`extract_frames`)
+/// or take a single frame (`frame`, still output formats)
+pub fn convert_animation(
+    job: &ConvertJob,
+    animation: Animation,
+    format: ImageFormat,
+) -> Result<(), ConvertError> {
+    let options = job.options;
+    let count = animation.count;
+    let extract = options.extract_frames == Some(true);
+
+    if options.frame.is_some()
+        || (!extract && !matches!(format, ImageFormat::Gif | ImageFormat::WebP))
+    {
+        let frame = animation.into_frame(options.frame.unwrap_or(0))?;
+        let img = apply_transforms(frame, options)?;
+        return write_image(
+            &img,
+            &ImageMetadata::default(),
+            format,
+            job,
+            job.output_path,
+        );
+    }
+
+    let mut writer = if extract {
+        None
+    } else {
+        Some(AnimationWriter::new(
+            format,
+            job.output_path,
+            animation.plays,
+            WebPSettings::from_options(options)?,
+        )?)
+    };
+    for (index, frame) in animation.frames()?.enumerate() {
+        if job.cancel.is_cancelled() {
+            return Err(ConvertError::Cancelled);
+        }
+        let frame = frame.map_err(|e| ConvertError::decode(animation.format, e))?;
+        job.progress.report(
+            (index * 100 / count) as i32,
+            format!("Frame {}/{}", index + 1, count),
+        );
+
+        let delay = frame.delay();
+        let img = apply_transforms(DynamicImage::ImageRgba8(frame.into_buffer()), options)?;
+        match &mut writer {
+            Some(writer) => writer.add(img.into_rgba8(), delay)?,
+            None => {
+                if let Some(path) = claim_frame_path(job, index, count)? {
+                    write_image(&img, &ImageMetadata::default(), format, job, &path)?;
+                }
+            }
+        }
+    }
+    match writer {
+        Some(writer) => writer.finish(),
+        None => Ok(()),
+    }
+}
+
+/// Claim the path of frame `index` when extracting frames (`photo.png` -> `photo_001.png`)
+///
+/// The path is resolved with the job's `on_conflict` like any output and recorded in
+/// `job.outputs`; `None` means the frame exists and is skipped (`OnConflict::Skip`).
+pub fn claim_frame_path(
+    job: &ConvertJob,
+    index: usize,
+    count: usize,
+) -> Result<Option<PathBuf>, ConvertError> {
+    let output_path = job.output_path;
+    let stem = output_path
+        .file_stem()
+        .map(|s| s.to_string_lossy().to_string())
+        .unwrap_or_default();
+    let ext = output_path
+        .extension()
+        .map(|s| s.to_string_lossy().to_string())
+        .unwrap_or_default();
+    let digits = count.to_string().len().max(3);
+    let output = resolve_output_path(
+        output_path.parent().unwrap_or(Path::new("")),
+        &format!("{}_{:0digits$}", stem, index + 1),
+        &ext,
+        job.options.on_conflict.unwrap_or_default(),
+    )?;
+
+    if output.skip() {
+        job.warn(format!(
+            "{} already exists; the frame was skipped",
+            output.path().display()
+        ));
+    }
+    if let Some(warning) = output.warning() {
+        job.warn(warning);
+    }
+    let path = (!output.skip()).then(|| output.path().to_path_buf());
+    job.outputs
+        .lock()
+        .unwrap_or_else(|e| e.into_inner())
+        .push(output);
+    Ok(path)
+}
+
+/// Animated GIF / WebP output, written frame by frame
+enum AnimationWriter {
+    Gif(GifEncoder<BufWriter<File>>),
+    WebP(AnimatedWebP),
+}
+
+impl AnimationWriter {
+    fn new(
+        format: ImageFormat,
+        path: &Path,
+        plays: Option<u32>,
+        webp: WebPSettings,
+    ) -> Result<Self, ConvertError> {
+        if format == ImageFormat::WebP {
+            return Ok(Self::WebP(AnimatedWebP {
+                path: path.to_path_buf(),
+                plays,
+                settings: webp,
+                canvas: (0, 0),
+                frames: Vec::new(),
+            }));
+        }
+
+        let file =
+            File::create(path).map_err(|e| ConvertError::io("Failed to create output file", e))?;
+        // Speed 10 of 30: NeuQuant's best quality (1) is far too slow for long animations
+        let mut encoder = GifEncoder::new_with_speed(BufWriter::new(file), 10);
+        // Without the extension a GIF plays once
+        let repeat = match plays {
+            None => Some(Repeat::Infinite),
+            Some(plays) if plays > 1 => {
+                Some(Repeat::Finite((plays - 1).min(u16::MAX as u32) as u16))
+            }
+            Some(_) => None,
+        };
+        if let Some(repeat) = repeat {
+            encoder
+                .set_repeat(repeat)
+                .map_err(|e| ConvertError::encode("gif", e))?;
+        }
+        Ok(Self::Gif(encoder))
+    }
+
+    fn add(&mut self, img: RgbaImage, delay: Delay) -> Result<(), ConvertError> {
+        match self {
+            Self::Gif(encoder) => encoder
+                .encode_frame(Frame::from_parts(img, 0, 0, delay))
+                .map_err(|e| ConvertError::encode("gif", e)),
+            Self::WebP(webp) => webp.add(img, delay),
+        }
+    }
+
+    fn finish(self) -> Result<(), ConvertError> {
+        match self {
+            // The GIF trailer is written on drop
+            Self::Gif(encoder) => {
+                drop(encoder);
+                Ok(())
+            }
+            Self::WebP(webp) => webp.finish(),
+        }
+    }
+}
+
+/// Animated WebP container around individually encoded frames
+struct AnimatedWebP {
+    path: PathBuf,
+    plays: Option<u32>,
+    settings: WebPSettings,
+    canvas: (u32, u32),
+    /// `ANMF` chunks
+    frames: Vec<u8>,
+}
+
+impl AnimatedWebP {
+    fn add(&mut self, img: RgbaImage, delay: Delay) -> Result<(), ConvertError> {
+        let (width, height) = img.dimensions();
+        let image_chunks = encode_image_chunks(&DynamicImage::ImageRgba8(img), self.settings)?;
+
+        let (numer, denom) = delay.numer_denom_ms();
+        let duration = (numer + denom / 2) / denom.max(1);
+        let mut data = Vec::with_capacity(16 + image_chunks.len());
+        // Frame offset (x / 2, y / 2), frame size - 1, duration in ms
+        data.extend_from_slice(&u24(0));
+        data.extend_from_slice(&u24(0));
+        data.extend_from_slice(&u24(width - 1));
+        data.extend_from_slice(&u24(height - 1));
+        data.extend_from_slice(&u24(duration.min(0xFF_FFFF)));
+        // Frames cover the whole canvas: replace instead of alpha-blending, no disposal
+        data.push(0b10);
+        data.extend_from_slice(&image_chunks);
+        write_chunk(&mut self.frames, b"ANMF", &data);
+
+        self.canvas = (self.canvas.0.max(width), self.canvas.1.max(height));
+        Ok(())
+    }
+
+    fn finish(self) -> Result<(), ConvertError> {
+        let (width, height) = self.canvas;
+        let mut anim = vec![0; 4]; // Background color (transparent)
+        let loops = self
+            .plays
+            .map_or(0, |plays| plays.min(u16::MAX as u32) as u16);
+        anim.extend_from_slice(&loops.to_le_bytes());
+
+        let mut chunks = Vec::new();
+        // Flags: alpha, animation
+        write_chunk(&mut chunks, b"VP8X", &vp8x(0b0001_0010, width, height));
+        write_chunk(&mut chunks, b"ANIM", &anim);
+        chunks.extend_from_slice(&self.frames);
+        std::fs::write(&self.path, riff(&chunks))
+            .map_err(|e| ConvertError::io("Failed to write output file", e))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::api::{ConvertErrorCode, ConvertOptions, ConvertResult, OnConflict};
+
+    fn encode_gif(repeat: Option<Repeat>) -> Vec<u8> {
+        let mut data = Vec::new();
+        {
+            let mut encoder = GifEncoder::new(&mut data);
+            if let Some(repeat) = repeat {
+                encoder.set_repeat(repeat).unwrap();
+            }
+            let frames = (0..2u8)
+                .map(|i| Frame::new(RgbaImage::from_pixel(4, 4, image::Rgba([i, 0, 0, 255]))));
+            encoder.encode_frames(frames).unwrap();
+        }
+        data
+    }
+
+    #[test]
+    fn scan_gif_reads_the_loop_and_frame_counts() {
+        assert_eq!(scan_gif(&encode_gif(None)).plays(), Some(1));
+        let blocks = scan_gif(&encode_gif(Some(Repeat::Infinite)));
+        assert_eq!((blocks.plays(), blocks.frames), (None, 2));
+        assert_eq!(
+            scan_gif(&encode_gif(Some(Repeat::Finite(2)))).plays(),
+            Some(3)
+        );
+    }
+
+    #[test]
+    fn scan_gif_tolerates_truncated_files() {
+        let data = encode_gif(Some(Repeat::Infinite));
+        for len in 0..data.len() {
+            scan_gif(&data[..len]);
+        }
+    }
+
+    #[test]
+    fn open_animation_decodes_only_the_requested_frame() {
+        let dir = tempfile::tempdir().unwrap();
+        let input = dir.path().join("anim.gif");
+        std::fs::write(&input, encode_gif(None)).unwrap();
+
+        let frame = open_animation(&input, "gif")
+            .unwrap()
+            .unwrap()
+            .into_frame(1);
+        assert_eq!(frame.unwrap().to_rgba8().get_pixel(0, 0).0, [1, 0, 0, 255]);
+        let Err(error) = open_animation(&input, "gif")
+            .unwrap()
+            .unwrap()
+            .into_frame(2)
+        else {
+            panic!("frame 2 of 2 was decoded");
+        };
+        assert_eq!(error.code(), ConvertErrorCode::InvalidOption);
+    }
+
+    #[test]
+    fn open_animation_rejects_oversized_canvases() {
+        let dir = tempfile::tempdir().unwrap();
+        let input = dir.path().join("anim.gif");
+        let mut data = encode_gif(None);
+        data[6..10].copy_from_slice(&[0xFF; 4]);
+        std::fs::write(&input, data).unwrap();
+        assert!(open_animation(&input, "gif").is_err());
+    }
+
+    fn extract_frames(dir: &Path, on_conflict: OnConflict) -> ConvertResult {
+        let input = dir.join("anim.gif");
+        std::fs::write(&input, encode_gif(None)).unwrap();
+        let options = ConvertOptions {
+            output_format: "png".to_string(),
+            extract_frames: Some(true),
+            on_conflict: Some(on_conflict),
+            ..Default::default()
+        };
+        crate::converters::convert_single(
+            input.to_str().unwrap(),
+            dir.join("out").to_str().unwrap(),
+            &options,
+        )
+    }
+
+    fn names(result: &ConvertResult) -> Vec<String> {
+        let names = result.output_paths.iter().map(|path| {
+            let name = Path::new(path).file_name().unwrap();
+            name.to_string_lossy().to_string()
+        });
+        names.collect()
+    }
+
+    #[test]
+    fn extracted_frames_follow_the_conflict_policy() {
+        let dir = tempfile::tempdir().unwrap();
+        let result = extract_frames(dir.path(), OnConflict::Fail);
+        assert_eq!(names(&result), ["anim_001.png", "anim_002.png"]);
+
+        let result = extract_frames(dir.path(), OnConflict::Fail);
+        assert_eq!(result.error_code, Some(ConvertErrorCode::OutputExists));
+
+        let result = extract_frames(dir.path(), OnConflict::Rename);
+        assert_eq!(names(&result), ["anim_001_1.png", "anim_002_1.png"]);
+
+        let result = extract_frames(dir.path(), OnConflict::Skip);
+        assert!(result.success && result.skipped);
+        assert_eq!(names(&result), ["anim_001.png", "anim_002.png"]);
+        assert_eq!(result.warnings.len(), 2);
+
+        let result = extract_frames(dir.path(), OnConflict::Overwrite);
+        assert_eq!(names(&result), ["anim_001.png", "anim_002.png"]);
+    }
+}
@@ -0,0 +1,59 @@
+use crate::converters::media::ffmpeg_command;
+use crate::converters::process::run_process;
+use crate::converters::ConvertJob;
+use crate::error::ConvertError;
+use image::codecs::avif::AvifEncoder;
+use image::DynamicImage;
+
+/// Encode an AVIF (`image_quality`, `avif_speed`)
+pub fn encode_avif(img: &DynamicImage, job: &ConvertJob) -> Result<Vec<u8>, ConvertError> {
+    let options = job.options;
+    let speed = match options.avif_speed {
+        // The defaults of `cavif`
+        None => 4,
+        Some(speed @ 1..=10) => speed as u8,
+        Some(speed) => {
+            return Err(ConvertError::invalid_option(
+                "avif_speed",
+                format!("{} (must be 1-10)", speed),
+            ))
+        }
+    };
+    let quality = options.image_quality.unwrap_or(85).clamp(1, 100) as u8;
+
+    job.progress.stage("Encoding AVIF");
+    let mut bytes = Vec::new();
+    img.write_with_encoder(AvifEncoder::new_with_speed_quality(
+        &mut bytes, speed, quality,
+    ))
+    .map_err(|e| ConvertError::encode("avif", e))?;
+    Ok(bytes)
+}
+
+/// Decode an AVIF with FFmpeg (the `image` crate only decodes AVIF with the native dav1d
+/// library, which is not bundled)
+pub fn decode_avif(job: &ConvertJob) -> Result<DynamicImage, ConvertError> {
+    let mut cmd = ffmpeg_command(job.options)?;
+    let temp_dir = tempfile::Builder::new()
+        .prefix("convertx-")
+        .tempdir()
+        .map_err(|e| ConvertError::io("Failed to create temporary directory", e))?;
+    let png_path = temp_dir.path().join("decoded.png");
+    cmd.arg("-nostats")
+        .arg("-i")
+        .arg(job.input_path)
+        .arg("-frames:v") // Still image (the first frame of an AVIF sequence)
+        .arg("1")
+        .arg(&png_path);
+
+    job.progress.stage("Decoding AVIF with FFmpeg");
+    let out = run_process("FFmpeg", &mut cmd, job.cancel, |_| {}, |_| {})?;
+    if !out.status.success() {
+        return Err(ConvertError::external_tool_failed(
+            "FFmpeg",
+            out.status.code(),
+            out.stderr,
+        ));
+    }
+    image::open(&png_path).map_err(|e| ConvertError::decode("avif", e))
+}
@@ -0,0 +1,270 @@
+use super::icc::convert_to_srgb;
+use super::metadata::ImageMetadata;
+use super::svg::render_text;
+use super::transform::filter_type;
+use super::{check_pixel_limit, image_format, load_image, parse_color, write_image};
+use crate::api::ConvertOptions;
+use crate::converters::ConvertJob;
+use crate::error::ConvertError;
+use image::{imageops, DynamicImage, Rgba, RgbaImage};
+use std::path::Path;
+
+/// Caption text size in pixels
+const CAPTION_SIZE: u32 = 14;
+/// Space between a thumbnail and its caption
+const CAPTION_GAP: u32 = 4;
+
+/// Contact sheet grid settings
+struct SheetSettings {
+    columns: u32,
+    cell_width: u32,
+    cell_height: u32,
+    spacing: u32,
+    background: Rgba<u8>,
+    captions: bool,
+}
+
+impl SheetSettings {
+    fn from_options(options: &ConvertOptions, count: usize) -> Result<Self, ConvertError> {
+        let positive = |value: Option<i32>, name: &str, default: u32| match value {
+            None => Ok(default),
+            Some(value) if value <= 0 => {
+                Err(ConvertError::invalid_option(name, "must be positive"))
+            }
+            Some(value) => Ok(value as u32),
+        };
+        // Roughly square by default
+        let columns = (1..).find(|c| c * c >= count).unwrap_or(1).max(1) as u32;
+        let spacing = match options.sheet_spacing {
+            None => 10,
+            Some(spacing @ 0..) => spacing as u32,
+            Some(spacing) => {
+                return Err(ConvertError::invalid_option(
+                    "sheet_spacing",
+                    format!("{} (must not be negative)", spacing),
+                ))
+            }
+        };
+        Ok(Self {
+            columns: positive(options.sheet_columns, "sheet_columns", columns)?,
+            cell_width: positive(options.sheet_cell_width, "sheet_cell_width", 200)?,
+            cell_height: positive(options.sheet_cell_height, "sheet_cell_height", 200)?,
+            spacing,
+            background: match &options.sheet_background {
+                Some(value) => parse_color("sheet_background", value)?,
+                None => Rgba([255, 255, 255, 255]),
+            },
+            captions: options.sheet_captions == Some(true),
+        })
+    }
+
+    /// Height of the caption area under each thumbnail
+    fn caption_height(&self) -> u32 {
+        if self.captions {
+            CAPTION_GAP + CAPTION_SIZE * 3 / 2
+        } else {
+            0
+        }
+    }
+
+    /// Black captions on light backgrounds, white ones on dark backgrounds
+    fn caption_color(&self) -> Rgba<u8> {
+        let [r, g, b, a] = self.background.0;
+        let luminance = (299 * r as u32 + 587 * g as u32 + 114 * b as u32) / 1000;
+        if a < 128 || luminance >= 128 {
+            Rgba([0, 0, 0, 255])
+        } else {
+            Rgba([255, 255, 255, 255])
+        }
+    }
+}
+
+/// Write the inputs of `jobs` as thumbnails in a grid, in order, into one image
+///
+/// Every job shares the options of the first one, which also decide the output format.
+pub fn write_contact_sheet(jobs: &[ConvertJob], path: &Path) -> Result<(), ConvertError> {
+    let Some(first) = jobs.first() else {
+        return Err(ConvertError::invalid_option(
+            "input_paths",
+            "no images given",
+        ));
+    };
+    let Some(format) = image_format(first.output_format) else {
+        return Err(ConvertError::invalid_option(
+            "output_format",
+            format!(
+                "{} (a contact sheet must be written in an image format)",
+                first.output_format
+            ),
+        ));
+    };
+    let settings = SheetSettings::from_options(first.options, jobs.len())?;
+
+    let columns = settings.columns.min(jobs.len() as u32);
+    let rows = (jobs.len() as u32).div_ceil(columns);
+    let step_x = settings.cell_width as u64 + settings.spacing as u64;
+    let step_y =
+        settings.cell_height as u64 + settings.caption_height() as u64 + settings.spacing as u64;
+    let width = settings.spacing as u64 + columns as u64 * step_x;
+    let height = settings.spacing as u64 + rows as u64 * step_y;
+    check_pixel_limit("sheet_cell_width", width, height)?;
+
+    let mut canvas = RgbaImage::from_pixel(width as u32, height as u32, settings.background);
+    let filter = filter_type(first.options.resize_filter.unwrap_or_default());
+    for (index, job) in jobs.iter().enumerate() {
+        if job.cancel.is_cancelled() {
+            return Err(ConvertError::Cancelled);
+        }
+        job.progress.report(
+            (index * 100 / jobs.len()) as i32,
+            format!("Image {}/{}", index + 1, jobs.len()),
+        );
+
+        let (img, metadata) = load_image(job)?;
+        // Thumbnails from different color spaces only fit together in sRGB
+        let img = match &metadata.icc {
+            Some(icc) => convert_to_srgb(img, icc, job),
+            None => img,
+        };
+        // Thumbnails are only ever shrunk, then centered in their cell
+        let thumbnail = if img.width() > settings.cell_width || img.height() > settings.cell_height
+        {
+            img.resize(settings.cell_width, settings.cell_height, filter)
+        } else {
+            img
+        };
+        let cell_x = settings.spacing as i64 + (index as u32 % columns) as i64 * step_x as i64;
+        let cell_y = settings.spacing as i64 + (index as u32 / columns) as i64 * step_y as i64;
+        imageops::overlay(
+            &mut canvas,
+            &thumbnail.to_rgba8(),
+            cell_x + (settings.cell_width - thumbnail.width()) as i64 / 2,
+            cell_y + (settings.cell_height - thumbnail.height()) as i64 / 2,
+        );
+
+        if settings.captions {
+            let name = job
+                .input_path
+                .file_name()
+                .map(|name| name.to_string_lossy().to_string())
+                .unwrap_or_default();
+            let (caption, baseline) = render_caption(&name, &settings)?;
+            // Every caption shares the baseline, whatever its glyphs reach up to
+            let top = (settings.cell_height + CAPTION_GAP + CAPTION_SIZE) as f32 - baseline;
+            imageops::overlay(
+                &mut canvas,
+                &caption,
+                cell_x + (settings.cell_width as i64 - caption.width() as i64) / 2,
+                cell_y + top.round() as i64,
+            );
+        }
+    }
+
+    // Thumbnails are composited onto the background, so an opaque one leaves no alpha
+    let sheet = if settings.background[3] == u8::MAX {
+        DynamicImage::ImageRgb8(DynamicImage::ImageRgba8(canvas).into_rgb8())
+    } else {
+        DynamicImage::ImageRgba8(canvas)
+    };
+    write_image(&sheet, &ImageMetadata::default(), format, first, path)
+}
+
+/// Render a file name, shortened with "…" to fit the cell width, and its baseline
+fn render_caption(name: &str, settings: &SheetSettings) -> Result<(RgbaImage, f32), ConvertError> {
+    let render = |text: &str| {
+        render_text(text, None, CAPTION_SIZE as f32, settings.caption_color())
+            .map_err(|e| ConvertError::invalid_option("sheet_captions", e))
+    };
+    let caption = render(name)?;
+    if caption.0.width() <= settings.cell_width {
+        return Ok(caption);
+    }
+
+    let chars: Vec<char> = name.chars().collect();
+    // Start from the share of the name that should fit, then drop characters until it does
+    let mut keep = chars.len() * settings.cell_width as usize / caption.0.width() as usize;
+    loop {
+        let text: String = chars[..keep].iter().chain(['…'].iter()).collect();
+        let caption = render(&text)?;
+        if caption.0.width() <= settings.cell_width || keep == 0 {
+            return Ok(caption);
+        }
+        keep -= 1;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::api::{ConvertErrorCode, ConvertOptions, ConvertResult};
+    use image::{Rgb, RgbImage};
+
+    const COLORS: [[u8; 3]; 5] = [
+        [255, 0, 0],
+        [0, 255, 0],
+        [0, 0, 255],
+        [255, 255, 0],
+        [0, 255, 255],
+    ];
+
+    /// Lay out five 40x20 images of `COLORS` in 20x20 cells, 2px apart
+    fn sheet(options: ConvertOptions) -> (ConvertResult, tempfile::TempDir) {
+        let dir = tempfile::tempdir().unwrap();
+        let inputs: Vec<_> = COLORS
+            .iter()
+            .enumerate()
+            .map(|(index, color)| {
+                let path = dir.path().join(format!("{}.png", index));
+                RgbImage::from_pixel(40, 20, Rgb(*color))
+                    .save(&path)
+                    .unwrap();
+                path.to_string_lossy().to_string()
+            })
+            .collect();
+        let options = ConvertOptions {
+            output_format: "png".to_string(),
+            sheet_cell_width: Some(20),
+            sheet_cell_height: Some(20),
+            sheet_spacing: Some(2),
+            ..options
+        };
+        let output_dir = dir.path().join("out");
+        let result =
+            crate::converters::contact_sheet(&inputs, output_dir.to_str().unwrap(), &options, None);
+        (result, dir)
+    }
+
+    #[test]
+    fn thumbnails_fill_the_grid_in_order() {
+        let (result, _dir) = sheet(ConvertOptions::default());
+        let output = result.output_path.unwrap();
+        assert!(output.ends_with("contact_sheet.png"));
+        let img = image::open(output).unwrap().to_rgb8();
+        // Three columns (roughly square), two rows
+        assert_eq!(img.dimensions(), (2 + 3 * 22, 2 + 2 * 22));
+        for (index, color) in COLORS.iter().enumerate() {
+            let (column, row) = (index as u32 % 3, index as u32 / 3);
+            let (x, y) = (2 + column * 22, 2 + row * 22);
+            // Shrunk to 20x10 and centered vertically in the cell
+            assert_eq!(img.get_pixel(x + 10, y + 10).0, *color, "image {}", index);
+            assert_eq!(img.get_pixel(x + 10, y + 2).0, [255, 255, 255]);
+        }
+    }
+
+    #[test]
+    fn sheet_options_set_the_columns_and_background() {
+        let (result, _dir) = sheet(ConvertOptions {
+            sheet_columns: Some(5),
+            sheet_background: Some("black".to_string()),
+            ..Default::default()
+        });
+        let img = image::open(result.output_path.unwrap()).unwrap().to_rgb8();
+        assert_eq!(img.dimensions(), (2 + 5 * 22, 2 + 22));
+        assert_eq!(img.get_pixel(0, 0).0, [0, 0, 0]);
+
+        let (result, _dir) = sheet(ConvertOptions {
+            sheet_columns: Some(0),
+            ..Default::default()
+        });
+        assert_eq!(result.error_code, Some(ConvertErrorCode::InvalidOption));
+    }
+}
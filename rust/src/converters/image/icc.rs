@@ -0,0 +1,263 @@
+use super::metadata::ImageMetadata;
+use crate::api::IccProfileMode;
+use crate::converters::ConvertJob;
+use image::DynamicImage;
+use moxcms::{ColorProfile, DataColorSpace, Layout, TransformExecutor, TransformOptions};
+
+/// Whether `format` output embeds `ImageMetadata::icc`
+pub fn embeds_icc(format: &str) -> bool {
+    matches!(
+        format,
+        "jpg" | "jpeg" | "png" | "webp" | "tif" | "tiff" | "pdf"
+    )
+}
+
+/// Apply `icc_profile` to a freshly decoded image: keep its profile in `metadata` for the
+/// encoder, or convert the pixels to sRGB and drop it
+pub fn apply_icc_mode(
+    img: DynamicImage,
+    metadata: &mut ImageMetadata,
+    job: &ConvertJob,
+) -> DynamicImage {
+    let Some(icc) = metadata.icc.take() else {
+        return img;
+    };
+    let mode = job.options.icc_profile.unwrap_or_default();
+    // Grayscale conversion and grayscale profiles (which watermarks or JPEG flattening would
+    // turn into color images) are handled in sRGB
+    let keep = mode == IccProfileMode::Preserve
+        && embeds_icc(job.output_format)
+        && job.options.grayscale != Some(true)
+        && img.color().has_color();
+    if keep {
+        metadata.icc = Some(icc);
+        return img;
+    }
+
+    let mut img = img;
+    match transform_to_srgb(&mut img, &icc) {
+        Ok(()) if mode == IccProfileMode::Preserve && !embeds_icc(job.output_format) => {
+            job.warn(format!(
+                "{} output cannot carry the ICC color profile; the colors were converted to sRGB",
+                job.output_format.to_uppercase()
+            ))
+        }
+        Ok(()) => {}
+        Err(e) => warn_unusable(job, e),
+    }
+    img
+}
+
+/// `img` converted from the color space of the `icc` profile to sRGB
+///
+/// A profile that cannot be applied (damaged, or e.g. CMYK for pixels the decoder already
+/// converted) is reported as a warning and the pixels are left as they are.
+pub fn convert_to_srgb(img: DynamicImage, icc: &[u8], job: &ConvertJob) -> DynamicImage {
+    let mut img = img;
+    if let Err(e) = transform_to_srgb(&mut img, icc) {
+        warn_unusable(job, e);
+    }
+    img
+}
+
+fn warn_unusable(job: &ConvertJob, error: String) {
+    job.warn(format!(
+        "The embedded ICC color profile could not be applied ({}); it was dropped",
+        error
+    ));
+}
+
+fn transform_to_srgb(img: &mut DynamicImage, icc: &[u8]) -> Result<(), String> {
+    let profile = ColorProfile::new_from_slice(icc).map_err(|e| e.to_string())?;
+    let color = img.color().has_color();
+    let expected = if color {
+        DataColorSpace::Rgb
+    } else {
+        DataColorSpace::Gray
+    };
+    if profile.color_space != expected {
+        return Err(format!(
+            "a {:?} profile does not fit {} pixels",
+            profile.color_space,
+            if color { "RGB" } else { "grayscale" }
+        ));
+    }
+    let target = if color {
+        ColorProfile::new_srgb()
+    } else {
+        srgb_gray()
+    };
+
+    let options = TransformOptions::default();
+    let transform_8bit = |layout: Layout| {
+        profile
+            .create_transform_8bit(layout, &target, layout, options)
+            .map_err(|e| e.to_string())
+    };
+    let transform_16bit = |layout: Layout| {
+        profile
+            .create_transform_16bit(layout, &target, layout, options)
+            .map_err(|e| e.to_string())
+    };
+    let transform_f32 = |layout: Layout| {
+        profile
+            .create_transform_f32(layout, &target, layout, options)
+            .map_err(|e| e.to_string())
+    };
+    match img {
+        DynamicImage::ImageLuma8(buffer) => apply(buffer, &*transform_8bit(Layout::Gray)?),
+        DynamicImage::ImageLumaA8(buffer) => apply(buffer, &*transform_8bit(Layout::GrayAlpha)?),
+        DynamicImage::ImageRgb8(buffer) => apply(buffer, &*transform_8bit(Layout::Rgb)?),
+        DynamicImage::ImageRgba8(buffer) => apply(buffer, &*transform_8bit(Layout::Rgba)?),
+        DynamicImage::ImageLuma16(buffer) => apply(buffer, &*transform_16bit(Layout::Gray)?),
+        DynamicImage::ImageLumaA16(buffer) => apply(buffer, &*transform_16bit(Layout::GrayAlpha)?),
+        DynamicImage::ImageRgb16(buffer) => apply(buffer, &*transform_16bit(Layout::Rgb)?),
+        DynamicImage::ImageRgba16(buffer) => apply(buffer, &*transform_16bit(Layout::Rgba)?),
+        DynamicImage::ImageRgb32F(buffer) => {
+            apply(buffer, &*transform_f32(Layout::Rgb)?)?;
+            // Colors outside of sRGB come out below 0 or above 1
+            buffer.iter_mut().for_each(|v| *v = v.clamp(0.0, 1.0));
+            Ok(())
+        }
+        DynamicImage::ImageRgba32F(buffer) => {
+            apply(buffer, &*transform_f32(Layout::Rgba)?)?;
+            buffer.iter_mut().for_each(|v| *v = v.clamp(0.0, 1.0));
+            Ok(())
+        }
+        _ => Err("unsupported pixel format".to_string()),
+    }
+}
+
+/// Run `transform` over interleaved samples in place
+fn apply<V: Copy + Default>(
+    samples: &mut [V],
+    transform: &(dyn TransformExecutor<V> + Send + Sync),
+) -> Result<(), String> {
+    let source = samples.to_vec();
+    transform
+        .transform(&source, samples)
+        .map_err(|e| e.to_string())
+}
+
+/// Grayscale profile with the sRGB tone curve
+fn srgb_gray() -> ColorProfile {
+    let mut gray = ColorProfile::new_gray_with_gamma(2.2);
+    gray.gray_trc = ColorProfile::new_srgb().red_trc;
+    gray
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::api::{ConvertOptions, ConvertResult, IccProfileMode};
+    use crate::converters::image::tiff::{encode_tiff, read_icc_profile};
+    use image::codecs::png::PngEncoder;
+    use image::{ImageDecoder, ImageEncoder, ImageReader, Rgb, RgbImage};
+    use std::path::Path;
+
+    const COLOR: [u8; 3] = [200, 100, 50];
+
+    /// Convert a Display P3 PNG of `COLOR` to `format`, returning the output's ICC profile
+    /// and first pixel
+    fn convert(format: &str, options: ConvertOptions) -> (ConvertResult, Option<Vec<u8>>, [u8; 3]) {
+        let dir = tempfile::tempdir().unwrap();
+        let input = dir.path().join("p3.png");
+        let icc = moxcms::ColorProfile::new_display_p3().encode().unwrap();
+        let mut encoder = PngEncoder::new(std::fs::File::create(&input).unwrap());
+        encoder.set_icc_profile(icc).unwrap();
+        RgbImage::from_pixel(8, 8, Rgb(COLOR))
+            .write_with_encoder(encoder)
+            .unwrap();
+
+        let options = ConvertOptions {
+            output_format: format.to_string(),
+            image_quality: Some(100),
+            ..options
+        };
+        let result = crate::converters::convert_single(
+            input.to_str().unwrap(),
+            dir.path().join("out").to_str().unwrap(),
+            &options,
+        );
+        let path = result.output_path.as_ref().unwrap();
+        let reader = ImageReader::open(path)
+            .unwrap()
+            .with_guessed_format()
+            .unwrap();
+        let mut decoder = reader.into_decoder().unwrap();
+        let icc = if format == "tiff" {
+            read_icc_profile(Path::new(path))
+        } else {
+            decoder.icc_profile().unwrap()
+        };
+        let img = image::DynamicImage::from_decoder(decoder).unwrap();
+        (result, icc, img.to_rgb8().get_pixel(4, 4).0)
+    }
+
+    fn close(a: [u8; 3], b: [u8; 3]) -> bool {
+        a.iter().zip(b).all(|(a, b)| a.abs_diff(b) <= 3)
+    }
+
+    #[test]
+    fn preserve_embeds_the_profile_where_the_format_allows() {
+        for format in ["png", "jpg", "webp", "tiff"] {
+            let (result, icc, pixel) = convert(format, ConvertOptions::default());
+            assert!(icc.is_some(), "{}", format);
+            assert!(close(pixel, COLOR), "{}: {:?}", format, pixel);
+            assert!(result.warnings.is_empty(), "{}", format);
+        }
+
+        let (result, icc, pixel) = convert("bmp", ConvertOptions::default());
+        assert!(icc.is_none() && !close(pixel, COLOR));
+        assert!(result.warnings[0].starts_with("BMP output cannot carry"));
+    }
+
+    #[test]
+    fn tiff_input_keeps_its_profile() {
+        let dir = tempfile::tempdir().unwrap();
+        let input = dir.path().join("p3.tiff");
+        let icc = moxcms::ColorProfile::new_display_p3().encode().unwrap();
+        let img = image::DynamicImage::ImageRgb8(RgbImage::from_pixel(8, 8, Rgb(COLOR)));
+        let tiff = encode_tiff(&img, Some(&icc), Default::default()).unwrap();
+        std::fs::write(&input, tiff).unwrap();
+
+        let options = ConvertOptions {
+            output_format: "png".to_string(),
+            ..Default::default()
+        };
+        let result = crate::converters::convert_single(
+            input.to_str().unwrap(),
+            dir.path().join("out").to_str().unwrap(),
+            &options,
+        );
+        let reader = ImageReader::open(result.output_path.unwrap()).unwrap();
+        let mut decoder = reader
+            .with_guessed_format()
+            .unwrap()
+            .into_decoder()
+            .unwrap();
+        assert_eq!(decoder.icc_profile().unwrap(), Some(icc));
+    }
+
+    #[test]
+    fn convert_to_srgb_drops_the_profile() {
+        let (result, icc, pixel) = convert(
+            "png",
+            ConvertOptions {
+                icc_profile: Some(IccProfileMode::ConvertToSrgb),
+                ..Default::default()
+            },
+        );
+        assert!(icc.is_none() && result.warnings.is_empty());
+        // P3 colors are more saturated than the same values in sRGB
+        assert!(pixel[0] > COLOR[0] && pixel[2] < COLOR[2], "{:?}", pixel);
+
+        let (_, icc, _) = convert(
+            "png",
+            ConvertOptions {
+                grayscale: Some(true),
+                ..Default::default()
+            },
+        );
+        assert!(icc.is_none());
+    }
+}
@@ -0,0 +1,215 @@
+use super::transform::filter_type;
+use crate::api::ConvertOptions;
+use crate::error::ConvertError;
+use image::codecs::ico::{IcoEncoder, IcoFrame};
+use image::imageops::FilterType;
+use image::{DynamicImage, ExtendedColorType, ImageFormat, RgbaImage};
+use std::path::Path;
+
+/// Icon sizes written when `ico_sizes` is not set (those larger than the source are left out)
+pub const DEFAULT_ICO_SIZES: [u32; 7] = [16, 24, 32, 48, 64, 128, 256];
+
+/// Encode a multi-resolution icon, each size scaled down from `img` separately
+pub fn encode_ico(img: &DynamicImage, options: &ConvertOptions) -> Result<Vec<u8>, ConvertError> {
+    let filter = filter_type(options.resize_filter.unwrap_or_default());
+    let icons = icon_sizes(img, options)?
+        .into_iter()
+        .map(|size| square_icon(img, size, filter))
+        .collect::<Vec<_>>();
+    let frames = icons
+        .iter()
+        .map(|icon| {
+            IcoFrame::as_png(
+                icon.as_raw(),
+                icon.width(),
+                icon.height(),
+                ExtendedColorType::Rgba8,
+            )
+        })
+        .collect::<Result<Vec<_>, _>>()
+        .map_err(|e| ConvertError::encode("ico", e))?;
+
+    let mut bytes = Vec::new();
+    IcoEncoder::new(&mut bytes)
+        .encode_images(&frames)
+        .map_err(|e| ConvertError::encode("ico", e))?;
+    Ok(bytes)
+}
+
+fn icon_sizes(img: &DynamicImage, options: &ConvertOptions) -> Result<Vec<u32>, ConvertError> {
+    let Some(sizes) = &options.ico_sizes else {
+        let source = img.width().max(img.height());
+        let sizes: Vec<_> = DEFAULT_ICO_SIZES
+            .into_iter()
+            .filter(|size| *size <= source)
+            .collect();
+        // Tiny sources still get the smallest icon
+        return Ok(if sizes.is_empty() {
+            vec![DEFAULT_ICO_SIZES[0]]
+        } else {
+            sizes
+        });
+    };
+
+    if sizes.is_empty() {
+        return Err(ConvertError::invalid_option(
+            "ico_sizes",
+            "must not be empty",
+        ));
+    }
+    let mut sizes = sizes
+        .iter()
+        .map(|&size| match u32::try_from(size) {
+            Ok(size @ 1..=256) => Ok(size),
+            _ => Err(ConvertError::invalid_option(
+                "ico_sizes",
+                format!("{} (sizes must be 1-256)", size),
+            )),
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+    sizes.sort_unstable();
+    sizes.dedup();
+    Ok(sizes)
+}
+
+/// Scale `img` to fit a `size` x `size` square, centered on transparency
+fn square_icon(img: &DynamicImage, size: u32, filter: FilterType) -> RgbaImage {
+    let scaled = img.resize(size, size, filter).into_rgba8();
+    if scaled.dimensions() == (size, size) {
+        return scaled;
+    }
+    let mut icon = RgbaImage::new(size, size);
+    let x = (size - scaled.width()) / 2;
+    let y = (size - scaled.height()) / 2;
+    image::imageops::overlay(&mut icon, &scaled, x as i64, y as i64);
+    icon
+}
+
+/// Decode the `size` x `size` entry of an icon (the one with the most colors if there are several)
+pub fn decode_icon_size(path: &Path, size: i32) -> Result<DynamicImage, ConvertError> {
+    let data = std::fs::read(path).map_err(|e| ConvertError::io("Failed to read file", e))?;
+    let entries =
+        icon_entries(&data).ok_or_else(|| ConvertError::decode("ico", "Invalid icon directory"))?;
+
+    let Some(entry) = entries
+        .iter()
+        .filter(|entry| i64::from(entry.width) == i64::from(size))
+        .max_by_key(|entry| entry.bit_count)
+    else {
+        let mut available: Vec<_> = entries.iter().map(|entry| entry.width).collect();
+        available.sort_unstable();
+        available.dedup();
+        let available: Vec<_> = available.iter().map(u32::to_string).collect();
+        return Err(ConvertError::invalid_option(
+            "ico_size",
+            format!(
+                "{} is not in the icon (available: {})",
+                size,
+                available.join(", ")
+            ),
+        ));
+    };
+
+    // A single-entry icon around the chosen image, read by the regular ICO decoder
+    let image = &data[entry.range.clone()];
+    let mut single = vec![0, 0, 1, 0, 1, 0];
+    single.extend_from_slice(&entry.header[..12]);
+    single.extend_from_slice(&22u32.to_le_bytes());
+    single.extend_from_slice(image);
+    image::load_from_memory_with_format(&single, ImageFormat::Ico)
+        .map_err(|e| ConvertError::decode("ico", e))
+}
+
+struct IconEntry {
+    width: u32,
+    bit_count: u16,
+    /// Directory entry (16 bytes)
+    header: [u8; 16],
+    range: std::ops::Range<usize>,
+}
+
+/// Parse the icon directory
+fn icon_entries(data: &[u8]) -> Option<Vec<IconEntry>> {
+    if data.get(..4)? != [0, 0, 1, 0] {
+        return None;
+    }
+    let count = u16::from_le_bytes(data.get(4..6)?.try_into().ok()?) as usize;
+    (0..count)
+        .map(|index| {
+            let header: [u8; 16] = data.get(6 + index * 16..22 + index * 16)?.try_into().ok()?;
+            let len = u32::from_le_bytes(header[8..12].try_into().ok()?) as usize;
+            let offset = u32::from_le_bytes(header[12..16].try_into().ok()?) as usize;
+            let range = offset..offset.checked_add(len)?;
+            data.get(range.clone())?;
+            Some(IconEntry {
+                // 0 stands for 256
+                width: if header[0] == 0 {
+                    256
+                } else {
+                    header[0] as u32
+                },
+                bit_count: u16::from_le_bytes([header[6], header[7]]),
+                header,
+                range,
+            })
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn widths(ico: &[u8]) -> Vec<u32> {
+        let entries = icon_entries(ico).unwrap();
+        entries.iter().map(|entry| entry.width).collect()
+    }
+
+    #[test]
+    fn default_sizes_skip_those_larger_than_the_source() {
+        let img = DynamicImage::new_rgba8(100, 50);
+        let ico = encode_ico(&img, &ConvertOptions::default()).unwrap();
+        assert_eq!(widths(&ico), [16, 24, 32, 48, 64]);
+
+        let ico = encode_ico(&DynamicImage::new_rgba8(8, 8), &ConvertOptions::default()).unwrap();
+        assert_eq!(widths(&ico), [16]);
+    }
+
+    #[test]
+    fn ico_sizes_are_sorted_deduplicated_and_validated() {
+        let img = DynamicImage::new_rgba8(64, 64);
+        let options = |sizes: Vec<i32>| ConvertOptions {
+            ico_sizes: Some(sizes),
+            ..Default::default()
+        };
+        let ico = encode_ico(&img, &options(vec![48, 16, 16, 256])).unwrap();
+        assert_eq!(widths(&ico), [16, 48, 256]);
+        assert!(encode_ico(&img, &options(vec![])).is_err());
+        assert!(encode_ico(&img, &options(vec![0])).is_err());
+        assert!(encode_ico(&img, &options(vec![257])).is_err());
+    }
+
+    #[test]
+    fn decode_icon_size_picks_the_requested_entry() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("app.ico");
+        let options = ConvertOptions {
+            ico_sizes: Some(vec![16, 32]),
+            ..Default::default()
+        };
+        let img = RgbaImage::from_pixel(64, 32, image::Rgba([255, 0, 0, 255]));
+        let ico = encode_ico(&DynamicImage::ImageRgba8(img), &options).unwrap();
+        std::fs::write(&path, ico).unwrap();
+
+        let icon = decode_icon_size(&path, 32).unwrap();
+        assert_eq!((icon.width(), icon.height()), (32, 32));
+        // Centered on transparency: the top row is padding
+        let icon = icon.to_rgba8();
+        assert_eq!(icon.get_pixel(16, 0)[3], 0);
+        assert_eq!(icon.get_pixel(16, 16)[3], 255);
+        let Err(error) = decode_icon_size(&path, 48) else {
+            panic!("a 48px entry was found");
+        };
+        assert!(error.to_string().contains("available: 16, 32"), "{}", error);
+    }
+}
@@ -0,0 +1,315 @@
+use crate::api::MetadataMode;
+use crate::error::ConvertError;
+use image::metadata::Orientation;
+use image::{DynamicImage, ImageDecoder, ImageEncoder, ImageReader};
+use regex::Regex;
+use std::path::Path;
+use std::sync::OnceLock;
+
+/// Metadata carried from the input to the output image
+#[derive(Debug, Default)]
+pub struct ImageMetadata {
+    pub icc: Option<Vec<u8>>,
+    /// Raw TIFF structure (without the `Exif\0\0` prefix)
+    pub exif: Option<Vec<u8>>,
+    pub xmp: Option<Vec<u8>>,
+}
+
+/// Decode `path` with its EXIF orientation applied, and read the metadata to keep under `mode`
+pub fn decode_image(
+    path: &Path,
+    input_ext: &str,
+    mode: MetadataMode,
+) -> Result<(DynamicImage, ImageMetadata), ConvertError> {
+    // The format is sniffed from the content, not the extension
+    let mut decoder = ImageReader::open(path)
+        .map_err(|e| ConvertError::io("Failed to read file", e))?
+        .with_guessed_format()
+        .map_err(|e| ConvertError::io("Failed to read file", e))?
+        .into_decoder()
+        .map_err(|e| ConvertError::decode(input_ext, e))?;
+
+    // Broken metadata is not worth failing the conversion over
+    let orientation = decoder.orientation().unwrap_or(Orientation::NoTransforms);
+    let icc = match decoder.icc_profile().ok().flatten() {
+        None if input_ext == "tiff" => super::tiff::read_icc_profile(path),
+        icc => icc,
+    };
+    let mut metadata = ImageMetadata {
+        icc,
+        exif: decoder.exif_metadata().ok().flatten(),
+        xmp: decoder.xmp_metadata().ok().flatten(),
+    };
+
+    let mut img =
+        DynamicImage::from_decoder(decoder).map_err(|e| ConvertError::decode(input_ext, e))?;
+    img.apply_orientation(orientation);
+    if let Some(exif) = &mut metadata.exif {
+        // The pixels are upright now; a kept tag would rotate them a second time
+        let _ = Orientation::remove_from_exif_chunk(exif);
+    }
+
+    match mode {
+        MetadataMode::StripAll => {
+            metadata.exif = None;
+            metadata.xmp = None;
+        }
+        MetadataMode::StripGps => {
+            metadata.exif = metadata.exif.and_then(strip_exif_gps);
+            metadata.xmp = metadata.xmp.map(|xmp| strip_xmp_gps(&xmp));
+        }
+        MetadataMode::Preserve => {}
+    }
+    Ok((img, metadata))
+}
+
+impl ImageMetadata {
+    /// Hand the ICC profile and EXIF to an encoder that embeds them itself
+    pub fn attach(&self, encoder: &mut impl ImageEncoder) {
+        // Encoders without support simply drop them
+        if let Some(icc) = &self.icc {
+            let _ = encoder.set_icc_profile(icc.clone());
+        }
+        if let Some(exif) = &self.exif {
+            let _ = encoder.set_exif_metadata(exif.clone());
+        }
+    }
+
+    /// Insert the XMP packet as an APP1 segment after the JFIF/EXIF/ICC segments
+    pub fn embed_xmp_jpeg(&self, jpeg: &mut Vec<u8>) {
+        const XMP_NAMESPACE: &[u8] = b"http://ns.adobe.com/xap/1.0/\0";
+        let Some(xmp) = &self.xmp else { return };
+        let length = 2 + XMP_NAMESPACE.len() + xmp.len();
+        // Packets over 64 KiB need the extended XMP scheme, which is not supported
+        if length > u16::MAX as usize || !jpeg.starts_with(&[0xFF, 0xD8]) {
+            return;
+        }
+
+        let mut position = 2;
+        while position + 4 <= jpeg.len()
+            && jpeg[position] == 0xFF
+            && (0xE0..=0xEF).contains(&jpeg[position + 1])
+        {
+            let size = u16::from_be_bytes([jpeg[position + 2], jpeg[position + 3]]) as usize;
+            position += 2 + size;
+        }
+        let position = position.min(jpeg.len());
+
+        let mut segment = Vec::with_capacity(2 + length);
+        segment.extend_from_slice(&[0xFF, 0xE1]);
+        segment.extend_from_slice(&(length as u16).to_be_bytes());
+        segment.extend_from_slice(XMP_NAMESPACE);
+        segment.extend_from_slice(xmp);
+        jpeg.splice(position..position, segment);
+    }
+
+    /// Insert the XMP packet as an `iTXt` chunk before the image data
+    pub fn embed_xmp_png(&self, png: &mut Vec<u8>) {
+        let Some(xmp) = &self.xmp else { return };
+
+        // Chunks: length (4), type (4), data, CRC (4)
+        let mut position = 8;
+        while position + 8 <= png.len() && &png[position + 4..position + 8] != b"IDAT" {
+            let size = u32::from_be_bytes([
+                png[position],
+                png[position + 1],
+                png[position + 2],
+                png[position + 3],
+            ]) as usize;
+            position += 12 + size;
+        }
+        if position + 8 > png.len() {
+            return;
+        }
+
+        // Keyword, no compression, empty language tag and translated keyword
+        let mut data = b"XML:com.adobe.xmp\0\0\0\0\0".to_vec();
+        data.extend_from_slice(xmp);
+        let mut chunk = Vec::with_capacity(12 + data.len());
+        chunk.extend_from_slice(&(data.len() as u32).to_be_bytes());
+        chunk.extend_from_slice(b"iTXt");
+        chunk.extend_from_slice(&data);
+        chunk.extend_from_slice(&crc32fast::hash(&chunk[4..]).to_be_bytes());
+        png.splice(position..position, chunk);
+    }
+}
+
+/// Empty the GPS IFD of an EXIF block
+///
+/// Returns `None` (drop all EXIF) if the structure cannot be parsed, since the location
+/// could not be removed reliably.
+fn strip_exif_gps(mut exif: Vec<u8>) -> Option<Vec<u8>> {
+    const GPS_IFD_POINTER: u16 = 0x8825;
+    let tiff = Tiff::new(&exif)?;
+
+    let ifd0 = tiff.u32(&exif, 4)? as usize;
+    let count = tiff.u16(&exif, ifd0)? as usize;
+    let gps_ifd = (0..count).find_map(|i| {
+        let entry = ifd0 + 2 + i * 12;
+        (tiff.u16(&exif, entry)? == GPS_IFD_POINTER).then(|| tiff.u32(&exif, entry + 8))?
+    });
+    let Some(gps_ifd) = gps_ifd.map(|offset| offset as usize) else {
+        return Some(exif);
+    };
+
+    let count = tiff.u16(&exif, gps_ifd)? as usize;
+    let end = gps_ifd + 2 + count * 12 + 4;
+    if end > exif.len() {
+        return None;
+    }
+    for i in 0..count {
+        let entry = gps_ifd + 2 + i * 12;
+        let size = value_size(tiff.u16(&exif, entry + 2)?)? * tiff.u32(&exif, entry + 4)? as usize;
+        // Values over 4 bytes live outside the entry
+        if size > 4 {
+            let offset = tiff.u32(&exif, entry + 8)? as usize;
+            exif.get_mut(offset..offset.checked_add(size)?)?.fill(0);
+        }
+    }
+    // An IFD with no entries and no next IFD
+    exif[gps_ifd..end].fill(0);
+    Some(exif)
+}
+
+/// Size in bytes of one value of a TIFF field type
+fn value_size(field_type: u16) -> Option<usize> {
+    match field_type {
+        1 | 2 | 6 | 7 => Some(1),
+        3 | 8 => Some(2),
+        4 | 9 | 11 => Some(4),
+        5 | 10 | 12 => Some(8),
+        _ => None,
+    }
+}
+
+/// Byte order of a TIFF structure
+struct Tiff {
+    little_endian: bool,
+}
+
+impl Tiff {
+    fn new(data: &[u8]) -> Option<Self> {
+        match data.get(..4)? {
+            [b'I', b'I', 42, 0] => Some(Self {
+                little_endian: true,
+            }),
+            [b'M', b'M', 0, 42] => Some(Self {
+                little_endian: false,
+            }),
+            _ => None,
+        }
+    }
+
+    fn u16(&self, data: &[u8], offset: usize) -> Option<u16> {
+        let bytes = data.get(offset..offset.checked_add(2)?)?.try_into().ok()?;
+        Some(if self.little_endian {
+            u16::from_le_bytes(bytes)
+        } else {
+            u16::from_be_bytes(bytes)
+        })
+    }
+
+    fn u32(&self, data: &[u8], offset: usize) -> Option<u32> {
+        let bytes = data.get(offset..offset.checked_add(4)?)?.try_into().ok()?;
+        Some(if self.little_endian {
+            u32::from_le_bytes(bytes)
+        } else {
+            u32::from_be_bytes(bytes)
+        })
+    }
+}
+
+/// Remove the `exif:GPS*` properties from an XMP packet
+fn strip_xmp_gps(xmp: &[u8]) -> Vec<u8> {
+    static GPS_PROPERTIES: OnceLock<Vec<Regex>> = OnceLock::new();
+    let patterns = GPS_PROPERTIES.get_or_init(|| {
+        [
+            // Attribute form: exif:GPSLatitude="..."
+            r#"\s+exif:GPS\w+\s*=\s*("[^"]*"|'[^']*')"#,
+            // Element forms: <exif:GPSLatitude>...</exif:GPSLatitude>, <exif:GPSLatitude/>
+            r"(?s)<exif:GPS\w+[^>]*/>",
+            r"(?s)<exif:GPS\w+[^>]*>.*?</exif:GPS\w+>",
+        ]
+        .iter()
+        .map(|pattern| Regex::new(pattern).expect("valid regex"))
+        .collect()
+    });
+
+    let mut text = String::from_utf8_lossy(xmp).into_owned();
+    for pattern in patterns {
+        text = pattern.replace_all(&text, "").into_owned();
+    }
+    text.into_bytes()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use image::codecs::jpeg::JpegEncoder;
+
+    /// Little-endian EXIF with orientation 6 (rotate 90°) and a GPS latitude
+    fn exif_with_gps() -> Vec<u8> {
+        let mut exif = b"II*\0".to_vec();
+        exif.extend(8u32.to_le_bytes());
+        // IFD0: orientation, GPS IFD pointer
+        exif.extend(2u16.to_le_bytes());
+        exif.extend([0x12, 0x01, 3, 0, 1, 0, 0, 0, 6, 0, 0, 0]);
+        exif.extend([0x25, 0x88, 4, 0, 1, 0, 0, 0, 38, 0, 0, 0]);
+        exif.extend(0u32.to_le_bytes());
+        // GPS IFD: latitude, three rationals stored after the IFD
+        exif.extend(1u16.to_le_bytes());
+        exif.extend([0x02, 0x00, 5, 0, 3, 0, 0, 0, 56, 0, 0, 0]);
+        exif.extend(0u32.to_le_bytes());
+        exif.extend((1..=6u32).flat_map(u32::to_le_bytes));
+        exif
+    }
+
+    #[test]
+    fn strip_exif_gps_empties_the_gps_ifd() {
+        let exif = exif_with_gps();
+        let stripped = strip_exif_gps(exif.clone()).unwrap();
+        assert_eq!(stripped[..38], exif[..38]);
+        assert!(stripped[38..].iter().all(|&b| b == 0));
+
+        // Unparseable EXIF is dropped rather than kept with the location
+        assert_eq!(strip_exif_gps(b"II*\0\xff\xff\xff\x7f".to_vec()), None);
+    }
+
+    #[test]
+    fn strip_xmp_gps_removes_only_gps_properties() {
+        let xmp = br#"<rdf:Description exif:GPSLatitude="52,31N" tiff:Make="Acme">
+<exif:GPSLongitude>13,24E</exif:GPSLongitude><exif:GPSAltitude/><dc:title>Trip</dc:title>
+</rdf:Description>"#;
+        let stripped = String::from_utf8(strip_xmp_gps(xmp)).unwrap();
+        assert!(!stripped.contains("GPS"), "{}", stripped);
+        assert!(stripped.contains(r#"tiff:Make="Acme""#));
+        assert!(stripped.contains("<dc:title>Trip</dc:title>"));
+    }
+
+    #[test]
+    fn decode_image_applies_the_orientation_and_the_metadata_mode() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("photo.jpg");
+        let mut jpeg = Vec::new();
+        let mut encoder = JpegEncoder::new(&mut jpeg);
+        encoder.set_exif_metadata(exif_with_gps()).unwrap();
+        DynamicImage::new_rgb8(4, 2)
+            .write_with_encoder(encoder)
+            .unwrap();
+        std::fs::write(&path, jpeg).unwrap();
+
+        let (img, metadata) = decode_image(&path, "jpg", MetadataMode::Preserve).unwrap();
+        assert_eq!((img.width(), img.height()), (2, 4));
+        let exif = metadata.exif.unwrap();
+        assert_ne!(
+            Orientation::from_exif_chunk(&exif),
+            Some(Orientation::Rotate90)
+        );
+        assert_eq!(exif[56..], exif_with_gps()[56..]);
+
+        let (_, metadata) = decode_image(&path, "jpg", MetadataMode::StripGps).unwrap();
+        assert!(metadata.exif.unwrap()[56..].iter().all(|&b| b == 0));
+        let (_, metadata) = decode_image(&path, "jpg", MetadataMode::StripAll).unwrap();
+        assert!(metadata.exif.is_none());
+    }
+}
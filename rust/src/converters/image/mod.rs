@@ -0,0 +1,475 @@
+pub mod adjust;
+pub mod animation;
+pub mod avif;
+pub mod contact_sheet;
+pub mod icc;
+pub mod ico;
+pub mod metadata;
+pub mod pdf;
+pub mod png;
+pub mod svg;
+pub mod target_size;
+pub mod tiff;
+pub mod transform;
+pub mod watermark;
+pub mod webp;
+
+use super::{ConvertJob, Converter, ConverterRegistry};
+use crate::error::ConvertError;
+use image::codecs::jpeg::JpegEncoder;
+use image::{DynamicImage, ImageFormat, Rgba};
+use metadata::ImageMetadata;
+use std::io::Cursor;
+use std::path::Path;
+
+/// Largest image the converters create: 256 megapixels (1 GiB as 8-bit RGBA)
+const MAX_PIXELS: u64 = 1 << 28;
+
+/// Image re-encoding (via the `image` crate)
+pub struct ImageConverter;
+
+impl Converter for ImageConverter {
+    fn name(&self) -> &'static str {
+        "image"
+    }
+
+    fn inputs(&self) -> &[&'static str] {
+        &[
+            "png", "jpg", "jpeg", "webp", "bmp", "ico", "svg", "gif", "tif", "tiff", "tga", "qoi",
+            "pnm",
+        ]
+    }
+
+    fn outputs(&self) -> &[&'static str] {
+        IMAGE_OUTPUTS
+    }
+
+    fn convert(&self, job: &ConvertJob) -> Result<(), ConvertError> {
+        convert_image(job)
+    }
+}
+
+/// AVIF input, decoded with FFmpeg (see `avif::decode_avif`) and then encoded like
+/// `ImageConverter` does
+pub struct AvifInputConverter;
+
+impl Converter for AvifInputConverter {
+    fn name(&self) -> &'static str {
+        "avif"
+    }
+
+    fn inputs(&self) -> &[&'static str] {
+        &["avif"]
+    }
+
+    fn outputs(&self) -> &[&'static str] {
+        IMAGE_OUTPUTS
+    }
+
+    fn required_tools(&self) -> &[&'static str] {
+        &["ffmpeg"]
+    }
+
+    fn convert(&self, job: &ConvertJob) -> Result<(), ConvertError> {
+        convert_image(job)
+    }
+}
+
+const IMAGE_OUTPUTS: &[&str] = &[
+    "png", "jpg", "jpeg", "webp", "bmp", "ico", "gif", "avif", "tif", "tiff", "tga", "qoi", "pnm",
+    "pdf",
+];
+
+pub fn register(registry: &mut ConverterRegistry) {
+    registry.register(ImageConverter);
+    registry.register(AvifInputConverter);
+}
+
+/// Convert image
+pub fn convert_image(job: &ConvertJob) -> Result<(), ConvertError> {
+    let options = job.options;
+    let input_ext = job.input_format;
+    let output_ext = job.output_format;
+
+    if output_ext == "pdf" {
+        return pdf::write_pdf(std::slice::from_ref(job), job.output_path);
+    }
+
+    let Some(format) = image_format(output_ext) else {
+        return Err(ConvertError::unsupported_pair(input_ext, output_ext));
+    };
+
+    if matches!(input_ext, "gif" | "webp") {
+        if let Some(animation) = animation::open_animation(job.input_path, input_ext)? {
+            return animation::convert_animation(job, animation, format);
+        }
+    }
+
+    let (img, metadata) = decode_still(job)?;
+    let img = transform::apply_transforms(img, options)?;
+
+    if options.extract_frames == Some(true) {
+        return match animation::claim_frame_path(job, 0, 1)? {
+            Some(path) => write_image(&img, &metadata, format, job, &path),
+            None => Ok(()),
+        };
+    }
+    write_image(&img, &metadata, format, job, job.output_path)
+}
+
+/// Encoder format for an output extension (`None` for PDF and non-image formats)
+fn image_format(output_ext: &str) -> Option<ImageFormat> {
+    let format = match output_ext {
+        "png" => ImageFormat::Png,
+        "jpg" | "jpeg" => ImageFormat::Jpeg,
+        "webp" => ImageFormat::WebP,
+        "bmp" => ImageFormat::Bmp,
+        "ico" => ImageFormat::Ico,
+        "gif" => ImageFormat::Gif,
+        "avif" => ImageFormat::Avif,
+        "tif" | "tiff" => ImageFormat::Tiff,
+        "tga" => ImageFormat::Tga,
+        "qoi" => ImageFormat::Qoi,
+        "pnm" => ImageFormat::Pnm,
+        _ => return None,
+    };
+    Some(format)
+}
+
+/// Decode a still image (`frame` of an animation) and apply the transform options
+pub(crate) fn load_image(job: &ConvertJob) -> Result<(DynamicImage, ImageMetadata), ConvertError> {
+    if matches!(job.input_format, "gif" | "webp") {
+        if let Some(animation) = animation::open_animation(job.input_path, job.input_format)? {
+            let frame = animation.into_frame(job.options.frame.unwrap_or(0))?;
+            let img = transform::apply_transforms(frame, job.options)?;
+            return Ok((img, ImageMetadata::default()));
+        }
+    }
+    let (img, metadata) = decode_still(job)?;
+    Ok((transform::apply_transforms(img, job.options)?, metadata))
+}
+
+/// Decode a non-animated input, handling its ICC profile per `icc_profile`
+fn decode_still(job: &ConvertJob) -> Result<(DynamicImage, ImageMetadata), ConvertError> {
+    let options = job.options;
+    let decoded = match (job.input_format, options.ico_size) {
+        ("svg", _) => (
+            svg::rasterize(job.input_path, options)?,
+            ImageMetadata::default(),
+        ),
+        ("avif", _) => (avif::decode_avif(job)?, ImageMetadata::default()),
+        ("ico", Some(size)) => (
+            ico::decode_icon_size(job.input_path, size)?,
+            ImageMetadata::default(),
+        ),
+        _ => metadata::decode_image(
+            job.input_path,
+            job.input_format,
+            options.metadata.unwrap_or_default(),
+        )?,
+    };
+    if options.frame.is_some_and(|frame| frame != 0) {
+        return Err(ConvertError::invalid_option(
+            "frame",
+            "the input is not animated (only frame 0 exists)",
+        ));
+    }
+    let (img, mut metadata) = decoded;
+    let img = icc::apply_icc_mode(img, &mut metadata, job);
+    Ok((img, metadata))
+}
+
+/// Encode a still image into `path`
+fn write_image(
+    img: &DynamicImage,
+    metadata: &ImageMetadata,
+    format: ImageFormat,
+    job: &ConvertJob,
+    path: &Path,
+) -> Result<(), ConvertError> {
+    let flattened;
+    let img = if !supports_alpha(format) && img.color().has_alpha() {
+        let background = match &job.options.background {
+            Some(value) => parse_color("background", value)?,
+            None => Rgba([255, 255, 255, 255]),
+        };
+        let (image, had_transparency) = flatten_alpha(img, background);
+        if had_transparency {
+            job.warn(format!(
+                "Transparency was removed: {} has no alpha channel, transparent areas were \
+                 filled with {}",
+                job.output_format.to_uppercase(),
+                job.options.background.as_deref().unwrap_or("white")
+            ));
+        }
+        flattened = image;
+        &flattened
+    } else {
+        img
+    };
+
+    // Only PNG and TIFF store more than 8 bits per sample
+    let converted;
+    let img = match img.color().bytes_per_pixel() / img.color().channel_count() {
+        1 => img,
+        2 if format == ImageFormat::Png => img,
+        _ if format == ImageFormat::Tiff => img,
+        _ => {
+            converted = to_8bit(img);
+            &converted
+        }
+    };
+
+    let bytes = match job.options.target_size_bytes {
+        Some(target) if target_size::supports_target_size(format, job.options) => {
+            if target <= 0 {
+                return Err(ConvertError::invalid_option(
+                    "target_size_bytes",
+                    "must be positive",
+                ));
+            }
+            target_size::encode_to_size(img, metadata, format, job, target as u64)?
+        }
+        target => {
+            if target.is_some() {
+                job.warn(
+                    "target_size_bytes only applies to JPEG, lossy WebP and AVIF output; it was \
+                     ignored",
+                );
+            }
+            encode_image(img, metadata, format, job)?
+        }
+    };
+    std::fs::write(path, bytes).map_err(|e| ConvertError::io("Failed to write output file", e))
+}
+
+/// Encode a still image in memory with the job's options
+fn encode_image(
+    img: &DynamicImage,
+    metadata: &ImageMetadata,
+    format: ImageFormat,
+    job: &ConvertJob,
+) -> Result<Vec<u8>, ConvertError> {
+    // Save image with quality settings
+    let quality = job.options.image_quality.unwrap_or(85).clamp(1, 100) as u8;
+
+    let mut bytes = Vec::new();
+    match format {
+        ImageFormat::Jpeg => {
+            // JPEG supports quality setting
+            let mut encoder = JpegEncoder::new_with_quality(&mut bytes, quality);
+            metadata.attach(&mut encoder);
+            img.write_with_encoder(encoder)
+                .map_err(|e| ConvertError::encode("jpeg", e))?;
+            metadata.embed_xmp_jpeg(&mut bytes);
+        }
+        ImageFormat::Png => {
+            let settings = png::PngSettings::from_options(job.options)?;
+            bytes = png::encode_png(img, metadata, &settings)?;
+        }
+        ImageFormat::Ico => bytes = ico::encode_ico(img, job.options)?,
+        ImageFormat::Avif => bytes = avif::encode_avif(img, job)?,
+        ImageFormat::Tiff => {
+            bytes = tiff::encode_tiff(
+                img,
+                metadata.icc.as_deref(),
+                job.options.tiff_compression.unwrap_or_default(),
+            )?
+        }
+        ImageFormat::WebP => {
+            let settings = webp::WebPSettings::from_options(job.options)?;
+            bytes = webp::encode_webp(img, metadata, settings)?;
+        }
+        _ => {
+            // BMP, GIF, TGA, QOI, PNM - no quality setting or metadata (lossless or fixed format)
+            img.write_to(&mut Cursor::new(&mut bytes), format)
+                .map_err(|e| ConvertError::encode(job.output_format, e))?;
+        }
+    }
+    Ok(bytes)
+}
+
+/// Whether `format` output keeps an alpha channel (others are flattened onto `background`)
+///
+/// PNM counts as opaque: the alpha variant (PAM) is not read by most tools expecting PPM.
+fn supports_alpha(format: ImageFormat) -> bool {
+    matches!(
+        format,
+        ImageFormat::Png
+            | ImageFormat::WebP
+            | ImageFormat::Ico
+            | ImageFormat::Gif
+            | ImageFormat::Avif
+            | ImageFormat::Tiff
+            | ImageFormat::Tga
+            | ImageFormat::Qoi
+            | ImageFormat::Bmp
+    )
+}
+
+/// Composite `img` onto an opaque `background` color, dropping the alpha channel
+///
+/// Also returns whether any pixel was actually transparent.
+pub(crate) fn flatten_alpha(img: &DynamicImage, background: Rgba<u8>) -> (DynamicImage, bool) {
+    let mut rgba = img.to_rgba8();
+    if rgba.pixels().all(|pixel| pixel[3] == u8::MAX) {
+        let opaque = if img.color().has_color() {
+            DynamicImage::ImageRgb8(img.to_rgb8())
+        } else {
+            DynamicImage::ImageLuma8(img.to_luma8())
+        };
+        return (opaque, false);
+    }
+
+    for pixel in rgba.pixels_mut() {
+        let alpha = pixel[3] as u32;
+        for c in 0..3 {
+            let blended = pixel[c] as u32 * alpha + background[c] as u32 * (255 - alpha);
+            pixel[c] = ((blended + 127) / 255) as u8;
+        }
+    }
+    (
+        DynamicImage::ImageRgb8(DynamicImage::ImageRgba8(rgba).into_rgb8()),
+        true,
+    )
+}
+
+/// 8-bit copy of a 16-bit or float image, keeping color and alpha channels
+fn to_8bit(img: &DynamicImage) -> DynamicImage {
+    match (img.color().has_color(), img.color().has_alpha()) {
+        (false, false) => DynamicImage::ImageLuma8(img.to_luma8()),
+        (false, true) => DynamicImage::ImageLumaA8(img.to_luma_alpha8()),
+        (true, false) => DynamicImage::ImageRgb8(img.to_rgb8()),
+        (true, true) => DynamicImage::ImageRgba8(img.to_rgba8()),
+    }
+}
+
+/// Check that a `width` x `height` image to be created stays within `MAX_PIXELS`
+///
+/// Allocating a larger one may abort the process instead of failing, so the sizes derived
+/// from `option` are rejected up front.
+pub(crate) fn check_pixel_limit(option: &str, width: u64, height: u64) -> Result<(), ConvertError> {
+    if width.saturating_mul(height) > MAX_PIXELS {
+        return Err(ConvertError::invalid_option(
+            option,
+            format!(
+                "the image would be {}x{} pixels, over the limit of {} megapixels",
+                width,
+                height,
+                MAX_PIXELS >> 20
+            ),
+        ));
+    }
+    Ok(())
+}
+
+/// Parse a CSS color (`#rrggbb`, `#rgb`, `rgb(...)`, `white`, ...) given for `option`
+pub(crate) fn parse_color(option: &str, value: &str) -> Result<Rgba<u8>, ConvertError> {
+    let color: svgtypes::Color = value
+        .trim()
+        .parse()
+        .map_err(|_| ConvertError::invalid_option(option, format!("'{}' is not a color", value)))?;
+    Ok(Rgba([color.red, color.green, color.blue, color.alpha]))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::api::ConvertOptions;
+
+    fn half_transparent() -> DynamicImage {
+        let mut img = image::RgbaImage::from_pixel(2, 1, Rgba([255, 0, 0, 255]));
+        img.put_pixel(1, 0, Rgba([0, 0, 255, 0]));
+        DynamicImage::ImageRgba8(img)
+    }
+
+    #[test]
+    fn flatten_alpha_blends_onto_the_background() {
+        let img = image::RgbaImage::from_pixel(1, 1, Rgba([0, 0, 0, 128]));
+        let (flat, had_transparency) =
+            flatten_alpha(&DynamicImage::ImageRgba8(img), Rgba([255, 255, 255, 255]));
+        assert!(had_transparency);
+        assert_eq!(flat.to_rgb8().get_pixel(0, 0).0, [127, 127, 127]);
+    }
+
+    #[test]
+    fn flatten_alpha_keeps_opaque_images_unchanged() {
+        let img = image::RgbaImage::from_pixel(1, 1, Rgba([9, 8, 7, 255]));
+        let (flat, had_transparency) =
+            flatten_alpha(&DynamicImage::ImageRgba8(img), Rgba([255, 255, 255, 255]));
+        assert!(!had_transparency);
+        assert_eq!(
+            flat,
+            DynamicImage::ImageRgb8(image::RgbImage::from_pixel(1, 1, image::Rgb([9, 8, 7])))
+        );
+    }
+
+    #[test]
+    fn transparency_is_flattened_for_every_opaque_output() {
+        let dir = tempfile::tempdir().unwrap();
+        let input = dir.path().join("in.png");
+        half_transparent().save(&input).unwrap();
+
+        for (format, flattened) in [
+            ("jpg", true),
+            ("pnm", true),
+            ("png", false),
+            ("webp", false),
+        ] {
+            let options = ConvertOptions {
+                output_format: format.to_string(),
+                background: Some("#00ff00".to_string()),
+                ..Default::default()
+            };
+            let result = crate::converters::convert_single(
+                input.to_str().unwrap(),
+                dir.path().join(format).to_str().unwrap(),
+                &options,
+            );
+            assert!(result.success, "{}: {:?}", format, result.error);
+            assert_eq!(!result.warnings.is_empty(), flattened, "{}", format);
+
+            let output = image::open(result.output_path.unwrap()).unwrap();
+            assert_eq!(output.color().has_alpha(), !flattened, "{}", format);
+            let pixel = output.to_rgba8().get_pixel(1, 0).0;
+            match format {
+                "pnm" => assert_eq!(pixel, [0, 255, 0, 255]),
+                // Lossy WebP may change the color of transparent pixels
+                "png" | "webp" => assert_eq!(pixel[3], 0, "{}", format),
+                _ => {}
+            }
+        }
+    }
+
+    #[test]
+    fn added_formats_are_written_and_read_back() {
+        let dir = tempfile::tempdir().unwrap();
+        let input = dir.path().join("in.png");
+        let img =
+            image::RgbImage::from_fn(8, 4, |x, y| image::Rgb([x as u8 * 30, y as u8 * 60, 9]));
+        img.save(&input).unwrap();
+
+        for format in ["tga", "qoi", "pnm", "tiff", "avif"] {
+            let options = ConvertOptions {
+                output_format: format.to_string(),
+                avif_speed: Some(10),
+                ..Default::default()
+            };
+            let result = crate::converters::convert_single(
+                input.to_str().unwrap(),
+                dir.path().join(format).to_str().unwrap(),
+                &options,
+            );
+            assert!(result.success, "{}: {:?}", format, result.error);
+            let output = result.output_path.unwrap();
+            if format == "avif" {
+                // Decoding AVIF needs FFmpeg; check the container brand
+                let data = std::fs::read(output).unwrap();
+                assert_eq!(&data[4..12], b"ftypavif");
+                continue;
+            }
+            let output = image::open(output).unwrap();
+            assert_eq!(output.to_rgb8(), img, "{}", format);
+        }
+    }
+}
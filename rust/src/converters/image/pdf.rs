@@ -0,0 +1,522 @@
+use super::load_image;
+use super::transform::is_identity;
+use crate::api::{ConvertOptions, MetadataMode, PdfPageSize, ResizeFit};
+use crate::converters::ConvertJob;
+use crate::error::ConvertError;
+use image::codecs::jpeg::{JpegDecoder, JpegEncoder};
+use image::metadata::Orientation;
+use image::{DynamicImage, ImageDecoder};
+use lopdf::content::{Content, Operation};
+use lopdf::{dictionary, Document, Object, ObjectId, Stream};
+use std::fs::File;
+use std::io::{BufWriter, Cursor};
+use std::path::Path;
+
+/// PDF points per pixel: images are placed at 96 DPI (PDF units are 1/72 inch)
+const POINTS_PER_PIXEL: f32 = 72.0 / 96.0;
+const POINTS_PER_MM: f32 = 72.0 / 25.4;
+
+/// PDF page layout settings
+struct PdfSettings {
+    page_size: PdfPageSize,
+    /// Margin in points
+    margin: f32,
+    fit: ResizeFit,
+    jpeg_quality: Option<u8>,
+}
+
+impl PdfSettings {
+    fn from_options(options: &ConvertOptions) -> Result<Self, ConvertError> {
+        let margin = match options.pdf_margin_mm {
+            None => 0,
+            Some(margin @ 0..) => margin,
+            Some(margin) => {
+                return Err(ConvertError::invalid_option(
+                    "pdf_margin_mm",
+                    format!("{} (must not be negative)", margin),
+                ))
+            }
+        };
+        let jpeg_quality = match options.pdf_jpeg_quality {
+            None => None,
+            Some(quality @ 1..=100) => Some(quality as u8),
+            Some(quality) => {
+                return Err(ConvertError::invalid_option(
+                    "pdf_jpeg_quality",
+                    format!("{} (must be 1-100)", quality),
+                ))
+            }
+        };
+        Ok(Self {
+            page_size: options.pdf_page_size.unwrap_or_default(),
+            margin: margin as f32 * POINTS_PER_MM,
+            fit: options.pdf_fit.unwrap_or_default(),
+            jpeg_quality,
+        })
+    }
+
+    /// Page size in points for an image of `width` x `height` points
+    fn page_size(&self, width: f32, height: f32) -> (f32, f32) {
+        let (short, long) = match self.page_size {
+            PdfPageSize::Image => return (width + 2.0 * self.margin, height + 2.0 * self.margin),
+            PdfPageSize::A3 => (842.0, 1191.0),
+            PdfPageSize::A4 => (595.0, 842.0),
+            PdfPageSize::A5 => (420.0, 595.0),
+            PdfPageSize::Letter => (612.0, 792.0),
+            PdfPageSize::Legal => (612.0, 1008.0),
+        };
+        if width > height {
+            (long, short)
+        } else {
+            (short, long)
+        }
+    }
+}
+
+/// Write the inputs of `jobs` as the pages of one PDF, in order
+///
+/// Every job shares the options of the first one.
+pub fn write_pdf(jobs: &[ConvertJob], path: &Path) -> Result<(), ConvertError> {
+    let Some(first) = jobs.first() else {
+        return Err(ConvertError::invalid_option(
+            "input_paths",
+            "no images to put into the PDF",
+        ));
+    };
+    let settings = PdfSettings::from_options(first.options)?;
+
+    let mut doc = Document::with_version("1.5");
+    let pages_id = doc.new_object_id();
+    let mut kids = Vec::with_capacity(jobs.len());
+    for (index, job) in jobs.iter().enumerate() {
+        if job.cancel.is_cancelled() {
+            return Err(ConvertError::Cancelled);
+        }
+        job.progress.report(
+            (index * 100 / jobs.len()) as i32,
+            format!("Page {}/{}", index + 1, jobs.len()),
+        );
+        kids.push(add_page(&mut doc, pages_id, job, &settings)?.into());
+    }
+
+    let count = kids.len() as i64;
+    doc.objects.insert(
+        pages_id,
+        Object::Dictionary(dictionary! {
+            "Type" => "Pages",
+            "Kids" => kids,
+            "Count" => count,
+        }),
+    );
+    let catalog_id = doc.add_object(dictionary! {
+        "Type" => "Catalog",
+        "Pages" => pages_id,
+    });
+    doc.trailer.set("Root", catalog_id);
+
+    let file =
+        File::create(path).map_err(|e| ConvertError::io("Failed to create output file", e))?;
+    doc.save_to(&mut BufWriter::new(file))
+        .map_err(|e| ConvertError::encode("pdf", e))
+}
+
+/// Add the image of `job` as a page
+fn add_page(
+    doc: &mut Document,
+    pages_id: ObjectId,
+    job: &ConvertJob,
+    settings: &PdfSettings,
+) -> Result<ObjectId, ConvertError> {
+    let (img, metadata) = load_image(job)?;
+    let (pixel_width, pixel_height) = (img.width(), img.height());
+    let image_id = match original_jpeg(job, settings) {
+        Some(jpeg) => add_jpeg(
+            doc,
+            jpeg,
+            img.color().has_color(),
+            pixel_width,
+            pixel_height,
+        ),
+        None => add_image(doc, &img, metadata.icc, settings)?,
+    };
+
+    let (width, height) = (
+        pixel_width as f32 * POINTS_PER_PIXEL,
+        pixel_height as f32 * POINTS_PER_PIXEL,
+    );
+    let (page_width, page_height) = settings.page_size(width, height);
+    let margin = settings.margin;
+    let (box_width, box_height) = (page_width - 2.0 * margin, page_height - 2.0 * margin);
+    if box_width < 1.0 || box_height < 1.0 {
+        return Err(ConvertError::invalid_option(
+            "pdf_margin_mm",
+            "the margins leave no room for the image",
+        ));
+    }
+
+    let (draw_width, draw_height) = match settings.fit {
+        ResizeFit::Exact => (box_width, box_height),
+        fit => {
+            let (x_scale, y_scale) = (box_width / width, box_height / height);
+            let scale = match fit {
+                ResizeFit::Cover => x_scale.max(y_scale),
+                ResizeFit::MaxDimension => x_scale.min(y_scale).min(1.0),
+                _ => x_scale.min(y_scale),
+            };
+            (width * scale, height * scale)
+        }
+    };
+    let x = margin + (box_width - draw_width) / 2.0;
+    let y = margin + (box_height - draw_height) / 2.0;
+
+    let mut operations = vec![Operation::new("q", vec![])];
+    if settings.fit == ResizeFit::Cover {
+        // Clip the overflow to the area inside the margins
+        operations.push(Operation::new(
+            "re",
+            vec![
+                margin.into(),
+                margin.into(),
+                box_width.into(),
+                box_height.into(),
+            ],
+        ));
+        operations.push(Operation::new("W", vec![]));
+        operations.push(Operation::new("n", vec![]));
+    }
+    operations.extend([
+        Operation::new(
+            "cm",
+            vec![
+                draw_width.into(),
+                0.into(),
+                0.into(),
+                draw_height.into(),
+                x.into(),
+                y.into(),
+            ],
+        ),
+        Operation::new("Do", vec!["Im0".into()]),
+        Operation::new("Q", vec![]),
+    ]);
+    let content = Content { operations }
+        .encode()
+        .map_err(|e| ConvertError::encode("pdf", e))?;
+    let content_id = add_stream(doc, Stream::new(dictionary! {}, content))?;
+
+    Ok(doc.add_object(dictionary! {
+        "Type" => "Page",
+        "Parent" => pages_id,
+        "MediaBox" => vec![0.into(), 0.into(), page_width.into(), page_height.into()],
+        "Contents" => content_id,
+        "Resources" => dictionary! {
+            "XObject" => dictionary! { "Im0" => image_id },
+        },
+    }))
+}
+
+/// Add a decoded image: JPEG-compressed with `pdf_jpeg_quality`, otherwise flate-compressed
+/// samples; transparency goes into a soft mask and an ICC profile into an `ICCBased`
+/// color space
+fn add_image(
+    doc: &mut Document,
+    img: &DynamicImage,
+    icc: Option<Vec<u8>>,
+    settings: &PdfSettings,
+) -> Result<ObjectId, ConvertError> {
+    let color = img.color().has_color();
+    let (width, height) = (img.width(), img.height());
+    let samples = if color {
+        DynamicImage::ImageRgb8(img.to_rgb8())
+    } else {
+        DynamicImage::ImageLuma8(img.to_luma8())
+    };
+
+    let mut dict = image_dictionary(color, width, height);
+    if let Some(icc) = icc {
+        let profile = Stream::new(
+            dictionary! {
+                "N" => if color { 3 } else { 1 },
+                "Alternate" => if color { "DeviceRGB" } else { "DeviceGray" },
+            },
+            icc,
+        );
+        let profile_id = add_stream(doc, profile)?;
+        dict.set(
+            "ColorSpace",
+            vec![Object::Name(b"ICCBased".to_vec()), profile_id.into()],
+        );
+    }
+    if img.color().has_alpha() {
+        let alpha: Vec<u8> = img.to_rgba8().pixels().map(|pixel| pixel[3]).collect();
+        if alpha.iter().any(|&a| a != u8::MAX) {
+            let mask = Stream::new(image_dictionary(false, width, height), alpha);
+            dict.set("SMask", add_stream(doc, mask)?);
+        }
+    }
+
+    let stream = match settings.jpeg_quality {
+        Some(quality) => {
+            let mut jpeg = Vec::new();
+            samples
+                .write_with_encoder(JpegEncoder::new_with_quality(&mut jpeg, quality))
+                .map_err(|e| ConvertError::encode("jpeg", e))?;
+            dict.set("Filter", "DCTDecode");
+            Stream::new(dict, jpeg).with_compression(false)
+        }
+        None => Stream::new(dict, samples.into_bytes()),
+    };
+    add_stream(doc, stream)
+}
+
+/// Add a stream, flate-compressing it first unless it is already encoded (e.g. JPEG)
+///
+/// Compressing every stream as it is added keeps only the compressed data of earlier
+/// pages in memory.
+fn add_stream(doc: &mut Document, mut stream: Stream) -> Result<ObjectId, ConvertError> {
+    stream
+        .compress()
+        .map_err(|e| ConvertError::encode("pdf", e))?;
+    Ok(doc.add_object(stream))
+}
+
+/// Add JPEG data as it is
+fn add_jpeg(doc: &mut Document, jpeg: Vec<u8>, color: bool, width: u32, height: u32) -> ObjectId {
+    let mut dict = image_dictionary(color, width, height);
+    dict.set("Filter", "DCTDecode");
+    doc.add_object(Stream::new(dict, jpeg).with_compression(false))
+}
+
+/// Image XObject dictionary for 8-bit RGB or grayscale samples
+fn image_dictionary(color: bool, width: u32, height: u32) -> lopdf::Dictionary {
+    dictionary! {
+        "Type" => "XObject",
+        "Subtype" => "Image",
+        "Width" => width,
+        "Height" => height,
+        "ColorSpace" => if color { "DeviceRGB" } else { "DeviceGray" },
+        "BitsPerComponent" => 8,
+    }
+}
+
+/// The input file itself when it is a JPEG that can be embedded without re-encoding
+///
+/// That is the case when nothing changes its pixels (transforms, EXIF orientation, an ICC
+/// profile, `pdf_jpeg_quality`) and it is RGB or grayscale. EXIF and XMP are removed unless
+/// `metadata` is `Preserve`.
+fn original_jpeg(job: &ConvertJob, settings: &PdfSettings) -> Option<Vec<u8>> {
+    if !matches!(job.input_format, "jpg" | "jpeg")
+        || settings.jpeg_quality.is_some()
+        || !is_identity(job.options)
+    {
+        return None;
+    }
+    let data = std::fs::read(job.input_path).ok()?;
+    let mut decoder = JpegDecoder::new(Cursor::new(&data)).ok()?;
+    if decoder.orientation().ok()? != Orientation::NoTransforms
+        || decoder.icc_profile().ok()?.is_some()
+    {
+        return None;
+    }
+    let keep_metadata = job.options.metadata == Some(MetadataMode::Preserve);
+    embeddable_jpeg(&data, keep_metadata)
+}
+
+/// `data` without its APP1 (EXIF, XMP) segments unless `keep_metadata`, provided it is a
+/// baseline or progressive JPEG with one or three components (what `DCTDecode` reads)
+fn embeddable_jpeg(data: &[u8], keep_metadata: bool) -> Option<Vec<u8>> {
+    if data.get(..2)? != [0xFF, 0xD8] {
+        return None;
+    }
+    let mut out = data[..2].to_vec();
+    let mut components = None;
+    let mut pos = 2;
+    loop {
+        let &[0xFF, marker] = data.get(pos..pos + 2)? else {
+            return None;
+        };
+        if marker == 0xDA {
+            // Start of scan: entropy-coded data follows, no more header segments
+            out.extend_from_slice(&data[pos..]);
+            return matches!(components, Some(1 | 3)).then_some(out);
+        }
+        let length = u16::from_be_bytes([*data.get(pos + 2)?, *data.get(pos + 3)?]) as usize;
+        let segment = data.get(pos..pos + 2 + length)?;
+        match marker {
+            // Baseline, extended and progressive DCT frames
+            0xC0..=0xC2 => components = Some(*segment.get(9)?),
+            // Lossless or arithmetic-coded frames
+            0xC3 | 0xC5..=0xC7 | 0xC9..=0xCB | 0xCD..=0xCF => return None,
+            _ => {}
+        }
+        if marker != 0xE1 || keep_metadata {
+            out.extend_from_slice(segment);
+        }
+        pos += 2 + length;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use image::{Rgba, RgbaImage};
+
+    /// Convert a 16x8 image with a transparent column to PDF and load the result
+    fn convert_to_pdf(options: ConvertOptions) -> Document {
+        let dir = tempfile::tempdir().unwrap();
+        let input = dir.path().join("page.png");
+        let img = RgbaImage::from_fn(16, 8, |x, y| Rgba([x as u8 * 16, y as u8 * 32, 0, 255]));
+        let mut img = DynamicImage::ImageRgba8(img);
+        img.as_mut_rgba8()
+            .unwrap()
+            .put_pixel(0, 0, Rgba([0, 0, 0, 0]));
+        img.save(&input).unwrap();
+
+        let options = ConvertOptions {
+            output_format: "pdf".to_string(),
+            ..options
+        };
+        let result = crate::converters::convert_single(
+            input.to_str().unwrap(),
+            dir.path().to_str().unwrap(),
+            &options,
+        );
+        assert!(result.success, "{:?}", result.error);
+        Document::load(result.output_path.unwrap()).unwrap()
+    }
+
+    #[test]
+    fn icc_profiles_are_embedded_as_icc_based_color_spaces() {
+        let dir = tempfile::tempdir().unwrap();
+        let input = dir.path().join("page.png");
+        let icc = moxcms::ColorProfile::new_display_p3().encode().unwrap();
+        let mut encoder = image::codecs::png::PngEncoder::new(File::create(&input).unwrap());
+        image::ImageEncoder::set_icc_profile(&mut encoder, icc.clone()).unwrap();
+        let img = image::RgbImage::from_pixel(4, 4, image::Rgb([200, 100, 50]));
+        img.write_with_encoder(encoder).unwrap();
+
+        let options = ConvertOptions {
+            output_format: "pdf".to_string(),
+            ..Default::default()
+        };
+        let result = crate::converters::convert_single(
+            input.to_str().unwrap(),
+            dir.path().to_str().unwrap(),
+            &options,
+        );
+        assert!(result.warnings.is_empty(), "{:?}", result.warnings);
+        let doc = Document::load(result.output_path.unwrap()).unwrap();
+        let color_space = images(&doc)[0]
+            .get(b"ColorSpace")
+            .unwrap()
+            .as_array()
+            .unwrap();
+        assert_eq!(color_space[0].as_name().unwrap(), b"ICCBased");
+        let profile = doc
+            .get_object(color_space[1].as_reference().unwrap())
+            .unwrap();
+        let profile = profile.as_stream().unwrap();
+        assert_eq!(profile.dict.get(b"N").unwrap().as_i64().unwrap(), 3);
+        assert_eq!(profile.get_plain_content().unwrap(), icc);
+    }
+
+    /// Dictionaries of the image XObjects: the page image, then its soft mask
+    fn images(doc: &Document) -> Vec<&lopdf::Dictionary> {
+        let images = doc.objects.values().filter_map(|object| {
+            let stream = object.as_stream().ok()?;
+            let subtype = stream.dict.get(b"Subtype").ok()?.as_name().ok()?;
+            (subtype == b"Image").then_some(&stream.dict)
+        });
+        let mut images: Vec<_> = images.collect();
+        images.sort_by_key(|dict| !dict.has(b"SMask"));
+        images
+    }
+
+    #[test]
+    fn image_streams_are_compressed_as_they_are_added() {
+        let doc = convert_to_pdf(ConvertOptions::default());
+        let dicts = images(&doc);
+        assert_eq!(dicts.len(), 2);
+        for dict in dicts {
+            assert_eq!(
+                dict.get(b"Filter").unwrap().as_name().unwrap(),
+                b"FlateDecode"
+            );
+        }
+
+        let options = ConvertOptions {
+            pdf_jpeg_quality: Some(80),
+            ..Default::default()
+        };
+        let doc = convert_to_pdf(options);
+        let filter = images(&doc)[0].get(b"Filter").unwrap().as_name().unwrap();
+        assert_eq!(filter, b"DCTDecode");
+    }
+
+    #[test]
+    fn pages_follow_the_page_size_and_margins() {
+        let options = ConvertOptions {
+            pdf_page_size: Some(PdfPageSize::A4),
+            pdf_margin_mm: Some(10),
+            ..Default::default()
+        };
+        let doc = convert_to_pdf(options);
+        let page_id = *doc.get_pages().values().next().unwrap();
+        let page = doc.get_dictionary(page_id).unwrap();
+        let media_box: Vec<_> = page
+            .get(b"MediaBox")
+            .unwrap()
+            .as_array()
+            .unwrap()
+            .iter()
+            .map(|v| v.as_float().unwrap())
+            .collect();
+        // Landscape A4 for a landscape image
+        assert_eq!(media_box, [0.0, 0.0, 842.0, 595.0]);
+
+        let options = ConvertOptions {
+            pdf_margin_mm: Some(-1),
+            output_format: "pdf".to_string(),
+            ..Default::default()
+        };
+        assert!(PdfSettings::from_options(&options).is_err());
+    }
+
+    #[test]
+    fn combined_pdfs_have_one_page_per_image_in_order() {
+        let dir = tempfile::tempdir().unwrap();
+        let inputs: Vec<_> = [(40, 20), (10, 30)]
+            .iter()
+            .enumerate()
+            .map(|(index, &(width, height))| {
+                let path = dir.path().join(format!("{}.png", index));
+                image::RgbImage::new(width, height).save(&path).unwrap();
+                path.to_string_lossy().to_string()
+            })
+            .collect();
+        let result = crate::converters::combine_pdf(
+            &inputs,
+            dir.path().join("out").to_str().unwrap(),
+            &ConvertOptions::default(),
+            None,
+        );
+        let output = result.output_path.unwrap();
+        assert!(output.ends_with("0.pdf"));
+
+        let doc = Document::load(output).unwrap();
+        let sizes: Vec<_> = doc
+            .get_pages()
+            .values()
+            .map(|&id| {
+                let page = doc.get_dictionary(id).unwrap();
+                let media_box = page.get(b"MediaBox").unwrap().as_array().unwrap();
+                (
+                    media_box[2].as_float().unwrap(),
+                    media_box[3].as_float().unwrap(),
+                )
+            })
+            .collect();
+        // Each page is the size of its image at 96 DPI
+        assert_eq!(sizes, [(30.0, 15.0), (7.5, 22.5)]);
+    }
+}
@@ -0,0 +1,231 @@
+use super::metadata::ImageMetadata;
+use crate::api::{ConvertOptions, PngFilter};
+use crate::error::ConvertError;
+use color_quant::NeuQuant;
+use image::codecs::png::{CompressionType, FilterType, PngEncoder};
+use image::{DynamicImage, Rgba, RgbaImage};
+use oxipng::{indexset, RowFilter, StripChunks};
+
+/// PNG encoder settings
+pub struct PngSettings {
+    /// zlib level, 0-9
+    level: u8,
+    filter: Option<PngFilter>,
+    /// Palette size for lossy quantization
+    colors: Option<usize>,
+    dither: bool,
+    optimize: bool,
+}
+
+impl PngSettings {
+    pub fn from_options(options: &ConvertOptions) -> Result<Self, ConvertError> {
+        let level = match options.png_compression {
+            None => 6,
+            Some(level @ 0..=9) => level as u8,
+            Some(level) => {
+                return Err(ConvertError::invalid_option(
+                    "png_compression",
+                    format!("{} (must be 0-9)", level),
+                ))
+            }
+        };
+        let colors = match options.png_colors {
+            None => None,
+            Some(colors @ 2..=256) => Some(colors as usize),
+            Some(colors) => {
+                return Err(ConvertError::invalid_option(
+                    "png_colors",
+                    format!("{} (must be 2-256)", colors),
+                ))
+            }
+        };
+        Ok(Self {
+            level,
+            filter: options.png_filter,
+            colors,
+            dither: options.png_dither != Some(false),
+            optimize: options.png_optimize == Some(true),
+        })
+    }
+}
+
+/// Encode a PNG, embedding the metadata
+pub fn encode_png(
+    img: &DynamicImage,
+    metadata: &ImageMetadata,
+    settings: &PngSettings,
+) -> Result<Vec<u8>, ConvertError> {
+    let quantized;
+    let img = match settings.colors {
+        Some(colors) => {
+            quantized =
+                DynamicImage::ImageRgba8(quantize(&img.to_rgba8(), colors, settings.dither));
+            &quantized
+        }
+        None => img,
+    };
+
+    let compression = match settings.level {
+        0 => CompressionType::Uncompressed,
+        level => CompressionType::Level(level),
+    };
+    let filter = match settings.filter.unwrap_or_default() {
+        PngFilter::NoFilter => FilterType::NoFilter,
+        PngFilter::Sub => FilterType::Sub,
+        PngFilter::Up => FilterType::Up,
+        PngFilter::Average => FilterType::Avg,
+        PngFilter::Paeth => FilterType::Paeth,
+        PngFilter::Adaptive => FilterType::Adaptive,
+    };
+    let mut bytes = Vec::new();
+    let mut encoder = PngEncoder::new_with_quality(&mut bytes, compression, filter);
+    metadata.attach(&mut encoder);
+    img.write_with_encoder(encoder)
+        .map_err(|e| ConvertError::encode("png", e))?;
+    metadata.embed_xmp_png(&mut bytes);
+
+    // The quantized image is still written as RGBA; the optimizer turns it into a palette
+    if settings.optimize || settings.colors.is_some() {
+        bytes = optimize(&bytes, settings)?;
+    }
+    Ok(bytes)
+}
+
+/// Losslessly shrink a PNG with oxipng (color type and bit depth reduction, filter and
+/// deflate search), keeping every pixel and metadata chunk
+fn optimize(png: &[u8], settings: &PngSettings) -> Result<Vec<u8>, ConvertError> {
+    let preset = match settings.level {
+        0..=3 => 1,
+        4..=6 => 2,
+        _ => 4,
+    };
+    let mut options = oxipng::Options::from_preset(preset);
+    // The metadata chunks were already filtered by `metadata`
+    options.strip = StripChunks::None;
+    if let Some(filter) = settings.filter {
+        options.filter = indexset! {match filter {
+            PngFilter::NoFilter => RowFilter::None,
+            PngFilter::Sub => RowFilter::Sub,
+            PngFilter::Up => RowFilter::Up,
+            PngFilter::Average => RowFilter::Average,
+            PngFilter::Paeth => RowFilter::Paeth,
+            PngFilter::Adaptive => RowFilter::MinSum,
+        }};
+    }
+    oxipng::optimize_from_memory(png, &options).map_err(|e| ConvertError::encode("png", e))
+}
+
+/// Reduce `img` to a palette of `colors` colors (NeuQuant), with Floyd-Steinberg error
+/// diffusion when `dither` is set
+fn quantize(img: &RgbaImage, colors: usize, dither: bool) -> RgbaImage {
+    let quant = NeuQuant::new(10, colors, img.as_raw());
+    let palette = quant.color_map_rgba();
+    let color = |index: usize| {
+        Rgba([
+            palette[index * 4],
+            palette[index * 4 + 1],
+            palette[index * 4 + 2],
+            palette[index * 4 + 3],
+        ])
+    };
+
+    let (width, height) = img.dimensions();
+    let mut out = RgbaImage::new(width, height);
+    if !dither {
+        for (pixel, target) in img.pixels().zip(out.pixels_mut()) {
+            *target = color(quant.index_of(&pixel.0));
+        }
+        return out;
+    }
+
+    // Error carried into the current and the next row, offset by one for x - 1
+    let mut current = vec![[0f32; 4]; width as usize + 2];
+    let mut next = current.clone();
+    for y in 0..height {
+        for x in 0..width {
+            let pixel = img.get_pixel(x, y);
+            let i = x as usize + 1;
+            let wanted: [f32; 4] =
+                std::array::from_fn(|c| (pixel[c] as f32 + current[i][c]).clamp(0.0, 255.0));
+            let chosen = color(quant.index_of(&wanted.map(|value| value.round() as u8)));
+            for c in 0..4 {
+                let error = wanted[c] - chosen[c] as f32;
+                current[i + 1][c] += error * 7.0 / 16.0;
+                next[i - 1][c] += error * 3.0 / 16.0;
+                next[i][c] += error * 5.0 / 16.0;
+                next[i + 1][c] += error / 16.0;
+            }
+            out.put_pixel(x, y, chosen);
+        }
+        std::mem::swap(&mut current, &mut next);
+        next.fill([0.0; 4]);
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashSet;
+
+    fn gradient() -> DynamicImage {
+        DynamicImage::ImageRgba8(RgbaImage::from_fn(64, 64, |x, y| {
+            Rgba([(x * 4) as u8, (y * 4) as u8, 128, 255])
+        }))
+    }
+
+    fn encode(options: ConvertOptions) -> Vec<u8> {
+        let settings = PngSettings::from_options(&options).unwrap();
+        encode_png(&gradient(), &ImageMetadata::default(), &settings).unwrap()
+    }
+
+    #[test]
+    fn png_colors_writes_a_palette_image() {
+        for dither in [true, false] {
+            let png = encode(ConvertOptions {
+                png_colors: Some(4),
+                png_dither: Some(dither),
+                ..Default::default()
+            });
+            // IHDR color type 3: indexed
+            assert_eq!(png[25], 3);
+            let decoded = image::load_from_memory(&png).unwrap().to_rgba8();
+            let colors: HashSet<_> = decoded.pixels().collect();
+            assert!(colors.len() <= 4, "{} colors", colors.len());
+        }
+    }
+
+    #[test]
+    fn compression_and_optimize_keep_every_pixel() {
+        let level = |level| ConvertOptions {
+            png_compression: Some(level),
+            ..Default::default()
+        };
+        let stored = encode(level(0));
+        let best = encode(level(9));
+        let optimized = encode(ConvertOptions {
+            png_optimize: Some(true),
+            ..level(9)
+        });
+        assert!(best.len() < stored.len());
+        assert!(optimized.len() <= best.len());
+        for png in [stored, best, optimized] {
+            let decoded = image::load_from_memory(&png).unwrap();
+            assert_eq!(decoded.to_rgba8(), gradient().to_rgba8());
+        }
+    }
+
+    #[test]
+    fn out_of_range_settings_are_rejected() {
+        let options = ConvertOptions {
+            png_compression: Some(10),
+            ..Default::default()
+        };
+        assert!(PngSettings::from_options(&options).is_err());
+        let options = ConvertOptions {
+            png_colors: Some(1),
+            ..Default::default()
+        };
+        assert!(PngSettings::from_options(&options).is_err());
+    }
+}
@@ -0,0 +1,275 @@
+use super::{check_pixel_limit, parse_color};
+use crate::api::{ConvertOptions, ResizeFit};
+use crate::error::ConvertError;
+use image::{DynamicImage, Rgba, RgbaImage};
+use resvg::tiny_skia::{Color, Pixmap, Transform};
+use resvg::usvg::{fontdb, FontResolver, Options, Size, Tree};
+use std::path::Path;
+use std::sync::{Arc, OnceLock};
+
+/// DPI at which one SVG user unit is one pixel
+const CSS_DPI: f32 = 96.0;
+
+/// Rasterize an SVG file
+///
+/// Without a crop, the SVG is rendered directly at the size `image_width` / `image_height`
+/// ask for, so resizing does not blur it. Otherwise it is rendered at `svg_dpi` and crop
+/// coordinates refer to that raster.
+pub fn rasterize(path: &Path, options: &ConvertOptions) -> Result<DynamicImage, ConvertError> {
+    let data = std::fs::read(path).map_err(|e| ConvertError::io("Failed to read file", e))?;
+    let dpi = match options.svg_dpi {
+        Some(dpi) if dpi <= 0 => {
+            return Err(ConvertError::invalid_option("svg_dpi", "must be positive"))
+        }
+        Some(dpi) => dpi as f32,
+        None => CSS_DPI,
+    };
+    let background = options
+        .background
+        .as_deref()
+        .map(|value| parse_color("background", value))
+        .transpose()?;
+
+    let svg_options = Options {
+        resources_dir: path.parent().map(Path::to_path_buf),
+        fontdb: font_database(),
+        font_resolver: font_resolver(options.svg_font_family.clone()),
+        ..Options::default()
+    };
+    let tree = Tree::from_data(&data, &svg_options).map_err(|e| ConvertError::decode("svg", e))?;
+
+    let (scale_x, scale_y) = render_scale(tree.size(), dpi / CSS_DPI, options);
+    let width = (tree.size().width() as f64 * scale_x as f64)
+        .round()
+        .max(1.0) as u64;
+    let height = (tree.size().height() as f64 * scale_y as f64)
+        .round()
+        .max(1.0) as u64;
+    let option = match (options.image_width, options.image_height) {
+        _ if scale_x == dpi / CSS_DPI => "svg_dpi",
+        (Some(_), _) => "image_width",
+        _ => "image_height",
+    };
+    check_pixel_limit(option, width, height)?;
+    let (width, height) = (width as u32, height as u32);
+    let mut pixmap = Pixmap::new(width, height).ok_or_else(|| {
+        ConvertError::decode("svg", format!("Cannot render at {}x{}", width, height))
+    })?;
+    if let Some(color) = background {
+        pixmap.fill(Color::from_rgba8(color[0], color[1], color[2], color[3]));
+    }
+    resvg::render(
+        &tree,
+        Transform::from_scale(scale_x, scale_y),
+        &mut pixmap.as_mut(),
+    );
+
+    let img = to_rgba_image(&pixmap)
+        .ok_or_else(|| ConvertError::Internal("Rasterized SVG has an unexpected size".into()))?;
+    Ok(DynamicImage::ImageRgba8(img))
+}
+
+/// Straight-alpha copy of a rendered pixmap
+fn to_rgba_image(pixmap: &Pixmap) -> Option<RgbaImage> {
+    // tiny-skia stores premultiplied alpha
+    let pixels = pixmap
+        .pixels()
+        .iter()
+        .flat_map(|pixel| {
+            let color = pixel.demultiply();
+            [color.red(), color.green(), color.blue(), color.alpha()]
+        })
+        .collect();
+    RgbaImage::from_raw(pixmap.width(), pixmap.height(), pixels)
+}
+
+/// Scale from SVG user units to output pixels
+fn render_scale(size: Size, dpi_scale: f32, options: &ConvertOptions) -> (f32, f32) {
+    if options.crop.is_some() {
+        return (dpi_scale, dpi_scale);
+    }
+    let (mut width, mut height) = (options.image_width, options.image_height);
+    // Resizing happens after rotating
+    if matches!(options.rotate.map(|r| r.rem_euclid(360)), Some(90 | 270)) {
+        std::mem::swap(&mut width, &mut height);
+    }
+    // Invalid sizes are reported by the resize step
+    let ratio_x = width.filter(|w| *w > 0).map(|w| w as f32 / size.width());
+    let ratio_y = height.filter(|h| *h > 0).map(|h| h as f32 / size.height());
+
+    let fit = options.resize_fit.unwrap_or_default();
+    let scale = match (ratio_x, ratio_y) {
+        // Cover and exact fits are cut / stretched from a raster covering the whole box
+        (Some(x), Some(y)) if matches!(fit, ResizeFit::Cover | ResizeFit::Exact) => x.max(y),
+        (Some(x), Some(y)) => x.min(y),
+        (Some(ratio), None) | (None, Some(ratio)) => ratio,
+        (None, None) => dpi_scale,
+    };
+    let scale = match fit {
+        ResizeFit::MaxDimension => scale.min(dpi_scale),
+        _ => scale,
+    };
+    (scale, scale)
+}
+
+/// Render `text` (lines separated by `\n`) with the system fonts, cropped to its glyphs
+///
+/// Also returns the y of the first baseline in the image. `font_family` falls back like
+/// `svg_font_family`. Errors are meant for the user.
+pub(super) fn render_text(
+    text: &str,
+    font_family: Option<&str>,
+    size: f32,
+    color: Rgba<u8>,
+) -> Result<(RgbaImage, f32), String> {
+    let lines: String = text
+        .lines()
+        .enumerate()
+        .map(|(index, line)| {
+            let dy = if index == 0 { "0" } else { "1.2em" };
+            format!(r#"<tspan x="0" dy="{}">{}</tspan>"#, dy, escape_xml(line))
+        })
+        .collect();
+    let svg = format!(
+        r#"<svg xmlns="http://www.w3.org/2000/svg" width="1" height="1"><text font-family="{}" font-size="{}" fill="rgb({},{},{})" fill-opacity="{}" xml:space="preserve">{}</text></svg>"#,
+        escape_xml(font_family.unwrap_or("sans-serif")),
+        size,
+        color[0],
+        color[1],
+        color[2],
+        color[3] as f32 / 255.0,
+        lines
+    );
+    let svg_options = Options {
+        fontdb: font_database(),
+        font_resolver: font_resolver(font_family.map(str::to_string)),
+        ..Options::default()
+    };
+    let tree = Tree::from_str(&svg, &svg_options).map_err(|e| e.to_string())?;
+    if !tree.root().has_children() {
+        return Err("cannot be rendered: no font is installed".to_string());
+    }
+
+    // Glyphs extend above the baseline at y = 0 and past the 1x1 canvas
+    let bounds = tree.root().abs_stroke_bounding_box();
+    let width = bounds.width().ceil().max(1.0) as u32;
+    let height = bounds.height().ceil().max(1.0) as u32;
+    let mut pixmap = Pixmap::new(width, height)
+        .ok_or_else(|| format!("too large to render ({}x{})", width, height))?;
+    resvg::render(
+        &tree,
+        Transform::from_translate(-bounds.x(), -bounds.y()),
+        &mut pixmap.as_mut(),
+    );
+    let image =
+        to_rgba_image(&pixmap).ok_or_else(|| "rendered with an unexpected size".to_string())?;
+    Ok((image, -bounds.y()))
+}
+
+fn escape_xml(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
+
+/// System fonts, loaded once
+fn font_database() -> Arc<fontdb::Database> {
+    static FONTS: OnceLock<Arc<fontdb::Database>> = OnceLock::new();
+    FONTS
+        .get_or_init(|| {
+            let mut db = fontdb::Database::new();
+            db.load_system_fonts();
+            Arc::new(db)
+        })
+        .clone()
+}
+
+/// Font selection that falls back to `fallback_family`, then any sans-serif font, then
+/// any installed font, instead of dropping text whose fonts are not installed
+fn font_resolver(fallback_family: Option<String>) -> FontResolver<'static> {
+    let select_default = FontResolver::default_font_selector();
+    FontResolver {
+        select_font: Box::new(move |font, db| {
+            select_default(font, db).or_else(|| {
+                let mut families = Vec::new();
+                if let Some(family) = &fallback_family {
+                    families.push(fontdb::Family::Name(family));
+                }
+                families.push(fontdb::Family::SansSerif);
+                db.query(&fontdb::Query {
+                    families: &families,
+                    ..Default::default()
+                })
+                .or_else(|| db.faces().next().map(|face| face.id))
+            })
+        }),
+        select_fallback: FontResolver::default_fallback_selector(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Rasterize a 40x20 SVG whose left half is red
+    fn render(options: ConvertOptions) -> Result<RgbaImage, ConvertError> {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("shape.svg");
+        let svg = r#"<svg xmlns="http://www.w3.org/2000/svg" width="40" height="20">
+            <rect width="20" height="20" fill="red"/></svg>"#;
+        std::fs::write(&path, svg).unwrap();
+        Ok(rasterize(&path, &options)?.to_rgba8())
+    }
+
+    #[test]
+    fn the_size_follows_the_dpi_or_the_requested_size() {
+        let img = render(ConvertOptions::default()).unwrap();
+        assert_eq!(img.dimensions(), (40, 20));
+        assert_eq!(img.get_pixel(5, 5).0, [255, 0, 0, 255]);
+        assert_eq!(img.get_pixel(35, 5)[3], 0);
+
+        let img = render(ConvertOptions {
+            svg_dpi: Some(192),
+            ..Default::default()
+        })
+        .unwrap();
+        assert_eq!(img.dimensions(), (80, 40));
+
+        // Rendered at the target size rather than scaled up afterwards
+        let img = render(ConvertOptions {
+            image_height: Some(100),
+            ..Default::default()
+        })
+        .unwrap();
+        assert_eq!(img.dimensions(), (200, 100));
+        let img = render(ConvertOptions {
+            image_width: Some(400),
+            resize_fit: Some(ResizeFit::MaxDimension),
+            ..Default::default()
+        })
+        .unwrap();
+        assert_eq!(img.dimensions(), (40, 20));
+    }
+
+    #[test]
+    fn background_fills_the_canvas_and_bad_dpi_is_rejected() {
+        let img = render(ConvertOptions {
+            background: Some("#0000ff".to_string()),
+            ..Default::default()
+        })
+        .unwrap();
+        assert_eq!(img.get_pixel(35, 5).0, [0, 0, 255, 255]);
+
+        let options = ConvertOptions {
+            svg_dpi: Some(0),
+            ..Default::default()
+        };
+        assert!(render(options).is_err());
+        let options = ConvertOptions {
+            svg_dpi: Some(1_000_000),
+            ..Default::default()
+        };
+        assert!(render(options).is_err());
+    }
+}
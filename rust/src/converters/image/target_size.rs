@@ -0,0 +1,228 @@
+use super::encode_image;
+use super::metadata::ImageMetadata;
+use super::transform::filter_type;
+use crate::api::ConvertOptions;
+use crate::converters::ConvertJob;
+use crate::error::ConvertError;
+use image::{DynamicImage, ImageFormat};
+use std::borrow::Cow;
+
+/// Lowest quality tried before downscaling (`target_size_downscale`)
+const DOWNSCALE_MIN_QUALITY: i32 = 50;
+
+/// Downscaling stops at this size, however large the output still is
+const MIN_DIMENSION: u32 = 16;
+
+/// Whether `target_size_bytes` applies: lossy outputs with a quality setting
+pub fn supports_target_size(format: ImageFormat, options: &ConvertOptions) -> bool {
+    match format {
+        ImageFormat::Jpeg | ImageFormat::Avif => true,
+        ImageFormat::WebP => options.webp_lossless != Some(true),
+        _ => false,
+    }
+}
+
+/// Encode at the highest quality whose output fits in `target` bytes
+///
+/// `image_quality` caps the search. With `target_size_downscale`, the image is shrunk
+/// instead of going below quality 50.
+pub fn encode_to_size(
+    img: &DynamicImage,
+    metadata: &ImageMetadata,
+    format: ImageFormat,
+    job: &ConvertJob,
+    target: u64,
+) -> Result<Vec<u8>, ConvertError> {
+    let options = job.options;
+    let max_quality = options.image_quality.unwrap_or(100).clamp(1, 100);
+    let downscale = options.target_size_downscale == Some(true);
+    let min_quality = if downscale {
+        DOWNSCALE_MIN_QUALITY.min(max_quality)
+    } else {
+        1
+    };
+    let filter = filter_type(options.resize_filter.unwrap_or_default());
+
+    let mut current = Cow::Borrowed(img);
+    loop {
+        let (quality, bytes) = search_quality(
+            &current,
+            metadata,
+            format,
+            job,
+            target,
+            min_quality..=max_quality,
+        )?;
+        let fits = bytes.len() as u64 <= target;
+        let (width, height) = (current.width(), current.height());
+        if fits || !downscale || width.max(height) <= MIN_DIMENSION {
+            if !fits {
+                job.warn(format!(
+                    "target_size_bytes: {} bytes could not be reached, the smallest output is {} \
+                     bytes (quality {})",
+                    target,
+                    bytes.len(),
+                    quality
+                ));
+            }
+            if (width, height) != (img.width(), img.height()) {
+                job.warn(format!(
+                    "Downscaled to {}x{} to fit target_size_bytes",
+                    width, height
+                ));
+            }
+            *job.chosen_quality.lock().unwrap_or_else(|e| e.into_inner()) = Some(quality);
+            return Ok(bytes);
+        }
+
+        // The file size grows roughly with the pixel count
+        let factor = ((target as f64 / bytes.len() as f64).sqrt() * 0.95).min(0.9);
+        let width = ((width as f64 * factor).round() as u32).max(1);
+        let height = ((height as f64 * factor).round() as u32).max(1);
+        job.progress.stage(format!(
+            "Downscaling to {}x{} to fit the target size",
+            width, height
+        ));
+        current = Cow::Owned(current.resize_exact(width, height, filter));
+    }
+}
+
+/// Binary search for the highest quality in `qualities` that fits, returning the output at
+/// the lowest quality if none does
+fn search_quality(
+    img: &DynamicImage,
+    metadata: &ImageMetadata,
+    format: ImageFormat,
+    job: &ConvertJob,
+    target: u64,
+    qualities: std::ops::RangeInclusive<i32>,
+) -> Result<(i32, Vec<u8>), ConvertError> {
+    let encode = |quality: i32| {
+        if job.cancel.is_cancelled() {
+            return Err(ConvertError::Cancelled);
+        }
+        job.progress
+            .stage(format!("Trying quality {} for the target size", quality));
+        let options = ConvertOptions {
+            image_quality: Some(quality),
+            ..job.options.clone()
+        };
+        encode_image(
+            img,
+            metadata,
+            format,
+            &ConvertJob {
+                options: &options,
+                ..*job
+            },
+        )
+    };
+
+    let (min, max) = qualities.into_inner();
+    let mut best = (min, encode(min)?);
+    if best.1.len() as u64 > target {
+        return Ok(best);
+    }
+    let (mut low, mut high) = (min + 1, max);
+    while low <= high {
+        let quality = (low + high) / 2;
+        let bytes = encode(quality)?;
+        if bytes.len() as u64 <= target {
+            best = (quality, bytes);
+            low = quality + 1;
+        } else {
+            high = quality - 1;
+        }
+    }
+    Ok(best)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::api::{ConvertErrorCode, ConvertOptions, ConvertResult};
+    use image::{GenericImageView, RgbImage};
+
+    /// Convert a 128x128 noise image to `options.output_format` (JPEG unless set)
+    fn convert(options: ConvertOptions) -> (ConvertResult, tempfile::TempDir) {
+        let dir = tempfile::tempdir().unwrap();
+        let input = dir.path().join("noise.png");
+        let mut seed = 1u32;
+        let img = RgbImage::from_fn(128, 128, |_, _| {
+            seed = seed.wrapping_mul(1_103_515_245).wrapping_add(12345);
+            image::Rgb([(seed >> 16) as u8, (seed >> 8) as u8, (seed >> 24) as u8])
+        });
+        img.save(&input).unwrap();
+
+        let options = ConvertOptions {
+            output_format: if options.output_format.is_empty() {
+                "jpg".to_string()
+            } else {
+                options.output_format
+            },
+            ..options
+        };
+        let result = crate::converters::convert_single(
+            input.to_str().unwrap(),
+            dir.path().join("out").to_str().unwrap(),
+            &options,
+        );
+        (result, dir)
+    }
+
+    fn output_size(result: &ConvertResult) -> u64 {
+        std::fs::metadata(result.output_path.as_ref().unwrap())
+            .unwrap()
+            .len()
+    }
+
+    #[test]
+    fn picks_a_quality_that_fits() {
+        let (result, _dir) = convert(ConvertOptions {
+            target_size_bytes: Some(20_000),
+            ..Default::default()
+        });
+        assert!(result.success && result.warnings.is_empty(), "{:?}", result);
+        assert!(output_size(&result) <= 20_000);
+        let quality = result.image_quality.unwrap();
+        assert!((2..100).contains(&quality), "quality {}", quality);
+    }
+
+    #[test]
+    fn unreachable_targets_warn_or_downscale() {
+        let (result, _dir) = convert(ConvertOptions {
+            target_size_bytes: Some(1_000),
+            ..Default::default()
+        });
+        assert!(result.success);
+        assert_eq!(result.image_quality, Some(1));
+        assert!(result.warnings[0].contains("could not be reached"));
+
+        let (result, _dir) = convert(ConvertOptions {
+            target_size_bytes: Some(3_000),
+            target_size_downscale: Some(true),
+            ..Default::default()
+        });
+        assert!(output_size(&result) <= 3_000, "{:?}", result.warnings);
+        assert!(result.image_quality.unwrap() >= super::DOWNSCALE_MIN_QUALITY);
+        let output = image::open(result.output_path.as_ref().unwrap()).unwrap();
+        assert!(output.dimensions().0 < 128);
+        assert!(result.warnings[0].starts_with("Downscaled to"));
+    }
+
+    #[test]
+    fn other_outputs_ignore_the_target() {
+        let (result, _dir) = convert(ConvertOptions {
+            output_format: "png".to_string(),
+            target_size_bytes: Some(1_000),
+            ..Default::default()
+        });
+        assert!(result.success && result.image_quality.is_none());
+        assert!(result.warnings[0].contains("was ignored"));
+
+        let (result, _dir) = convert(ConvertOptions {
+            target_size_bytes: Some(0),
+            ..Default::default()
+        });
+        assert_eq!(result.error_code, Some(ConvertErrorCode::InvalidOption));
+    }
+}
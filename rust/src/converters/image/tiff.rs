@@ -0,0 +1,133 @@
+use crate::api::TiffCompression;
+use crate::error::ConvertError;
+use image::DynamicImage;
+use std::borrow::Cow;
+use std::fs::File;
+use std::io::Cursor;
+use std::io::{BufReader, Seek, Write};
+use std::path::Path;
+use tiff::encoder::colortype::{
+    ColorType, Gray16, Gray8, RGB32Float, RGBA32Float, RGB16, RGB8, RGBA16, RGBA8,
+};
+use tiff::encoder::{Compression, DeflateLevel, Predictor, TiffEncoder, TiffKind, TiffValue};
+use tiff::tags::{Tag, Type};
+use tiff::TiffResult;
+
+/// Encode a TIFF, keeping 16-bit and float samples, with an optional ICC profile
+pub fn encode_tiff(
+    img: &DynamicImage,
+    icc: Option<&[u8]>,
+    compression: TiffCompression,
+) -> Result<Vec<u8>, ConvertError> {
+    let compression = match compression {
+        TiffCompression::Uncompressed => Compression::Uncompressed,
+        TiffCompression::Lzw => Compression::Lzw,
+        TiffCompression::Deflate => Compression::Deflate(DeflateLevel::Balanced),
+        TiffCompression::PackBits => Compression::Packbits,
+    };
+    let mut bytes = Cursor::new(Vec::new());
+    let mut encoder = TiffEncoder::new(&mut bytes)
+        .map_err(|e| ConvertError::encode("tiff", e))?
+        .with_compression(compression);
+    // Storing differences to the previous sample makes LZW / Deflate far more effective on
+    // photos (integer samples only)
+    if matches!(compression, Compression::Lzw | Compression::Deflate(_))
+        && !matches!(
+            img,
+            DynamicImage::ImageRgb32F(_) | DynamicImage::ImageRgba32F(_)
+        )
+    {
+        encoder = encoder.with_predictor(Predictor::Horizontal);
+    }
+
+    let (width, height) = (img.width(), img.height());
+    let encoder = &mut encoder;
+    match img {
+        DynamicImage::ImageLuma8(img) => write::<Gray8, _, _>(encoder, width, height, img, icc),
+        DynamicImage::ImageRgb8(img) => write::<RGB8, _, _>(encoder, width, height, img, icc),
+        DynamicImage::ImageRgba8(img) => write::<RGBA8, _, _>(encoder, width, height, img, icc),
+        DynamicImage::ImageLuma16(img) => write::<Gray16, _, _>(encoder, width, height, img, icc),
+        DynamicImage::ImageRgb16(img) => write::<RGB16, _, _>(encoder, width, height, img, icc),
+        DynamicImage::ImageRgba16(img) => write::<RGBA16, _, _>(encoder, width, height, img, icc),
+        DynamicImage::ImageRgb32F(img) => {
+            write::<RGB32Float, _, _>(encoder, width, height, img, icc)
+        }
+        DynamicImage::ImageRgba32F(img) => {
+            write::<RGBA32Float, _, _>(encoder, width, height, img, icc)
+        }
+        // Gray + alpha
+        DynamicImage::ImageLumaA16(_) => {
+            write::<RGBA16, _, _>(encoder, width, height, &img.to_rgba16(), icc)
+        }
+        _ => write::<RGBA8, _, _>(encoder, width, height, &img.to_rgba8(), icc),
+    }
+    .map_err(|e| ConvertError::encode("tiff", e))?;
+    Ok(bytes.into_inner())
+}
+
+/// ICC profile of a TIFF file
+///
+/// The `image` TIFF decoder asks for the tag by a number the `tiff` crate never reports (it
+/// knows the tag by name), so it never finds one.
+pub fn read_icc_profile(path: &Path) -> Option<Vec<u8>> {
+    let file = File::open(path).ok()?;
+    let mut decoder = tiff::decoder::Decoder::new(BufReader::new(file)).ok()?;
+    decoder.get_tag_u8_vec(Tag::IccProfile).ok()
+}
+
+/// Write one image, with the ICC profile tag if there is one
+fn write<C: ColorType, W: Write + Seek, K: TiffKind>(
+    encoder: &mut TiffEncoder<W, K>,
+    width: u32,
+    height: u32,
+    data: &[C::Inner],
+    icc: Option<&[u8]>,
+) -> TiffResult<()>
+where
+    [C::Inner]: TiffValue,
+{
+    let mut image = encoder.new_image::<C>(width, height)?;
+    if let Some(icc) = icc {
+        image.encoder().write_tag(Tag::IccProfile, Undefined(icc))?;
+    }
+    image.write_data(data)
+}
+
+/// Opaque bytes: the ICC profile tag has the UNDEFINED type, not BYTE
+struct Undefined<'a>(&'a [u8]);
+
+impl TiffValue for Undefined<'_> {
+    const BYTE_LEN: u8 = 1;
+    const FIELD_TYPE: Type = Type::UNDEFINED;
+
+    fn count(&self) -> usize {
+        self.0.len()
+    }
+
+    fn data(&self) -> Cow<'_, [u8]> {
+        Cow::Borrowed(self.0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use image::{ImageBuffer, Rgb};
+
+    #[test]
+    fn every_compression_keeps_16_bit_samples() {
+        let img =
+            ImageBuffer::from_fn(16, 8, |x, y| Rgb([x as u16 * 4000, y as u16 * 8000, 65535]));
+        let img = DynamicImage::ImageRgb16(img);
+        for compression in [
+            TiffCompression::Uncompressed,
+            TiffCompression::Lzw,
+            TiffCompression::Deflate,
+            TiffCompression::PackBits,
+        ] {
+            let tiff = encode_tiff(&img, None, compression).unwrap();
+            let decoded = image::load_from_memory(&tiff).unwrap();
+            assert_eq!(decoded, img, "{:?}", compression);
+        }
+    }
+}
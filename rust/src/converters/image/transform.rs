@@ -0,0 +1,213 @@
+use super::adjust::apply_adjustments;
+use super::check_pixel_limit;
+use super::watermark::apply_watermark;
+use crate::api::{ConvertOptions, CropRect, Flip, ResizeFilter, ResizeFit};
+use crate::error::ConvertError;
+use image::imageops::FilterType;
+use image::DynamicImage;
+
+/// Apply the image transform options, in order: crop, rotate, flip, resize, color
+/// adjustments and filters, watermark
+pub fn apply_transforms(
+    img: DynamicImage,
+    options: &ConvertOptions,
+) -> Result<DynamicImage, ConvertError> {
+    let mut img = img;
+    if let Some(rect) = options.crop {
+        img = crop(img, rect)?;
+    }
+    if let Some(degrees) = options.rotate {
+        img = rotate(img, degrees)?;
+    }
+    img = match options.flip {
+        Some(Flip::Horizontal) => img.fliph(),
+        Some(Flip::Vertical) => img.flipv(),
+        Some(Flip::Both) => img.fliph().flipv(),
+        None => img,
+    };
+    if options.image_width.is_some() || options.image_height.is_some() {
+        img = resize(img, options)?;
+    }
+    let img = apply_adjustments(img, options)?;
+    apply_watermark(img, options)
+}
+
+/// Whether `apply_transforms` leaves the image as it is
+pub fn is_identity(options: &ConvertOptions) -> bool {
+    options.crop.is_none()
+        && options.rotate.is_none()
+        && options.flip.is_none()
+        && options.image_width.is_none()
+        && options.image_height.is_none()
+        && options.watermark_text.is_none()
+        && options.watermark_image.is_none()
+        && options.grayscale != Some(true)
+        && options.brightness.is_none()
+        && options.contrast.is_none()
+        && options.hue_rotate.is_none()
+        && options.gamma.is_none()
+        && options.invert != Some(true)
+        && options.blur.is_none()
+        && options.sharpen.is_none()
+}
+
+/// Crop to `rect`, clamped to the image bounds
+fn crop(img: DynamicImage, rect: CropRect) -> Result<DynamicImage, ConvertError> {
+    if rect.x < 0 || rect.y < 0 || rect.width <= 0 || rect.height <= 0 {
+        return Err(ConvertError::invalid_option(
+            "crop",
+            "x/y must not be negative and width/height must be positive",
+        ));
+    }
+    let (x, y) = (rect.x as u32, rect.y as u32);
+    if x >= img.width() || y >= img.height() {
+        return Err(ConvertError::invalid_option(
+            "crop",
+            format!(
+                "({}, {}) is outside the {}x{} image",
+                x,
+                y,
+                img.width(),
+                img.height()
+            ),
+        ));
+    }
+    let width = (rect.width as u32).min(img.width() - x);
+    let height = (rect.height as u32).min(img.height() - y);
+    Ok(img.crop_imm(x, y, width, height))
+}
+
+fn rotate(img: DynamicImage, degrees: i32) -> Result<DynamicImage, ConvertError> {
+    match degrees.rem_euclid(360) {
+        0 => Ok(img),
+        90 => Ok(img.rotate90()),
+        180 => Ok(img.rotate180()),
+        270 => Ok(img.rotate270()),
+        _ => Err(ConvertError::invalid_option(
+            "rotate",
+            format!("{} (must be a multiple of 90)", degrees),
+        )),
+    }
+}
+
+fn resize(img: DynamicImage, options: &ConvertOptions) -> Result<DynamicImage, ConvertError> {
+    let dimension = |value: Option<i32>, name: &str| match value {
+        Some(v) if v <= 0 => Err(ConvertError::invalid_option(name, "must be positive")),
+        v => Ok(v.map(|v| v as u32)),
+    };
+    let width = dimension(options.image_width, "image_width")?;
+    let height = dimension(options.image_height, "image_height")?;
+    let filter = filter_type(options.resize_filter.unwrap_or_default());
+    let (src_width, src_height) = (img.width(), img.height());
+    let fit = options.resize_fit.unwrap_or_default();
+    let (box_width, box_height) = complete_box(width, height, src_width, src_height);
+    let option = if width.is_some() {
+        "image_width"
+    } else {
+        "image_height"
+    };
+    check_pixel_limit(option, box_width as u64, box_height as u64)?;
+
+    let img = match fit {
+        ResizeFit::Exact => img.resize_exact(box_width, box_height, filter),
+        ResizeFit::Cover => img.resize_to_fill(box_width, box_height, filter),
+        ResizeFit::Contain => img.resize(box_width, box_height, filter),
+        ResizeFit::MaxDimension => {
+            if src_width <= box_width && src_height <= box_height {
+                img
+            } else {
+                img.resize(box_width, box_height, filter)
+            }
+        }
+    };
+    Ok(img)
+}
+
+/// Fill in a missing target dimension from the source aspect ratio
+fn complete_box(
+    width: Option<u32>,
+    height: Option<u32>,
+    src_width: u32,
+    src_height: u32,
+) -> (u32, u32) {
+    let scaled = |value: u32, from: u32, to: u32| {
+        ((value as u64 * to as u64 + from as u64 / 2) / from.max(1) as u64)
+            .clamp(1, u32::MAX as u64) as u32
+    };
+    match (width, height) {
+        (Some(w), Some(h)) => (w, h),
+        (Some(w), None) => (w, scaled(w, src_width, src_height)),
+        (None, Some(h)) => (scaled(h, src_height, src_width), h),
+        (None, None) => (src_width, src_height),
+    }
+}
+
+pub(crate) fn filter_type(filter: ResizeFilter) -> FilterType {
+    match filter {
+        ResizeFilter::Nearest => FilterType::Nearest,
+        ResizeFilter::Triangle => FilterType::Triangle,
+        ResizeFilter::CatmullRom => FilterType::CatmullRom,
+        ResizeFilter::Gaussian => FilterType::Gaussian,
+        ResizeFilter::Lanczos3 => FilterType::Lanczos3,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Size of a 200x100 image resized with `fit` to `width` x `height`
+    fn resized(fit: ResizeFit, width: Option<i32>, height: Option<i32>) -> (u32, u32) {
+        let options = ConvertOptions {
+            image_width: width,
+            image_height: height,
+            resize_fit: Some(fit),
+            ..Default::default()
+        };
+        let img = DynamicImage::new_rgb8(200, 100);
+        let img = apply_transforms(img, &options).unwrap();
+        (img.width(), img.height())
+    }
+
+    #[test]
+    fn resize_fits_follow_the_box() {
+        assert_eq!(resized(ResizeFit::Contain, Some(50), Some(50)), (50, 25));
+        assert_eq!(resized(ResizeFit::Cover, Some(50), Some(50)), (50, 50));
+        assert_eq!(resized(ResizeFit::Exact, Some(50), Some(50)), (50, 50));
+        assert_eq!(
+            resized(ResizeFit::MaxDimension, Some(50), Some(50)),
+            (50, 25)
+        );
+        assert_eq!(
+            resized(ResizeFit::MaxDimension, Some(400), Some(400)),
+            (200, 100)
+        );
+    }
+
+    #[test]
+    fn a_single_side_keeps_the_aspect_ratio_for_every_fit() {
+        for fit in [
+            ResizeFit::Contain,
+            ResizeFit::Cover,
+            ResizeFit::Exact,
+            ResizeFit::MaxDimension,
+        ] {
+            assert_eq!(resized(fit, Some(100), None), (100, 50), "{:?}", fit);
+            assert_eq!(resized(fit, None, Some(20)), (40, 20), "{:?}", fit);
+        }
+    }
+
+    #[test]
+    fn invalid_transforms_are_rejected() {
+        let options = ConvertOptions {
+            image_width: Some(0),
+            ..Default::default()
+        };
+        assert!(apply_transforms(DynamicImage::new_rgb8(2, 2), &options).is_err());
+        let options = ConvertOptions {
+            rotate: Some(45),
+            ..Default::default()
+        };
+        assert!(apply_transforms(DynamicImage::new_rgb8(2, 2), &options).is_err());
+    }
+}
@@ -0,0 +1,275 @@
+use super::parse_color;
+use super::svg::render_text;
+use super::transform::filter_type;
+use crate::api::{ConvertOptions, WatermarkPosition};
+use crate::error::ConvertError;
+use image::{imageops, DynamicImage, ImageReader, Rgba, RgbaImage};
+
+/// Stamp `watermark_text` or `watermark_image` onto `img`
+pub fn apply_watermark(
+    img: DynamicImage,
+    options: &ConvertOptions,
+) -> Result<DynamicImage, ConvertError> {
+    let short_side = img.width().min(img.height());
+    let mut overlay = match (&options.watermark_text, &options.watermark_image) {
+        (None, None) => return Ok(img),
+        (Some(_), Some(_)) => {
+            return Err(ConvertError::invalid_option(
+                "watermark_image",
+                "cannot be combined with watermark_text",
+            ))
+        }
+        (Some(text), None) => render_watermark_text(text, options, short_side)?,
+        (None, Some(path)) => load_overlay(path, options)?,
+    };
+    let opacity = match options.watermark_opacity {
+        None => 50,
+        Some(opacity @ 0..=100) => opacity as u32,
+        Some(opacity) => {
+            return Err(ConvertError::invalid_option(
+                "watermark_opacity",
+                format!("{} (must be 0-100)", opacity),
+            ))
+        }
+    };
+    let margin = match options.watermark_margin {
+        None => (short_side / 50) as i64,
+        Some(margin @ 0..) => margin as i64,
+        Some(margin) => {
+            return Err(ConvertError::invalid_option(
+                "watermark_margin",
+                format!("{} (must not be negative)", margin),
+            ))
+        }
+    };
+    let tile = options.watermark_tile == Some(true);
+    let (width, height) = (img.width() as i64, img.height() as i64);
+
+    // A single watermark larger than the image is shrunk to fit inside the margins
+    let (max_width, max_height) = ((width - 2 * margin).max(1), (height - 2 * margin).max(1));
+    if !tile && (overlay.width() as i64 > max_width || overlay.height() as i64 > max_height) {
+        overlay = DynamicImage::ImageRgba8(overlay)
+            .resize(
+                max_width as u32,
+                max_height as u32,
+                filter_type(options.resize_filter.unwrap_or_default()),
+            )
+            .into_rgba8();
+    }
+    for pixel in overlay.pixels_mut() {
+        pixel[3] = ((pixel[3] as u32 * opacity + 50) / 100) as u8;
+    }
+
+    // Grayscale images become color so that colored watermarks keep their color
+    let mut img = match (img.color().has_color(), img.color().has_alpha()) {
+        (true, _) => img,
+        (false, false) => DynamicImage::ImageRgb8(img.to_rgb8()),
+        (false, true) => DynamicImage::ImageRgba8(img.to_rgba8()),
+    };
+    let (overlay_width, overlay_height) = (overlay.width() as i64, overlay.height() as i64);
+    if tile {
+        // Every other row is shifted by half a tile, like bricks
+        let (step_x, step_y) = (overlay_width + margin, overlay_height + margin);
+        for (row, y) in (margin..height).step_by(step_y as usize).enumerate() {
+            let shift = if row % 2 == 1 { step_x / 2 } else { 0 };
+            for x in (margin - shift..width).step_by(step_x as usize) {
+                imageops::overlay(&mut img, &overlay, x, y);
+            }
+        }
+    } else {
+        let (x, y) = anchor(
+            options.watermark_position.unwrap_or_default(),
+            (width, height),
+            (overlay_width, overlay_height),
+            margin,
+        );
+        imageops::overlay(&mut img, &overlay, x, y);
+    }
+    Ok(img)
+}
+
+/// Top-left corner of a `size` watermark at `position` in an `image` sized area
+fn anchor(
+    position: WatermarkPosition,
+    image: (i64, i64),
+    size: (i64, i64),
+    margin: i64,
+) -> (i64, i64) {
+    use WatermarkPosition::*;
+    let x = match position {
+        TopLeft | Left | BottomLeft => margin,
+        Top | Center | Bottom => (image.0 - size.0) / 2,
+        TopRight | Right | BottomRight => image.0 - size.0 - margin,
+    };
+    let y = match position {
+        TopLeft | Top | TopRight => margin,
+        Left | Center | Right => (image.1 - size.1) / 2,
+        BottomLeft | Bottom | BottomRight => image.1 - size.1 - margin,
+    };
+    (x, y)
+}
+
+/// Render the watermark text, cropped to its glyphs
+fn render_watermark_text(
+    text: &str,
+    options: &ConvertOptions,
+    short_side: u32,
+) -> Result<RgbaImage, ConvertError> {
+    if text.trim().is_empty() {
+        return Err(ConvertError::invalid_option(
+            "watermark_text",
+            "must not be empty",
+        ));
+    }
+    let size = match options.watermark_font_size {
+        None => (short_side / 20).max(12),
+        Some(size) if size <= 0 => {
+            return Err(ConvertError::invalid_option(
+                "watermark_font_size",
+                "must be positive",
+            ))
+        }
+        Some(size) => size as u32,
+    };
+    let color = match &options.watermark_color {
+        Some(value) => parse_color("watermark_color", value)?,
+        None => Rgba([255, 255, 255, 255]),
+    };
+
+    render_text(
+        text,
+        options.watermark_font_family.as_deref(),
+        size as f32,
+        color,
+    )
+    .map(|(image, _)| image)
+    .map_err(|e| ConvertError::invalid_option("watermark_text", e))
+}
+
+/// Decode the watermark image, scaled to `watermark_image_width`
+fn load_overlay(path: &str, options: &ConvertOptions) -> Result<RgbaImage, ConvertError> {
+    let overlay = ImageReader::open(path)
+        .and_then(|reader| reader.with_guessed_format())
+        .map_err(|e| e.to_string())
+        .and_then(|reader| reader.decode().map_err(|e| e.to_string()))
+        .map_err(|e| {
+            ConvertError::invalid_option(
+                "watermark_image",
+                format!("cannot read '{}': {}", path, e),
+            )
+        })?;
+    match options.watermark_image_width {
+        None => Ok(overlay.into_rgba8()),
+        Some(width) if width <= 0 => Err(ConvertError::invalid_option(
+            "watermark_image_width",
+            "must be positive",
+        )),
+        Some(width) => {
+            let width = width as u32;
+            let height = ((overlay.height() as u64 * width as u64 + overlay.width() as u64 / 2)
+                / overlay.width().max(1) as u64)
+                .max(1) as u32;
+            let filter = filter_type(options.resize_filter.unwrap_or_default());
+            Ok(overlay.resize_exact(width, height, filter).into_rgba8())
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::api::ConvertErrorCode;
+
+    /// A 20x20 black image stamped with a 4x4 red square through `options`
+    fn stamp(options: ConvertOptions) -> Result<RgbaImage, ConvertError> {
+        let dir = tempfile::tempdir().unwrap();
+        let logo = dir.path().join("logo.png");
+        RgbaImage::from_pixel(4, 4, Rgba([255, 0, 0, 255]))
+            .save(&logo)
+            .unwrap();
+        let options = ConvertOptions {
+            watermark_image: Some(logo.to_string_lossy().to_string()),
+            watermark_margin: options.watermark_margin.or(Some(2)),
+            ..options
+        };
+        let img = DynamicImage::new_luma8(20, 20);
+        Ok(apply_watermark(img, &options)?.to_rgba8())
+    }
+
+    #[test]
+    fn image_watermarks_are_placed_with_the_opacity() {
+        let img = stamp(ConvertOptions {
+            watermark_opacity: Some(100),
+            ..Default::default()
+        })
+        .unwrap();
+        // Bottom right inside the 2px margin, in color on a grayscale image
+        assert_eq!(img.get_pixel(14, 14).0, [255, 0, 0, 255]);
+        assert_eq!(img.get_pixel(17, 17).0, [255, 0, 0, 255]);
+        assert_eq!(img.get_pixel(18, 18).0, [0, 0, 0, 255]);
+        assert_eq!(img.get_pixel(13, 13).0, [0, 0, 0, 255]);
+
+        let img = stamp(ConvertOptions {
+            watermark_position: Some(WatermarkPosition::TopLeft),
+            ..Default::default()
+        })
+        .unwrap();
+        assert_eq!(img.get_pixel(2, 2).0, [128, 0, 0, 255]);
+        assert_eq!(img.get_pixel(17, 17).0, [0, 0, 0, 255]);
+    }
+
+    #[test]
+    fn watermarks_are_shrunk_or_tiled() {
+        let img = stamp(ConvertOptions {
+            watermark_image_width: Some(40),
+            watermark_opacity: Some(100),
+            ..Default::default()
+        })
+        .unwrap();
+        // Scaled down to the 16x16 area inside the margins
+        assert_eq!(img.get_pixel(2, 2).0, [255, 0, 0, 255]);
+        assert_eq!(img.get_pixel(1, 1).0, [0, 0, 0, 255]);
+
+        let img = stamp(ConvertOptions {
+            watermark_tile: Some(true),
+            watermark_opacity: Some(100),
+            ..Default::default()
+        })
+        .unwrap();
+        let red = img.pixels().filter(|pixel| pixel[0] == 255).count();
+        assert!(red >= 4 * 16, "{} red pixels", red);
+    }
+
+    #[test]
+    fn invalid_watermark_options_are_rejected() {
+        let invalid = [
+            ConvertOptions {
+                watermark_text: Some("(c)".to_string()),
+                ..Default::default()
+            },
+            ConvertOptions {
+                watermark_opacity: Some(101),
+                ..Default::default()
+            },
+            ConvertOptions {
+                watermark_margin: Some(-1),
+                ..Default::default()
+            },
+            ConvertOptions {
+                watermark_image_width: Some(0),
+                ..Default::default()
+            },
+        ];
+        for options in invalid {
+            let error = stamp(options).unwrap_err();
+            assert_eq!(error.code(), ConvertErrorCode::InvalidOption, "{}", error);
+        }
+
+        let options = ConvertOptions {
+            watermark_image: Some("/nonexistent/logo.png".to_string()),
+            ..Default::default()
+        };
+        let error = apply_watermark(DynamicImage::new_rgb8(8, 8), &options).unwrap_err();
+        assert_eq!(error.code(), ConvertErrorCode::InvalidOption);
+    }
+}
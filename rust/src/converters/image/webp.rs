@@ -0,0 +1,224 @@
+use super::metadata::ImageMetadata;
+use crate::api::ConvertOptions;
+use crate::error::ConvertError;
+use ::webp::{Encoder, WebPConfig};
+use image::DynamicImage;
+
+/// WebP encoder settings
+#[derive(Clone, Copy)]
+pub struct WebPSettings {
+    lossless: bool,
+    /// 0-100; the compression effort for lossless encoding
+    quality: f32,
+    alpha_quality: i32,
+}
+
+impl WebPSettings {
+    pub fn from_options(options: &ConvertOptions) -> Result<Self, ConvertError> {
+        let alpha_quality = options.webp_alpha_quality.unwrap_or(100);
+        if !(0..=100).contains(&alpha_quality) {
+            return Err(ConvertError::invalid_option(
+                "webp_alpha_quality",
+                format!("{} (must be 0-100)", alpha_quality),
+            ));
+        }
+        let lossless = options.webp_lossless == Some(true);
+        let quality = if lossless {
+            // libwebp's default effort; `image_quality` does not apply to lossless output
+            75
+        } else {
+            options.image_quality.unwrap_or(85).clamp(1, 100)
+        };
+        Ok(Self {
+            lossless,
+            quality: quality as f32,
+            alpha_quality,
+        })
+    }
+}
+
+/// Encode a still WebP, embedding the metadata
+pub fn encode_webp(
+    img: &DynamicImage,
+    metadata: &ImageMetadata,
+    settings: WebPSettings,
+) -> Result<Vec<u8>, ConvertError> {
+    let has_alpha = img.color().has_alpha();
+    let image = encode_image_chunks(img, settings)?;
+    let (icc, exif, xmp) = (&metadata.icc, &metadata.exif, &metadata.xmp);
+
+    // The simple format has no room for metadata or a separate alpha channel
+    if icc.is_none() && exif.is_none() && xmp.is_none() && !has_alpha {
+        return Ok(riff(&image));
+    }
+    let flags = [
+        (icc.is_some(), 0b0010_0000),
+        (has_alpha, 0b0001_0000),
+        (exif.is_some(), 0b0000_1000),
+        (xmp.is_some(), 0b0000_0100),
+    ]
+    .iter()
+    .filter(|(set, _)| *set)
+    .fold(0, |flags, (_, flag)| flags | flag);
+
+    // Chunk order required by the container format: VP8X, ICCP, image, EXIF, XMP
+    let mut chunks = Vec::new();
+    write_chunk(
+        &mut chunks,
+        b"VP8X",
+        &vp8x(flags, img.width(), img.height()),
+    );
+    if let Some(icc) = icc {
+        write_chunk(&mut chunks, b"ICCP", icc);
+    }
+    chunks.extend_from_slice(&image);
+    if let Some(exif) = exif {
+        write_chunk(&mut chunks, b"EXIF", exif);
+    }
+    if let Some(xmp) = xmp {
+        write_chunk(&mut chunks, b"XMP ", xmp);
+    }
+    Ok(riff(&chunks))
+}
+
+/// Encode `img` into WebP image chunks (`ALPH` + `VP8 `, or `VP8L`), without the container
+pub fn encode_image_chunks(
+    img: &DynamicImage,
+    settings: WebPSettings,
+) -> Result<Vec<u8>, ConvertError> {
+    let mut config =
+        WebPConfig::new().map_err(|()| ConvertError::encode("webp", "Invalid encoder config"))?;
+    config.lossless = settings.lossless as i32;
+    config.quality = settings.quality;
+    config.alpha_quality = settings.alpha_quality;
+
+    let encoded = if img.color().has_alpha() {
+        let rgba = img.to_rgba8();
+        Encoder::from_rgba(rgba.as_raw(), img.width(), img.height()).encode_advanced(&config)
+    } else {
+        let rgb = img.to_rgb8();
+        Encoder::from_rgb(rgb.as_raw(), img.width(), img.height()).encode_advanced(&config)
+    }
+    .map_err(|e| ConvertError::encode("webp", format!("{:?}", e)))?;
+
+    // libwebp writes a complete file; keep only the image chunks
+    let mut chunks = Vec::new();
+    let mut position = 12;
+    while position + 8 <= encoded.len() {
+        let id = &encoded[position..position + 4];
+        let size = u32::from_le_bytes([
+            encoded[position + 4],
+            encoded[position + 5],
+            encoded[position + 6],
+            encoded[position + 7],
+        ]) as usize;
+        let end = (position + 8 + size + size % 2).min(encoded.len());
+        if matches!(id, b"ALPH" | b"VP8 " | b"VP8L") {
+            chunks.extend_from_slice(&encoded[position..end]);
+        }
+        position = end;
+    }
+    Ok(chunks)
+}
+
+/// `VP8X` chunk payload: feature flags and canvas size
+pub fn vp8x(flags: u8, width: u32, height: u32) -> Vec<u8> {
+    let mut data = vec![flags, 0, 0, 0];
+    data.extend_from_slice(&u24(width - 1));
+    data.extend_from_slice(&u24(height - 1));
+    data
+}
+
+/// Wrap chunks into a `RIFF ... WEBP` file
+pub fn riff(chunks: &[u8]) -> Vec<u8> {
+    let mut bytes = Vec::with_capacity(12 + chunks.len());
+    bytes.extend_from_slice(b"RIFF");
+    bytes.extend_from_slice(&(4 + chunks.len() as u32).to_le_bytes());
+    bytes.extend_from_slice(b"WEBP");
+    bytes.extend_from_slice(chunks);
+    bytes
+}
+
+/// Append a RIFF chunk (padded to an even size)
+pub fn write_chunk(out: &mut Vec<u8>, id: &[u8; 4], data: &[u8]) {
+    out.extend_from_slice(id);
+    out.extend_from_slice(&(data.len() as u32).to_le_bytes());
+    out.extend_from_slice(data);
+    if data.len() % 2 == 1 {
+        out.push(0);
+    }
+}
+
+pub fn u24(value: u32) -> [u8; 3] {
+    let [a, b, c, _] = value.to_le_bytes();
+    [a, b, c]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use image::{ImageDecoder, RgbaImage};
+
+    fn noise(alpha: u8) -> DynamicImage {
+        DynamicImage::ImageRgba8(RgbaImage::from_fn(64, 64, |x, y| {
+            let v = (x * 31 + y * 17) ^ (x * y);
+            image::Rgba([v as u8, (v >> 3) as u8, ((x + y) * 4) as u8, alpha])
+        }))
+    }
+
+    fn encode(img: &DynamicImage, metadata: &ImageMetadata, options: ConvertOptions) -> Vec<u8> {
+        let settings = WebPSettings::from_options(&options).unwrap();
+        encode_webp(img, metadata, settings).unwrap()
+    }
+
+    fn has_chunk(webp: &[u8], id: &[u8; 4]) -> bool {
+        webp.windows(4).any(|window| window == id)
+    }
+
+    #[test]
+    fn image_quality_sets_the_lossy_quality() {
+        let img = DynamicImage::ImageRgb8(noise(255).to_rgb8());
+        let quality = |quality| ConvertOptions {
+            image_quality: Some(quality),
+            ..Default::default()
+        };
+        let low = encode(&img, &ImageMetadata::default(), quality(10));
+        let high = encode(&img, &ImageMetadata::default(), quality(95));
+        assert!(low.len() < high.len(), "{} >= {}", low.len(), high.len());
+        assert!(has_chunk(&low, b"VP8 "));
+    }
+
+    #[test]
+    fn lossless_output_keeps_every_pixel() {
+        let img = noise(128);
+        let options = ConvertOptions {
+            webp_lossless: Some(true),
+            ..Default::default()
+        };
+        let webp = encode(&img, &ImageMetadata::default(), options);
+        assert!(has_chunk(&webp, b"VP8L"));
+        let decoded = image::load_from_memory(&webp).unwrap();
+        assert_eq!(decoded.to_rgba8(), img.to_rgba8());
+    }
+
+    #[test]
+    fn alpha_and_metadata_use_the_extended_format() {
+        let icc = moxcms::ColorProfile::new_display_p3().encode().unwrap();
+        let metadata = ImageMetadata {
+            icc: Some(icc.clone()),
+            ..Default::default()
+        };
+        let webp = encode(&noise(128), &metadata, ConvertOptions::default());
+        assert!(has_chunk(&webp, b"VP8X") && has_chunk(&webp, b"ALPH"));
+        let mut decoder =
+            image::codecs::webp::WebPDecoder::new(std::io::Cursor::new(&webp)).unwrap();
+        assert_eq!(decoder.icc_profile().unwrap(), Some(icc));
+        assert!(decoder.color_type().has_alpha());
+
+        let options = ConvertOptions {
+            webp_alpha_quality: Some(101),
+            ..Default::default()
+        };
+        assert!(WebPSettings::from_options(&options).is_err());
+    }
+}
@@ -1,94 +1,68 @@
-use crate::api::{ConvertOptions, ConvertResult};
+use super::process::run_process;
+use super::{extension_of, ConvertJob, Converter, ConverterRegistry};
+use crate::api::{ConvertOptions, OnConflict};
+use crate::error::ConvertError;
+use crate::progress::ProgressReporter;
+use crate::task::CancelToken;
 use std::path::Path;
+use std::process::Command;
+use std::sync::atomic::{AtomicU64, Ordering};
 
 #[cfg(target_os = "windows")]
 use std::os::windows::process::CommandExt;
 
-/// Convert media files (audio/video)
-pub fn convert_media(
-    input_path: &str,
-    output_dir: &str,
-    options: &ConvertOptions,
-) -> ConvertResult {
-    let input = Path::new(input_path);
-    let stem = input
-        .file_stem()
-        .and_then(|s| s.to_str())
-        .unwrap_or("output");
-    let output_ext = options.output_format.to_lowercase();
-    let output_name = format!("{}.{}", stem, output_ext);
-    let output_path = Path::new(output_dir).join(&output_name);
-
-    let input_ext = input
-        .extension()
-        .and_then(|e| e.to_str())
-        .unwrap_or("")
-        .to_lowercase();
-
-    // Special case: Video -> Audio (via FFmpeg)
-    let is_video = matches!(
-        input_ext.as_str(),
-        "mp4" | "avi" | "mkv" | "mov" | "webm" | "flv"
-    );
-    let is_audio_output = matches!(
-        output_ext.as_str(),
-        "mp3" | "wav" | "aac" | "flac" | "ogg" | "m4a"
-    );
-
-    if is_video && is_audio_output {
-        return convert_via_ffmpeg(input_path, &output_path, options);
-    }
+/// Video -> Audio extraction (via FFmpeg)
+pub struct FfmpegAudioExtractor;
 
-    ConvertResult {
-        success: false,
-        output_path: None,
-        error: Some(format!(
-            "Unsupported media conversion: {} -> {}",
-            input_ext, output_ext
-        )),
+impl Converter for FfmpegAudioExtractor {
+    fn name(&self) -> &'static str {
+        "ffmpeg-audio"
     }
-}
 
-fn convert_via_ffmpeg(
-    input_path: &str,
-    output_path: &Path,
-    options: &ConvertOptions,
-) -> ConvertResult {
-    let ffmpeg_cmd = options.ffmpeg_path.as_deref().unwrap_or("ffmpeg");
-
-    fn tool_exists(cmd: &str) -> bool {
-        std::process::Command::new(cmd)
-            .arg("-version")
-            .output()
-            .is_ok()
+    fn inputs(&self) -> &[&'static str] {
+        &["mp4", "avi", "mkv", "mov", "webm", "flv"]
     }
 
-    if !tool_exists(ffmpeg_cmd) {
-        return ConvertResult {
-            success: false,
-            output_path: None,
-            error: Some(
-                "FFmpeg is required for media conversions.\n\nInstall FFmpeg:\n- Windows (winget): winget install ffmpeg\n- macOS: brew install ffmpeg\n- Or download from https://ffmpeg.org/download.html"
-                    .to_string(),
-            ),
-        };
+    fn outputs(&self) -> &[&'static str] {
+        &["mp3", "wav", "aac", "flac", "ogg", "m4a"]
     }
 
-    let output_ext = output_path
-        .extension()
-        .and_then(|e| e.to_str())
-        .unwrap_or("")
-        .to_lowercase();
-
-    let mut cmd = std::process::Command::new(ffmpeg_cmd);
+    fn required_tools(&self) -> &[&'static str] {
+        &["ffmpeg"]
+    }
 
-    #[cfg(target_os = "windows")]
-    {
-        const CREATE_NO_WINDOW: u32 = 0x08000000;
-        cmd.creation_flags(CREATE_NO_WINDOW);
+    fn convert(&self, job: &ConvertJob) -> Result<(), ConvertError> {
+        convert_via_ffmpeg(
+            job.input_path,
+            job.output_path,
+            job.options,
+            job.progress,
+            job.cancel,
+        )
     }
+}
+
+pub fn register(registry: &mut ConverterRegistry) {
+    registry.register(FfmpegAudioExtractor);
+}
 
-    cmd.arg("-y") // Overwrite output files without asking
+fn convert_via_ffmpeg(
+    input_path: &Path,
+    output_path: &Path,
+    options: &ConvertOptions,
+    progress: &ProgressReporter,
+    cancel: &CancelToken,
+) -> Result<(), ConvertError> {
+    let output_ext = extension_of(output_path);
+    let mut cmd = ffmpeg_command(options)?;
+
+    // The output path was already chosen according to `on_conflict`; only replace an
+    // existing file when overwriting was requested
+    let overwrite = options.on_conflict.unwrap_or_default() == OnConflict::Overwrite;
+    cmd.arg(if overwrite { "-y" } else { "-n" })
+        .arg("-nostats")
+        .arg("-progress") // Machine-readable progress on stdout
+        .arg("pipe:1")
         .arg("-i")
         .arg(input_path)
         .arg("-vn"); // Disable video recording (extract audio only)
@@ -158,28 +132,103 @@ fn convert_via_ffmpeg(
 
     cmd.arg(output_path);
 
-    match cmd.output() {
-        Ok(out) if out.status.success() => ConvertResult {
-            success: true,
-            output_path: Some(output_path.to_string_lossy().to_string()),
-            error: None,
+    progress.stage("Running FFmpeg");
+
+    // stderr carries the input duration (needed for percentages), stdout the `-progress` output
+    let duration_us = AtomicU64::new(0);
+    let out = run_process(
+        "FFmpeg",
+        &mut cmd,
+        cancel,
+        |line| {
+            // NOTE: out_time_ms is also in microseconds (long-standing FFmpeg quirk)
+            let out_time_us = line
+                .strip_prefix("out_time_us=")
+                .or_else(|| line.strip_prefix("out_time_ms="))
+                .and_then(|v| v.trim().parse::<u64>().ok());
+            let duration = duration_us.load(Ordering::Relaxed);
+            if let (Some(out_time_us), true) = (out_time_us, duration > 0) {
+                let percent = (out_time_us.saturating_mul(100) / duration).min(99);
+                progress.report(percent as i32, "Extracting audio");
+            }
         },
-        Ok(out) => {
-            let stderr = String::from_utf8_lossy(&out.stderr).to_string();
-            ConvertResult {
-                success: false,
-                output_path: None,
-                error: Some(format!(
-                    "FFmpeg conversion failed (exit code: {:?}).\n{}",
-                    out.status.code(),
-                    stderr
-                )),
+        |line| {
+            if let Some(duration) = parse_duration_us(line) {
+                duration_us.store(duration, Ordering::Relaxed);
             }
-        }
-        Err(e) => ConvertResult {
-            success: false,
-            output_path: None,
-            error: Some(format!("Failed to execute FFmpeg: {e}")),
         },
+    )?;
+
+    if !out.status.success() {
+        return Err(ConvertError::external_tool_failed(
+            "FFmpeg",
+            out.status.code(),
+            out.stderr,
+        ));
+    }
+
+    Ok(())
+}
+
+/// FFmpeg command (`options.ffmpeg_path` or `ffmpeg` from PATH), checked to be installed
+pub(crate) fn ffmpeg_command(options: &ConvertOptions) -> Result<Command, ConvertError> {
+    let ffmpeg_cmd = options.ffmpeg_path.as_deref().unwrap_or("ffmpeg");
+
+    fn tool_exists(cmd: &str) -> bool {
+        Command::new(cmd).arg("-version").output().is_ok()
+    }
+
+    if !tool_exists(ffmpeg_cmd) {
+        return Err(ConvertError::tool_missing(
+            "FFmpeg",
+            "Install FFmpeg:\n- Windows (winget): winget install ffmpeg\n- macOS: brew install ffmpeg\n- Or download from https://ffmpeg.org/download.html",
+        ));
+    }
+
+    #[cfg_attr(not(target_os = "windows"), allow(unused_mut))]
+    let mut cmd = Command::new(ffmpeg_cmd);
+
+    #[cfg(target_os = "windows")]
+    {
+        const CREATE_NO_WINDOW: u32 = 0x08000000;
+        cmd.creation_flags(CREATE_NO_WINDOW);
+    }
+
+    Ok(cmd)
+}
+
+/// Parse `Duration: 00:01:23.45` from FFmpeg's stderr into microseconds
+fn parse_duration_us(line: &str) -> Option<u64> {
+    let rest = line.trim_start().strip_prefix("Duration:")?.trim_start();
+    let timestamp = rest.split(',').next()?.trim();
+    let mut parts = timestamp.split(':');
+    let hours: f64 = parts.next()?.parse().ok()?;
+    let minutes: f64 = parts.next()?.parse().ok()?;
+    let seconds: f64 = parts.next()?.parse().ok()?;
+    let total = (hours * 3600.0 + minutes * 60.0 + seconds) * 1_000_000.0;
+    (total > 0.0).then_some(total as u64)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_duration_us_reads_ffmpeg_durations() {
+        assert_eq!(
+            parse_duration_us("  Duration: 00:01:23.45, start: 0.000000, bitrate: 128 kb/s"),
+            Some(83_450_000)
+        );
+        assert_eq!(
+            parse_duration_us("Duration: 01:00:00.00"),
+            Some(3_600_000_000)
+        );
+    }
+
+    #[test]
+    fn parse_duration_us_rejects_unknown_durations() {
+        assert_eq!(parse_duration_us("  Duration: N/A, bitrate: N/A"), None);
+        assert_eq!(parse_duration_us("  Duration: 00:00:00.00, start: 0"), None);
+        assert_eq!(parse_duration_us("  Stream #0:0: Audio: mp3"), None);
     }
 }
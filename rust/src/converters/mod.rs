@@ -1,9 +1,103 @@
+mod batch;
+mod combine;
 pub mod config;
+pub mod directory;
 pub mod document;
+pub mod graph;
 pub mod image;
 pub mod media;
+mod output;
+mod process;
+pub mod registry;
 
 use crate::api::{ConvertOptions, ConvertResult};
+use crate::error::ConvertError;
+use crate::progress::{new_task_id, ProgressCallback, ProgressReporter};
+use crate::task::{CancelToken, TaskHandle};
+use graph::{graph, RouteStep};
+use output::{resolve_output_path, OutputPath};
+use std::path::{Path, PathBuf};
+use std::sync::Mutex;
+
+pub use batch::convert_batch;
+pub use combine::{combine_pdf, contact_sheet};
+pub use registry::{registry, ConverterRegistry};
+
+/// A converter handling a set of input formats -> output formats
+pub trait Converter: Send + Sync {
+    /// Converter name (for logs/diagnostics)
+    fn name(&self) -> &'static str;
+
+    /// Supported input extensions (lowercase, without dot)
+    fn inputs(&self) -> &[&'static str];
+
+    /// Supported output extensions (lowercase, without dot)
+    fn outputs(&self) -> &[&'static str];
+
+    /// External tools required by this converter (e.g., "ffmpeg", "pandoc")
+    fn required_tools(&self) -> &[&'static str] {
+        &[]
+    }
+
+    /// Relative cost of one conversion, used to pick the cheapest multi-step route
+    ///
+    /// External tools are slower to start and less predictable than in-process conversions.
+    fn cost(&self) -> u32 {
+        if self.required_tools().is_empty() {
+            1
+        } else {
+            4
+        }
+    }
+
+    /// Whether this converter handles `input_ext -> output_ext`
+    fn supports(&self, input_ext: &str, output_ext: &str) -> bool {
+        self.inputs().contains(&input_ext) && self.outputs().contains(&output_ext)
+    }
+
+    /// Convert `job.input_path` into `job.output_path`
+    fn convert(&self, job: &ConvertJob) -> Result<(), ConvertError>;
+}
+
+/// A single conversion handed to a [`Converter`]
+pub struct ConvertJob<'a> {
+    pub input_path: &'a Path,
+    /// Input format (detected from the content, falling back to the extension)
+    pub input_format: &'a str,
+    pub output_path: &'a Path,
+    /// Output format (lowercase extension)
+    pub output_format: &'a str,
+    pub options: &'a ConvertOptions,
+    pub progress: &'a ProgressReporter,
+    /// Converters must stop (and kill external tools) once this is cancelled
+    pub cancel: &'a CancelToken,
+    /// Files a converter writes instead of `output_path` (e.g. numbered animation frames),
+    /// claimed through [`output::resolve_output_path`] like `output_path`
+    pub outputs: &'a Mutex<Vec<OutputPath>>,
+    /// Non-fatal issues reported in `ConvertResult::warnings`
+    pub warnings: &'a Mutex<Vec<String>>,
+    /// Quality picked by the converter (e.g. to fit `target_size_bytes`)
+    pub chosen_quality: &'a Mutex<Option<i32>>,
+}
+
+impl ConvertJob<'_> {
+    /// Record a warning (once, however often it is reported, e.g. for every frame)
+    pub fn warn(&self, message: impl Into<String>) {
+        let message = message.into();
+        let mut warnings = self.warnings.lock().unwrap_or_else(|e| e.into_inner());
+        if !warnings.contains(&message) {
+            warnings.push(message);
+        }
+    }
+}
+
+/// Lowercase file extension (empty if none)
+pub(crate) fn extension_of(path: &Path) -> String {
+    path.extension()
+        .and_then(|e| e.to_str())
+        .unwrap_or("")
+        .to_lowercase()
+}
 
 /// Convert a single file
 pub fn convert_single(
@@ -11,33 +105,265 @@ pub fn convert_single(
     output_dir: &str,
     options: &ConvertOptions,
 ) -> ConvertResult {
-    // Ensure output directory exists
-    if let Err(e) = std::fs::create_dir_all(output_dir) {
-        return ConvertResult {
-            success: false,
-            output_path: None,
-            error: Some(format!("Failed to create output directory: {}", e)),
-        };
+    let task = TaskHandle::register(&task_id_for(options));
+    let reporter = ProgressReporter::silent(task.task_id(), input_path);
+    convert_with_reporter(input_path, output_dir, options, &reporter, task.token())
+}
+
+/// Convert a single file, reporting progress through `on_progress`
+pub fn convert_single_with_progress(
+    input_path: &str,
+    output_dir: &str,
+    options: &ConvertOptions,
+    on_progress: ProgressCallback,
+) -> ConvertResult {
+    let task = TaskHandle::register(&task_id_for(options));
+    let reporter = ProgressReporter::new(on_progress, task.task_id(), input_path, 0, 1);
+    convert_with_reporter(input_path, output_dir, options, &reporter, task.token())
+}
+
+/// Task ID from the options, or a newly generated one
+fn task_id_for(options: &ConvertOptions) -> String {
+    options.task_id.clone().unwrap_or_else(new_task_id)
+}
+
+fn convert_with_reporter(
+    input_path: &str,
+    output_dir: &str,
+    options: &ConvertOptions,
+    reporter: &ProgressReporter,
+    cancel: &CancelToken,
+) -> ConvertResult {
+    reporter.report(0, "Starting");
+    let result = match plan_conversion(input_path, output_dir, options) {
+        Ok(plan) => run_conversion(&plan, options, reporter, cancel),
+        Err(e) => ConvertResult::err(e),
+    };
+    finish_reporting(reporter, result)
+}
+
+/// Emit the final progress event of a file
+fn finish_reporting(reporter: &ProgressReporter, result: ConvertResult) -> ConvertResult {
+    reporter.set_range(0, 100);
+    if result.skipped {
+        reporter.report(100, "Skipped");
+    } else if result.success {
+        reporter.report(100, "Completed");
+    } else if result.error_code == Some(crate::api::ConvertErrorCode::Cancelled) {
+        reporter.stage("Cancelled");
+    } else {
+        reporter.stage("Failed");
+    }
+    result
+}
+
+/// A conversion resolved to its route and output path
+struct PlannedConversion {
+    /// One step for a direct conversion, more when intermediate formats are needed
+    steps: Vec<RouteStep<'static>>,
+    input_path: PathBuf,
+    input_format: String,
+    output: OutputPath,
+    output_format: String,
+    /// Files written instead of `output` (see `ConvertJob::outputs`), claimed as long as
+    /// the plan lives
+    outputs: Mutex<Vec<OutputPath>>,
+}
+
+impl PlannedConversion {
+    /// Whether any step runs an external tool (FFmpeg, pandoc, ...)
+    fn uses_external_tool(&self) -> bool {
+        self.steps
+            .iter()
+            .any(|step| !step.converter.required_tools().is_empty())
+    }
+
+    /// Formats the file goes through, e.g. `["docx", "md", "pdf"]`
+    fn route(&self) -> Vec<String> {
+        std::iter::once(self.input_format.as_str())
+            .chain(self.steps.iter().map(|step| step.to))
+            .map(str::to_string)
+            .collect()
     }
+}
 
-    let file_type = crate::api::detect_file_type(input_path.to_string());
+fn plan_conversion(
+    input_path: &str,
+    output_dir: &str,
+    options: &ConvertOptions,
+) -> Result<PlannedConversion, ConvertError> {
+    // Ensure output directory exists
+    std::fs::create_dir_all(output_dir)
+        .map_err(|e| ConvertError::io("Failed to create output directory", e))?;
 
-    match file_type {
-        Some(crate::api::FileType::Image) => image::convert_image(input_path, output_dir, options),
-        Some(crate::api::FileType::Document) => {
-            document::convert_document(input_path, output_dir, options)
+    let input = Path::new(input_path);
+    let detection = crate::detect::detect(input);
+    let input_format = detection.format.unwrap_or_default();
+    let output_ext = options.output_format.to_lowercase();
+
+    let Some(steps) = graph().route(&input_format, &output_ext) else {
+        if detection.file_type.is_none() {
+            return Err(ConvertError::UnsupportedFileType {
+                extension: input_format,
+            });
         }
-        Some(crate::api::FileType::Audio) | Some(crate::api::FileType::Video) => {
-            // Audio/Video conversion (requires FFmpeg)
-            media::convert_media(input_path, output_dir, options)
+        return Err(ConvertError::unsupported_pair(input_format, output_ext));
+    };
+
+    let stem = input
+        .file_stem()
+        .and_then(|s| s.to_str())
+        .unwrap_or("output");
+    let output = resolve_output_path(
+        Path::new(output_dir),
+        stem,
+        &output_ext,
+        options.on_conflict.unwrap_or_default(),
+    )?;
+
+    Ok(PlannedConversion {
+        steps,
+        input_path: input.to_path_buf(),
+        input_format,
+        output,
+        output_format: output_ext,
+        outputs: Mutex::new(Vec::new()),
+    })
+}
+
+fn run_conversion(
+    plan: &PlannedConversion,
+    options: &ConvertOptions,
+    reporter: &ProgressReporter,
+    cancel: &CancelToken,
+) -> ConvertResult {
+    // Remaining files of a cancelled batch are not started
+    if cancel.is_cancelled() {
+        return ConvertResult::err(ConvertError::Cancelled);
+    }
+    if plan.output.skip() {
+        return ConvertResult::skipped(plan.output.path());
+    }
+
+    let warnings = Mutex::new(
+        plan.output
+            .warning()
+            .map(str::to_string)
+            .into_iter()
+            .collect(),
+    );
+    let chosen_quality = Mutex::new(None);
+    let result = if plan.steps.len() == 1 {
+        let job = ConvertJob {
+            input_path: &plan.input_path,
+            input_format: &plan.input_format,
+            output_path: plan.output.path(),
+            output_format: &plan.output_format,
+            options,
+            progress: reporter,
+            cancel,
+            outputs: &plan.outputs,
+            warnings: &warnings,
+            chosen_quality: &chosen_quality,
+        };
+        plan.steps[0].converter.convert(&job)
+    } else {
+        run_route(
+            plan,
+            options,
+            reporter,
+            cancel,
+            &plan.outputs,
+            &warnings,
+            &chosen_quality,
+        )
+    };
+    let outputs = plan.outputs.lock().unwrap_or_else(|e| e.into_inner());
+
+    if let Err(ConvertError::Cancelled) = result {
+        // Do not leave partially written files behind
+        let _ = std::fs::remove_file(plan.output.path());
+        for output in outputs.iter().filter(|output| !output.skip()) {
+            let _ = std::fs::remove_file(output.path());
         }
-        Some(crate::api::FileType::Config) => {
-            config::convert_config(input_path, output_dir, options)
+    }
+    let mut result: ConvertResult = result.map(|()| plan.output.path()).into();
+    if result.success && !outputs.is_empty() {
+        result.skipped = outputs.iter().all(OutputPath::skip);
+        let outputs: Vec<_> = outputs
+            .iter()
+            .map(|output| output.path().to_string_lossy().to_string())
+            .collect();
+        result.output_path = outputs.first().cloned();
+        result.output_paths = outputs;
+    }
+    result.route = plan.route();
+    if result.success {
+        result.warnings = warnings.into_inner().unwrap_or_else(|e| e.into_inner());
+        result.image_quality = chosen_quality
+            .into_inner()
+            .unwrap_or_else(|e| e.into_inner());
+    }
+    result
+}
+
+/// Run a multi-step route, keeping intermediate files in a temporary directory
+fn run_route(
+    plan: &PlannedConversion,
+    options: &ConvertOptions,
+    reporter: &ProgressReporter,
+    cancel: &CancelToken,
+    outputs: &Mutex<Vec<OutputPath>>,
+    warnings: &Mutex<Vec<String>>,
+    chosen_quality: &Mutex<Option<i32>>,
+) -> Result<(), ConvertError> {
+    let temp_dir = tempfile::Builder::new()
+        .prefix("convertx-")
+        .tempdir()
+        .map_err(|e| ConvertError::io("Failed to create temporary directory", e))?;
+    let stem = plan
+        .input_path
+        .file_stem()
+        .and_then(|s| s.to_str())
+        .unwrap_or("output");
+
+    let count = plan.steps.len();
+    let mut input_path = plan.input_path.clone();
+    for (index, step) in plan.steps.iter().enumerate() {
+        if cancel.is_cancelled() {
+            return Err(ConvertError::Cancelled);
         }
-        None => ConvertResult {
-            success: false,
-            output_path: None,
-            error: Some("Unsupported file type".to_string()),
-        },
+        let output_path = if index + 1 == count {
+            plan.output.path().to_path_buf()
+        } else {
+            temp_dir.path().join(format!("{}.{}", stem, step.to))
+        };
+
+        reporter.set_range(
+            (index * 100 / count) as i32,
+            ((index + 1) * 100 / count) as i32,
+        );
+        reporter.stage(format!(
+            "Step {}/{}: {} -> {}",
+            index + 1,
+            count,
+            step.from,
+            step.to
+        ));
+        let job = ConvertJob {
+            input_path: &input_path,
+            input_format: step.from,
+            output_path: &output_path,
+            output_format: step.to,
+            options,
+            progress: reporter,
+            cancel,
+            outputs,
+            warnings,
+            chosen_quality,
+        };
+        step.converter.convert(&job)?;
+        input_path = output_path;
     }
+    Ok(())
 }
@@ -0,0 +1,183 @@
+use crate::api::OnConflict;
+use crate::error::ConvertError;
+use std::collections::HashSet;
+use std::path::{Path, PathBuf};
+use std::sync::{Mutex, OnceLock};
+
+/// Output paths claimed by running conversions
+///
+/// Files of a parallel batch may map to the same output name (`photo.png` / `photo.jpg`
+/// -> `photo.webp`) before either has been written, so existence checks alone are not enough.
+fn claimed_paths() -> &'static Mutex<HashSet<PathBuf>> {
+    static CLAIMED: OnceLock<Mutex<HashSet<PathBuf>>> = OnceLock::new();
+    CLAIMED.get_or_init(Default::default)
+}
+
+/// Where a conversion writes its output
+pub struct OutputPath {
+    path: PathBuf,
+    skip: bool,
+    claimed: bool,
+    warning: Option<String>,
+}
+
+impl OutputPath {
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    /// The file already exists and `OnConflict::Skip` is set
+    pub fn skip(&self) -> bool {
+        self.skip
+    }
+
+    /// Why the path differs from the one asked for, to report with the result
+    pub fn warning(&self) -> Option<&str> {
+        self.warning.as_deref()
+    }
+}
+
+impl Drop for OutputPath {
+    fn drop(&mut self) {
+        if self.claimed {
+            claimed_paths()
+                .lock()
+                .unwrap_or_else(|e| e.into_inner())
+                .remove(&self.path);
+        }
+    }
+}
+
+/// Choose the output path `output_dir/stem.ext` according to the conflict policy
+///
+/// The path stays claimed until the returned [`OutputPath`] is dropped. Even with
+/// `OnConflict::Overwrite`, a path claimed by another running conversion is never shared:
+/// two jobs writing the same file at once would corrupt it, so the later one is renamed.
+pub fn resolve_output_path(
+    output_dir: &Path,
+    stem: &str,
+    ext: &str,
+    on_conflict: OnConflict,
+) -> Result<OutputPath, ConvertError> {
+    let mut claimed = claimed_paths().lock().unwrap_or_else(|e| e.into_inner());
+    let taken = |path: &Path| path.exists() || claimed.contains(path);
+
+    let mut path = output_dir.join(format!("{}.{}", stem, ext));
+    let mut skip = false;
+    let mut warning = None;
+    match on_conflict {
+        OnConflict::Overwrite if claimed.contains(&path) => {
+            let requested = path.clone();
+            // Existing files are still overwritten, so reruns reuse the same names
+            path = free_path(output_dir, stem, ext, |path| claimed.contains(path));
+            warning = Some(format!(
+                "Another file of this batch is written to {}; saved as {} instead",
+                requested.display(),
+                path.display()
+            ));
+        }
+        OnConflict::Overwrite => {}
+        OnConflict::Skip => skip = taken(&path),
+        OnConflict::Fail if taken(&path) => {
+            return Err(ConvertError::OutputExists {
+                path: path.to_string_lossy().to_string(),
+            })
+        }
+        OnConflict::Fail => {}
+        OnConflict::Rename if taken(&path) => path = free_path(output_dir, stem, ext, taken),
+        OnConflict::Rename => {}
+    }
+
+    let claimed = !skip && claimed.insert(path.clone());
+    Ok(OutputPath {
+        path,
+        skip,
+        claimed,
+        warning,
+    })
+}
+
+/// First of `stem_1.ext`, `stem_2.ext`, ... that is not `taken`
+fn free_path(output_dir: &Path, stem: &str, ext: &str, taken: impl Fn(&Path) -> bool) -> PathBuf {
+    (1..)
+        .map(|n| output_dir.join(format!("{}_{}.{}", stem, n, ext)))
+        .find(|path| !taken(path))
+        .expect("some numbered path is free")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn resolve(dir: &Path, on_conflict: OnConflict) -> OutputPath {
+        resolve_output_path(dir, "x", "png", on_conflict).unwrap()
+    }
+
+    #[test]
+    fn overwrite_keeps_existing_files_but_not_claimed_paths() {
+        let dir = tempfile::tempdir().unwrap();
+        std::fs::write(dir.path().join("x.png"), b"").unwrap();
+
+        let first = resolve(dir.path(), OnConflict::Overwrite);
+        assert_eq!(first.path(), dir.path().join("x.png"));
+        assert!(!first.skip() && first.warning().is_none());
+
+        let second = resolve(dir.path(), OnConflict::Overwrite);
+        assert_eq!(second.path(), dir.path().join("x_1.png"));
+        assert!(second.warning().is_some());
+
+        drop((first, second));
+        assert_eq!(
+            resolve(dir.path(), OnConflict::Overwrite).path(),
+            dir.path().join("x.png")
+        );
+    }
+
+    #[test]
+    fn skip_skips_existing_and_claimed_paths() {
+        let dir = tempfile::tempdir().unwrap();
+        let first = resolve(dir.path(), OnConflict::Skip);
+        assert!(!first.skip());
+        assert!(resolve(dir.path(), OnConflict::Skip).skip());
+
+        drop(first);
+        assert!(!resolve(dir.path(), OnConflict::Skip).skip());
+        std::fs::write(dir.path().join("x.png"), b"").unwrap();
+        assert!(resolve(dir.path(), OnConflict::Skip).skip());
+    }
+
+    #[test]
+    fn fail_rejects_existing_and_claimed_paths() {
+        let dir = tempfile::tempdir().unwrap();
+        let first = resolve(dir.path(), OnConflict::Fail);
+        assert!(matches!(
+            resolve_output_path(dir.path(), "x", "png", OnConflict::Fail),
+            Err(ConvertError::OutputExists { .. })
+        ));
+
+        drop(first);
+        std::fs::write(dir.path().join("x.png"), b"").unwrap();
+        assert!(matches!(
+            resolve_output_path(dir.path(), "x", "png", OnConflict::Fail),
+            Err(ConvertError::OutputExists { .. })
+        ));
+    }
+
+    #[test]
+    fn rename_numbers_past_existing_and_claimed_paths() {
+        let dir = tempfile::tempdir().unwrap();
+        assert_eq!(
+            resolve(dir.path(), OnConflict::Rename).path(),
+            dir.path().join("x.png")
+        );
+
+        std::fs::write(dir.path().join("x.png"), b"").unwrap();
+        std::fs::write(dir.path().join("x_1.png"), b"").unwrap();
+        let first = resolve(dir.path(), OnConflict::Rename);
+        assert_eq!(first.path(), dir.path().join("x_2.png"));
+        assert_eq!(
+            resolve(dir.path(), OnConflict::Rename).path(),
+            dir.path().join("x_3.png")
+        );
+    }
+}
@@ -0,0 +1,101 @@
+use crate::error::ConvertError;
+use crate::task::CancelToken;
+use std::io::{BufRead, BufReader, Read};
+use std::process::{Command, ExitStatus, Stdio};
+use std::sync::mpsc::{self, RecvTimeoutError, Sender};
+use std::time::Duration;
+
+/// How often a running child process is checked for exit / cancellation
+const POLL_INTERVAL: Duration = Duration::from_millis(50);
+
+/// Output of an external tool run
+pub(crate) struct ProcessOutput {
+    pub status: ExitStatus,
+    pub stderr: String,
+}
+
+/// Run an external tool, killing it if the task is cancelled
+///
+/// `on_stdout_line` / `on_stderr_line` are called for every output line while the tool runs.
+pub(crate) fn run_process(
+    tool: &str,
+    cmd: &mut Command,
+    cancel: &CancelToken,
+    mut on_stdout_line: impl FnMut(&str),
+    mut on_stderr_line: impl FnMut(&str),
+) -> Result<ProcessOutput, ConvertError> {
+    if cancel.is_cancelled() {
+        return Err(ConvertError::Cancelled);
+    }
+
+    let mut child = cmd
+        .stdin(Stdio::null())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()
+        .map_err(|e| ConvertError::io(format!("Failed to execute {}", tool), e))?;
+
+    // The pipes are read on detached threads: helper processes the tool started may keep
+    // them open after it is killed, and a cancelled task must not wait for those
+    let (lines, received) = mpsc::channel();
+    if let Some(stdout) = child.stdout.take() {
+        spawn_reader(stdout, Stream::Stdout, lines.clone());
+    }
+    if let Some(stderr) = child.stderr.take() {
+        spawn_reader(stderr, Stream::Stderr, lines);
+    }
+
+    let mut stderr = String::new();
+    let mut status = None;
+    loop {
+        if status.is_none() {
+            match child.try_wait() {
+                Ok(Some(exit)) => status = Some(exit),
+                Ok(None) if cancel.is_cancelled() => {
+                    let _ = child.kill();
+                    let _ = child.wait();
+                    return Err(ConvertError::Cancelled);
+                }
+                Ok(None) => {}
+                Err(e) => return Err(ConvertError::io(format!("Failed to wait for {}", tool), e)),
+            }
+        } else if cancel.is_cancelled() {
+            // The tool is done, but something it started still holds the pipes open
+            return Err(ConvertError::Cancelled);
+        }
+
+        match received.recv_timeout(POLL_INTERVAL) {
+            Ok((Stream::Stdout, line)) => on_stdout_line(&line),
+            Ok((Stream::Stderr, line)) => {
+                on_stderr_line(&line);
+                stderr.push_str(&line);
+                stderr.push('\n');
+            }
+            Err(RecvTimeoutError::Timeout) => {}
+            // Both pipes are closed: wait for the exit
+            Err(RecvTimeoutError::Disconnected) => match status {
+                Some(status) => return Ok(ProcessOutput { status, stderr }),
+                None => std::thread::sleep(POLL_INTERVAL),
+            },
+        }
+    }
+}
+
+#[derive(Clone, Copy)]
+enum Stream {
+    Stdout,
+    Stderr,
+}
+
+/// Send every line of `pipe` until it is closed or nobody listens anymore
+fn spawn_reader(pipe: impl Read + Send + 'static, stream: Stream, lines: Sender<(Stream, String)>) {
+    std::thread::spawn(move || {
+        for line in BufReader::new(pipe).split(b'\n').map_while(Result::ok) {
+            let line = String::from_utf8_lossy(&line);
+            let line = line.trim_end_matches('\r').to_string();
+            if lines.send((stream, line)).is_err() {
+                break;
+            }
+        }
+    });
+}
@@ -0,0 +1,59 @@
+use super::Converter;
+use std::sync::OnceLock;
+
+/// Registry of all available converters
+///
+/// Both dispatch (`convert_single`) and the supported-format queries are derived from
+/// the registered converters, so they cannot disagree.
+#[derive(Default)]
+pub struct ConverterRegistry {
+    converters: Vec<Box<dyn Converter>>,
+}
+
+impl ConverterRegistry {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Register a converter
+    pub fn register(&mut self, converter: impl Converter + 'static) {
+        self.converters.push(Box::new(converter));
+    }
+
+    /// All registered converters, in registration order
+    pub fn converters(&self) -> impl Iterator<Item = &dyn Converter> {
+        self.converters.iter().map(|c| c.as_ref())
+    }
+
+    /// Find the converter handling `input_ext -> output_ext` (extensions are lowercase, without dot)
+    pub fn find(&self, input_ext: &str, output_ext: &str) -> Option<&dyn Converter> {
+        self.converters()
+            .find(|c| c.supports(input_ext, output_ext))
+    }
+
+    /// Output formats reachable from `input_ext`, in registration order without duplicates
+    pub fn outputs_for(&self, input_ext: &str) -> Vec<String> {
+        let mut outputs: Vec<String> = Vec::new();
+        for converter in self.converters() {
+            for output in converter.outputs() {
+                if converter.supports(input_ext, output) && !outputs.iter().any(|o| o == output) {
+                    outputs.push(output.to_string());
+                }
+            }
+        }
+        outputs
+    }
+}
+
+/// Global converter registry (every converter module registers into it on first use)
+pub fn registry() -> &'static ConverterRegistry {
+    static REGISTRY: OnceLock<ConverterRegistry> = OnceLock::new();
+    REGISTRY.get_or_init(|| {
+        let mut registry = ConverterRegistry::new();
+        super::image::register(&mut registry);
+        super::document::register(&mut registry);
+        super::media::register(&mut registry);
+        super::config::register(&mut registry);
+        registry
+    })
+}
@@ -0,0 +1,385 @@
+use crate::api::{DetectionConfidence, FileDetection};
+use std::io::{Read, Seek, SeekFrom};
+use std::path::Path;
+
+/// How many bytes to read from the start (and, for ZIP containers, the end) of a file
+const SNIFF_LEN: u64 = 64 * 1024;
+
+/// Detect the format of a file from its content, falling back to the extension
+pub fn detect(path: &Path) -> FileDetection {
+    let extension = path
+        .extension()
+        .and_then(|e| e.to_str())
+        .map(|e| e.to_lowercase())
+        .filter(|e| !e.is_empty());
+
+    let (sniffed, confidence) = match sniff_file(path, extension.as_deref()) {
+        Some((format, confidence)) => (Some(format), confidence),
+        None => (None, DetectionConfidence::Unknown),
+    };
+
+    let (format, confidence, extension_mismatch) = match (sniffed, extension.as_deref()) {
+        // Content and extension agree: keep the extension spelling (e.g. "jpeg", "yml")
+        (Some(sniffed), Some(ext)) if same_format(sniffed, ext) => {
+            (Some(ext.to_string()), confidence, false)
+        }
+        (Some(sniffed), ext) => (
+            Some(sniffed.to_string()),
+            confidence,
+            ext.is_some_and(|e| crate::api::file_type_for_extension(e).is_some()),
+        ),
+        (None, Some(ext)) => (Some(ext.to_string()), DetectionConfidence::Low, false),
+        (None, None) => (None, DetectionConfidence::Unknown, false),
+    };
+
+    FileDetection {
+        file_type: format
+            .as_deref()
+            .and_then(crate::api::file_type_for_extension),
+        format,
+        extension,
+        confidence,
+        extension_mismatch,
+    }
+}
+
+/// Whether two extensions denote the same format (e.g. "jpg" / "jpeg")
+pub fn same_format(a: &str, b: &str) -> bool {
+    canonical(a) == canonical(b)
+}
+
+fn canonical(ext: &str) -> &str {
+    match ext {
+        "jpeg" => "jpg",
+        "tif" => "tiff",
+        "yml" => "yaml",
+        "htm" => "html",
+        "markdown" => "md",
+        other => other,
+    }
+}
+
+fn sniff_file(path: &Path, extension: Option<&str>) -> Option<(&'static str, DetectionConfidence)> {
+    let mut file = std::fs::File::open(path).ok()?;
+    let mut head = Vec::new();
+    file.by_ref().take(SNIFF_LEN).read_to_end(&mut head).ok()?;
+
+    if head.starts_with(b"PK\x03\x04") {
+        // Office/EPUB are ZIP containers: the central directory at the end lists every entry
+        let mut tail = Vec::new();
+        let len = file.metadata().ok()?.len();
+        if len > SNIFF_LEN {
+            file.seek(SeekFrom::Start(len.saturating_sub(SNIFF_LEN)))
+                .ok()?;
+            file.read_to_end(&mut tail).ok()?;
+        }
+        return sniff_zip(&head, &tail).map(|f| (f, DetectionConfidence::High));
+    }
+
+    if let Some(format) = sniff_signature(&head) {
+        return Some((format, DetectionConfidence::High));
+    }
+
+    sniff_text(&head, extension).map(|f| (f, DetectionConfidence::Medium))
+}
+
+/// Match well-known binary file signatures
+fn sniff_signature(b: &[u8]) -> Option<&'static str> {
+    let at = |offset: usize, sig: &[u8]| b.get(offset..offset + sig.len()) == Some(sig);
+
+    if at(0, b"\x89PNG\r\n\x1a\n") {
+        Some("png")
+    } else if at(0, b"\xFF\xD8\xFF") {
+        Some("jpg")
+    } else if at(0, b"GIF87a") || at(0, b"GIF89a") {
+        Some("gif")
+    } else if at(0, b"RIFF") && at(8, b"WEBP") {
+        Some("webp")
+    } else if at(0, b"RIFF") && at(8, b"WAVE") {
+        Some("wav")
+    } else if at(0, b"RIFF") && at(8, b"AVI ") {
+        Some("avi")
+    } else if at(0, b"BM") && is_bmp(b) {
+        Some("bmp")
+    } else if at(0, b"\x00\x00\x01\x00") {
+        Some("ico")
+    } else if at(0, b"II*\x00") || at(0, b"MM\x00*") {
+        Some("tiff")
+    } else if at(0, b"qoif") {
+        Some("qoi")
+    } else if is_pnm(b) {
+        Some("pnm")
+    } else if at(0, b"%PDF-") {
+        Some("pdf")
+    } else if at(4, b"ftyp") {
+        match b.get(8..12) {
+            Some(b"M4A ") | Some(b"M4B ") => Some("m4a"),
+            Some(b"qt  ") => Some("mov"),
+            Some(b"avif") | Some(b"avis") => Some("avif"),
+            Some(b"heic") | Some(b"heix") | Some(b"mif1") => None,
+            _ => Some("mp4"),
+        }
+    } else if at(0, b"\x1A\x45\xDF\xA3") {
+        // Matroska / WebM share the EBML header; the DocType tells them apart
+        let header = &b[..b.len().min(64)];
+        if header.windows(4).any(|w| w == b"webm") {
+            Some("webm")
+        } else {
+            Some("mkv")
+        }
+    } else if at(0, b"FLV\x01") && at(5, b"\0\0\0\x09") {
+        // Version 1 and the fixed header size
+        Some("flv")
+    } else if at(0, b"fLaC") {
+        Some("flac")
+    } else if at(0, b"OggS\0") {
+        Some("ogg")
+    } else if is_id3(b) {
+        Some("mp3")
+    } else if b.len() >= 3 && b[0] == 0xFF && (b[1] & 0xF6) == 0xF0 && (b[2] >> 2) & 0x0F < 13 {
+        // ADTS (AAC) frame header: MPEG layer bits are 00, valid sampling rate index
+        Some("aac")
+    } else if is_mpeg_audio_frame(b) {
+        Some("mp3")
+    } else {
+        None
+    }
+}
+
+/// BMP file header followed by a DIB header of a known size, with the pixels after both
+///
+/// "BM" alone also starts plenty of text files.
+fn is_bmp(b: &[u8]) -> bool {
+    let u32_at = |offset: usize| {
+        b.get(offset..offset + 4)
+            .map(|v| u32::from_le_bytes([v[0], v[1], v[2], v[3]]))
+    };
+    let (Some(pixel_offset), Some(dib_size)) = (u32_at(10), u32_at(14)) else {
+        return false;
+    };
+    matches!(dib_size, 12 | 16 | 40 | 52 | 56 | 64 | 108 | 124) && pixel_offset >= 14 + dib_size
+}
+
+/// Netpbm header: P1-P6 (PBM/PGM/PPM) followed by the width, P7 (PAM) by a header field
+fn is_pnm(b: &[u8]) -> bool {
+    if b.len() < 3 || b[0] != b'P' || !(b'1'..=b'7').contains(&b[1]) {
+        return false;
+    }
+    // Whitespace and comments may come between the magic number and the first field
+    let mut rest = &b[2..];
+    if !rest[0].is_ascii_whitespace() {
+        return false;
+    }
+    loop {
+        match rest.first() {
+            Some(c) if c.is_ascii_whitespace() => rest = &rest[1..],
+            Some(b'#') => match rest.iter().position(|&c| c == b'\n') {
+                Some(end) => rest = &rest[end..],
+                None => return false,
+            },
+            Some(c) if b[1] != b'7' => return c.is_ascii_digit(),
+            Some(_) => {
+                return [
+                    b"WIDTH".as_slice(),
+                    b"HEIGHT",
+                    b"DEPTH",
+                    b"MAXVAL",
+                    b"TUPLTYPE",
+                ]
+                .iter()
+                .any(|field| rest.starts_with(field))
+            }
+            None => return false,
+        }
+    }
+}
+
+/// ID3v2 tag header: version 2-4 and a syncsafe size (high bits clear)
+fn is_id3(b: &[u8]) -> bool {
+    b.len() >= 10
+        && b.starts_with(b"ID3")
+        && (2..=4).contains(&b[3])
+        && b[4] != 0xFF
+        && b[6..10].iter().all(|&v| v < 0x80)
+}
+
+/// MPEG audio frame header: frame sync, then no reserved version, layer, bitrate or
+/// sampling rate
+fn is_mpeg_audio_frame(b: &[u8]) -> bool {
+    b.len() >= 3
+        && b[0] == 0xFF
+        && (b[1] & 0xE0) == 0xE0
+        && (b[1] >> 3) & 0x03 != 1
+        && (b[1] >> 1) & 0x03 != 0
+        && b[2] >> 4 != 0x0F
+        && (b[2] >> 2) & 0x03 != 3
+}
+
+/// Tell EPUB / DOCX / PPTX apart by the ZIP entry names
+fn sniff_zip(head: &[u8], tail: &[u8]) -> Option<&'static str> {
+    // EPUB stores an uncompressed "mimetype" entry first
+    if head.get(30..38) == Some(b"mimetype") && contains(head, b"application/epub+zip") {
+        return Some("epub");
+    }
+
+    let has_entry = |name: &[u8]| contains(head, name) || contains(tail, name);
+    if has_entry(b"word/") {
+        Some("docx")
+    } else if has_entry(b"ppt/") {
+        Some("pptx")
+    } else if has_entry(b"META-INF/container.xml") {
+        Some("epub")
+    } else {
+        None
+    }
+}
+
+/// Heuristics for text formats
+///
+/// A text extension wins unless the content provably contradicts it
+/// (e.g. a `.json` file that is really YAML).
+fn sniff_text(b: &[u8], extension: Option<&str>) -> Option<&'static str> {
+    if b.contains(&0) {
+        return None;
+    }
+    // The head may cut a multi-byte character in half
+    let text = match std::str::from_utf8(b) {
+        Ok(text) => text,
+        Err(e) if e.error_len().is_none() => std::str::from_utf8(&b[..e.valid_up_to()]).ok()?,
+        Err(_) => return None,
+    };
+    let trimmed = text.trim_start_matches('\u{feff}').trim_start();
+
+    let is_json = || serde_json::from_str::<serde_json::Value>(text).is_ok();
+    let is_yaml_document = || {
+        matches!(
+            serde_yaml::from_str::<serde_yaml::Value>(text),
+            Ok(serde_yaml::Value::Mapping(_)) | Ok(serde_yaml::Value::Sequence(_))
+        )
+    };
+
+    match extension.map(canonical) {
+        Some("json") if is_json() => Some("json"),
+        Some("json") if is_yaml_document() => Some("yaml"),
+        Some("md" | "html" | "txt" | "yaml" | "properties" | "svg" | "json") => None,
+        // Missing, unknown or binary extension: guess from the content
+        _ => {
+            let lower = trimmed.chars().take(512).collect::<String>().to_lowercase();
+            if lower.starts_with("<svg") || (lower.starts_with("<?xml") && lower.contains("<svg")) {
+                Some("svg")
+            } else if lower.starts_with("<!doctype html") || lower.starts_with("<html") {
+                Some("html")
+            } else if (trimmed.starts_with('{') || trimmed.starts_with('[')) && is_json() {
+                Some("json")
+            } else if is_yaml_document() {
+                Some("yaml")
+            } else {
+                Some("txt")
+            }
+        }
+    }
+}
+
+fn contains(haystack: &[u8], needle: &[u8]) -> bool {
+    haystack.windows(needle.len()).any(|w| w == needle)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn detect_content(name: &str, content: &[u8]) -> FileDetection {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join(name);
+        std::fs::write(&path, content).unwrap();
+        detect(&path)
+    }
+
+    #[test]
+    fn sniff_signature_matches_magic_bytes() {
+        assert_eq!(sniff_signature(b"\x89PNG\r\n\x1a\n\0\0"), Some("png"));
+        assert_eq!(sniff_signature(b"\xFF\xD8\xFF\xE0"), Some("jpg"));
+        assert_eq!(sniff_signature(b"RIFF\0\0\0\0WEBPVP8 "), Some("webp"));
+        assert_eq!(sniff_signature(b"RIFF\0\0\0\0WAVEfmt "), Some("wav"));
+        assert_eq!(sniff_signature(b"\0\0\0\x1cftypavif"), Some("avif"));
+        assert_eq!(sniff_signature(b"\0\0\0\x1cftypisom"), Some("mp4"));
+        assert_eq!(sniff_signature(b"\0\0\0\x1cftypheic"), None);
+        assert_eq!(sniff_signature(b"P6\n4 4\n255\n"), Some("pnm"));
+        assert_eq!(sniff_signature(b"%PDF-1.7"), Some("pdf"));
+    }
+
+    #[test]
+    fn sniff_signature_ignores_text_and_short_input() {
+        assert_eq!(sniff_signature(b""), None);
+        assert_eq!(sniff_signature(b"BM"), None);
+        assert_eq!(sniff_signature(b"Plain text"), None);
+        assert_eq!(sniff_signature(b"RIFF"), None);
+    }
+
+    #[test]
+    fn detect_flags_a_misleading_extension() {
+        let detection = detect_content("photo.jpg", b"\x89PNG\r\n\x1a\n\0\0");
+        assert_eq!(detection.format.as_deref(), Some("png"));
+        assert!(detection.extension_mismatch);
+
+        let detection = detect_content("config.json", b"a: 1\nb: [2, 3]\n");
+        assert_eq!(detection.format.as_deref(), Some("yaml"));
+        assert!(detection.extension_mismatch);
+    }
+
+    #[test]
+    fn detect_keeps_matching_and_unknown_extensions() {
+        let detection = detect_content("photo.jpeg", b"\xFF\xD8\xFF\xE0");
+        assert_eq!(detection.format.as_deref(), Some("jpeg"));
+        assert!(!detection.extension_mismatch);
+
+        // Not a supported extension, so nothing to contradict
+        let detection = detect_content("download.bin", b"\x89PNG\r\n\x1a\n\0\0");
+        assert_eq!(detection.format.as_deref(), Some("png"));
+        assert!(!detection.extension_mismatch);
+
+        let detection = detect_content("notes.md", b"# Title\n");
+        assert_eq!(detection.format.as_deref(), Some("md"));
+        assert!(!detection.extension_mismatch);
+    }
+
+    #[test]
+    fn sniff_signature_validates_weak_signatures() {
+        let mut bmp = Vec::new();
+        image::RgbImage::new(2, 2)
+            .write_to(&mut std::io::Cursor::new(&mut bmp), image::ImageFormat::Bmp)
+            .unwrap();
+        assert_eq!(sniff_signature(&bmp), Some("bmp"));
+        assert_eq!(
+            sniff_signature(b"P1\n# comment\n2 2\n0 1 1 0\n"),
+            Some("pnm")
+        );
+        assert_eq!(sniff_signature(b"P7\nWIDTH 2\nHEIGHT 2\n"), Some("pnm"));
+        assert_eq!(sniff_signature(b"FLV\x01\x05\0\0\0\x09"), Some("flv"));
+        assert_eq!(sniff_signature(b"ID3\x04\0\0\0\0\x01\x7f"), Some("mp3"));
+        assert_eq!(sniff_signature(b"\xFF\xFB\x90\x64"), Some("mp3"));
+        assert_eq!(sniff_signature(b"\xFF\xF1\x50\x80"), Some("aac"));
+
+        assert_eq!(sniff_signature(b"BMW 3 series, 2012, 120000 km"), None);
+        assert_eq!(sniff_signature(b"P1 fix the crash on startup"), None);
+        assert_eq!(sniff_signature(b"P7 notes\n"), None);
+        assert_eq!(sniff_signature(b"FLV is a video format"), None);
+        assert_eq!(sniff_signature(b"ID3 tags hold the title"), None);
+        assert_eq!(sniff_signature(b"OggS is the page marker"), None);
+        assert_eq!(sniff_signature(b"\xFF\xFF\xFF\xFF"), None);
+    }
+
+    #[test]
+    fn detect_keeps_text_files_starting_like_binary_formats() {
+        for (name, content) in [
+            ("cars.txt", b"BMW 3 series, 2012\n".as_slice()),
+            ("todo.md", b"P1 fix the crash on startup\n"),
+            ("notes.txt", b"ID3 tags hold the title\n"),
+            ("notes.md", b"FLV is a video format\n"),
+        ] {
+            let detection = detect_content(name, content);
+            assert_eq!(detection.format.as_deref(), detection.extension.as_deref());
+            assert!(!detection.extension_mismatch, "{}", name);
+        }
+    }
+}
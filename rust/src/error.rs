@@ -0,0 +1,214 @@
+use crate::api::{ConvertErrorCode, ConvertErrorDetails, ConvertResult};
+use std::path::Path;
+use thiserror::Error;
+
+/// Conversion error
+#[derive(Debug, Error)]
+pub enum ConvertError {
+    /// A required external tool (FFmpeg, pandoc, Chromium, ...) is not available
+    #[error("{tool} is required for this conversion.\n\n{install_hint}")]
+    ToolMissing { tool: String, install_hint: String },
+
+    /// The input/output format pair is not supported
+    #[error("Unsupported conversion: {from} -> {to}")]
+    UnsupportedPair { from: String, to: String },
+
+    /// The input file type could not be recognized
+    #[error("Unsupported file type: {extension}")]
+    UnsupportedFileType { extension: String },
+
+    /// The input could not be decoded / parsed
+    #[error("Failed to decode {format}: {message}")]
+    Decode { format: String, message: String },
+
+    /// The output could not be encoded / serialized
+    #[error("Failed to encode {format}: {message}")]
+    Encode { format: String, message: String },
+
+    /// File system error
+    #[error("{context}: {source}")]
+    Io {
+        context: String,
+        #[source]
+        source: std::io::Error,
+    },
+
+    /// An external tool ran but reported a failure
+    #[error("{tool} conversion failed (exit code: {exit_code:?}).\n{stderr}")]
+    ExternalToolFailed {
+        tool: String,
+        exit_code: Option<i32>,
+        stderr: String,
+    },
+
+    /// The conversion was cancelled by the user
+    #[error("Conversion cancelled")]
+    Cancelled,
+
+    /// The output file already exists and `OnConflict::Fail` is set
+    #[error("Output file already exists: {path}")]
+    OutputExists { path: String },
+
+    /// An option value is invalid
+    #[error("Invalid {option}: {message}")]
+    InvalidOption { option: String, message: String },
+
+    /// Unexpected internal error
+    #[error("{0}")]
+    Internal(String),
+}
+
+impl ConvertError {
+    pub fn tool_missing(tool: impl Into<String>, install_hint: impl Into<String>) -> Self {
+        Self::ToolMissing {
+            tool: tool.into(),
+            install_hint: install_hint.into(),
+        }
+    }
+
+    pub fn unsupported_pair(from: impl Into<String>, to: impl Into<String>) -> Self {
+        Self::UnsupportedPair {
+            from: from.into(),
+            to: to.into(),
+        }
+    }
+
+    pub fn decode(format: impl Into<String>, message: impl ToString) -> Self {
+        Self::Decode {
+            format: format.into(),
+            message: message.to_string(),
+        }
+    }
+
+    pub fn encode(format: impl Into<String>, message: impl ToString) -> Self {
+        Self::Encode {
+            format: format.into(),
+            message: message.to_string(),
+        }
+    }
+
+    pub fn io(context: impl Into<String>, source: std::io::Error) -> Self {
+        Self::Io {
+            context: context.into(),
+            source,
+        }
+    }
+
+    pub fn invalid_option(option: impl Into<String>, message: impl ToString) -> Self {
+        Self::InvalidOption {
+            option: option.into(),
+            message: message.to_string(),
+        }
+    }
+
+    pub fn external_tool_failed(
+        tool: impl Into<String>,
+        exit_code: Option<i32>,
+        stderr: impl Into<String>,
+    ) -> Self {
+        Self::ExternalToolFailed {
+            tool: tool.into(),
+            exit_code,
+            stderr: stderr.into(),
+        }
+    }
+
+    /// Stable error code exposed to the Dart side
+    pub fn code(&self) -> ConvertErrorCode {
+        match self {
+            Self::ToolMissing { .. } => ConvertErrorCode::ToolMissing,
+            Self::UnsupportedPair { .. } => ConvertErrorCode::UnsupportedPair,
+            Self::UnsupportedFileType { .. } => ConvertErrorCode::UnsupportedFileType,
+            Self::Decode { .. } => ConvertErrorCode::Decode,
+            Self::Encode { .. } => ConvertErrorCode::Encode,
+            Self::Io { .. } => ConvertErrorCode::Io,
+            Self::ExternalToolFailed { .. } => ConvertErrorCode::ExternalToolFailed,
+            Self::Cancelled => ConvertErrorCode::Cancelled,
+            Self::OutputExists { .. } => ConvertErrorCode::OutputExists,
+            Self::InvalidOption { .. } => ConvertErrorCode::InvalidOption,
+            Self::Internal(_) => ConvertErrorCode::Internal,
+        }
+    }
+
+    /// Structured fields for the error (tool name, format pair, exit code, ...)
+    pub fn details(&self) -> ConvertErrorDetails {
+        let mut details = ConvertErrorDetails::default();
+        match self {
+            Self::ToolMissing { tool, .. } => details.tool = Some(tool.clone()),
+            Self::UnsupportedPair { from, to } => {
+                details.from = Some(from.clone());
+                details.to = Some(to.clone());
+            }
+            Self::UnsupportedFileType { extension } => details.from = Some(extension.clone()),
+            Self::Decode { format, .. } => details.from = Some(format.clone()),
+            Self::Encode { format, .. } => details.to = Some(format.clone()),
+            Self::ExternalToolFailed {
+                tool,
+                exit_code,
+                stderr,
+            } => {
+                details.tool = Some(tool.clone());
+                details.exit_code = *exit_code;
+                details.stderr = Some(stderr.clone());
+            }
+            Self::Io { .. }
+            | Self::Cancelled
+            | Self::OutputExists { .. }
+            | Self::InvalidOption { .. }
+            | Self::Internal(_) => {}
+        }
+        details
+    }
+}
+
+impl ConvertResult {
+    /// Successful conversion result
+    pub fn ok(output_path: &Path) -> Self {
+        let output_path = output_path.to_string_lossy().to_string();
+        ConvertResult {
+            success: true,
+            output_path: Some(output_path.clone()),
+            error: None,
+            error_code: None,
+            error_details: None,
+            skipped: false,
+            route: Vec::new(),
+            output_paths: vec![output_path],
+            warnings: Vec::new(),
+            image_quality: None,
+        }
+    }
+
+    /// The output file already existed and was kept
+    pub fn skipped(output_path: &Path) -> Self {
+        ConvertResult {
+            skipped: true,
+            ..ConvertResult::ok(output_path)
+        }
+    }
+
+    /// Failed conversion result
+    pub fn err(error: ConvertError) -> Self {
+        ConvertResult {
+            success: false,
+            output_path: None,
+            error: Some(error.to_string()),
+            error_code: Some(error.code()),
+            error_details: Some(error.details()),
+            skipped: false,
+            route: Vec::new(),
+            output_paths: Vec::new(),
+            warnings: Vec::new(),
+            image_quality: None,
+        }
+    }
+}
+
+impl<P: AsRef<Path>> From<Result<P, ConvertError>> for ConvertResult {
+    fn from(result: Result<P, ConvertError>) -> Self {
+        match result {
+            Ok(path) => ConvertResult::ok(path.as_ref()),
+            Err(e) => ConvertResult::err(e),
+        }
+    }
+}
@@ -37,7 +37,7 @@ flutter_rust_bridge::frb_generated_boilerplate!(
     default_rust_auto_opaque = RustAutoOpaqueMoi,
 );
 pub(crate) const FLUTTER_RUST_BRIDGE_CODEGEN_VERSION: &str = "2.11.1";
-pub(crate) const FLUTTER_RUST_BRIDGE_CODEGEN_CONTENT_HASH: i32 = 545976053;
+pub(crate) const FLUTTER_RUST_BRIDGE_CODEGEN_CONTENT_HASH: i32 = -319853550;
 
 // Section: executor
 
@@ -45,6 +45,245 @@ flutter_rust_bridge::frb_generated_default_handler!();
 
 // Section: wire_funcs
 
+fn wire__crate__api__cancel_conversion_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "cancel_conversion",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_task_id = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, ()>((move || {
+                    let output_ok =
+                        Result::<_, ()>::Ok(crate::api::cancel_conversion(api_task_id))?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__combine_images_to_pdf_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "combine_images_to_pdf",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_input_paths = <Vec<String>>::sse_decode(&mut deserializer);
+            let api_output_dir = <String>::sse_decode(&mut deserializer);
+            let api_options = <crate::api::ConvertOptions>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, ()>((move || {
+                    let output_ok = Result::<_, ()>::Ok(crate::api::combine_images_to_pdf(
+                        api_input_paths,
+                        api_output_dir,
+                        api_options,
+                    ))?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__combine_images_to_pdf_with_progress_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "combine_images_to_pdf_with_progress",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_input_paths = <Vec<String>>::sse_decode(&mut deserializer);
+            let api_output_dir = <String>::sse_decode(&mut deserializer);
+            let api_options = <crate::api::ConvertOptions>::sse_decode(&mut deserializer);
+            let api_sink = <StreamSink<
+                crate::api::ConvertProgress,
+                flutter_rust_bridge::for_generated::SseCodec,
+            >>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, ()>((move || {
+                    let output_ok =
+                        Result::<_, ()>::Ok(crate::api::combine_images_to_pdf_with_progress(
+                            api_input_paths,
+                            api_output_dir,
+                            api_options,
+                            api_sink,
+                        ))?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__convert_directory_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "convert_directory",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_input_dir = <String>::sse_decode(&mut deserializer);
+            let api_output_dir = <String>::sse_decode(&mut deserializer);
+            let api_options = <crate::api::ConvertOptions>::sse_decode(&mut deserializer);
+            let api_filter = <crate::api::DirectoryFilter>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, ()>((move || {
+                    let output_ok = Result::<_, ()>::Ok(crate::api::convert_directory(
+                        api_input_dir,
+                        api_output_dir,
+                        api_options,
+                        api_filter,
+                    ))?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__convert_directory_with_progress_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "convert_directory_with_progress",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_input_dir = <String>::sse_decode(&mut deserializer);
+            let api_output_dir = <String>::sse_decode(&mut deserializer);
+            let api_options = <crate::api::ConvertOptions>::sse_decode(&mut deserializer);
+            let api_filter = <crate::api::DirectoryFilter>::sse_decode(&mut deserializer);
+            let api_sink = <StreamSink<
+                crate::api::ConvertProgress,
+                flutter_rust_bridge::for_generated::SseCodec,
+            >>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, ()>((move || {
+                    let output_ok =
+                        Result::<_, ()>::Ok(crate::api::convert_directory_with_progress(
+                            api_input_dir,
+                            api_output_dir,
+                            api_options,
+                            api_filter,
+                            api_sink,
+                        ))?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__convert_error_details_default_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "convert_error_details_default",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, ()>((move || {
+                    let output_ok =
+                        Result::<_, ()>::Ok(crate::api::ConvertErrorDetails::default())?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
 fn wire__crate__api__convert_file_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
@@ -84,7 +323,7 @@ fn wire__crate__api__convert_file_impl(
         },
     )
 }
-fn wire__crate__api__convert_files_impl(
+fn wire__crate__api__convert_file_with_progress_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -92,7 +331,7 @@ fn wire__crate__api__convert_files_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "convert_files",
+            debug_name: "convert_file_with_progress",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -106,16 +345,21 @@ fn wire__crate__api__convert_files_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_input_paths = <Vec<String>>::sse_decode(&mut deserializer);
+            let api_input_path = <String>::sse_decode(&mut deserializer);
             let api_output_dir = <String>::sse_decode(&mut deserializer);
             let api_options = <crate::api::ConvertOptions>::sse_decode(&mut deserializer);
+            let api_sink = <StreamSink<
+                crate::api::ConvertProgress,
+                flutter_rust_bridge::for_generated::SseCodec,
+            >>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| {
                 transform_result_sse::<_, ()>((move || {
-                    let output_ok = Result::<_, ()>::Ok(crate::api::convert_files(
-                        api_input_paths,
+                    let output_ok = Result::<_, ()>::Ok(crate::api::convert_file_with_progress(
+                        api_input_path,
                         api_output_dir,
                         api_options,
+                        api_sink,
                     ))?;
                     Ok(output_ok)
                 })())
@@ -123,7 +367,7 @@ fn wire__crate__api__convert_files_impl(
         },
     )
 }
-fn wire__crate__api__detect_file_type_impl(
+fn wire__crate__api__convert_files_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -131,7 +375,7 @@ fn wire__crate__api__detect_file_type_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "detect_file_type",
+            debug_name: "convert_files",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -145,19 +389,24 @@ fn wire__crate__api__detect_file_type_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_file_path = <String>::sse_decode(&mut deserializer);
+            let api_input_paths = <Vec<String>>::sse_decode(&mut deserializer);
+            let api_output_dir = <String>::sse_decode(&mut deserializer);
+            let api_options = <crate::api::ConvertOptions>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| {
                 transform_result_sse::<_, ()>((move || {
-                    let output_ok =
-                        Result::<_, ()>::Ok(crate::api::detect_file_type(api_file_path))?;
+                    let output_ok = Result::<_, ()>::Ok(crate::api::convert_files(
+                        api_input_paths,
+                        api_output_dir,
+                        api_options,
+                    ))?;
                     Ok(output_ok)
                 })())
             }
         },
     )
 }
-fn wire__crate__api__get_supported_output_formats_impl(
+fn wire__crate__api__convert_files_with_progress_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -165,7 +414,7 @@ fn wire__crate__api__get_supported_output_formats_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "get_supported_output_formats",
+            debug_name: "convert_files_with_progress",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -179,12 +428,21 @@ fn wire__crate__api__get_supported_output_formats_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_file_type = <crate::api::FileType>::sse_decode(&mut deserializer);
+            let api_input_paths = <Vec<String>>::sse_decode(&mut deserializer);
+            let api_output_dir = <String>::sse_decode(&mut deserializer);
+            let api_options = <crate::api::ConvertOptions>::sse_decode(&mut deserializer);
+            let api_sink = <StreamSink<
+                crate::api::ConvertProgress,
+                flutter_rust_bridge::for_generated::SseCodec,
+            >>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| {
                 transform_result_sse::<_, ()>((move || {
-                    let output_ok = Result::<_, ()>::Ok(crate::api::get_supported_output_formats(
-                        api_file_type,
+                    let output_ok = Result::<_, ()>::Ok(crate::api::convert_files_with_progress(
+                        api_input_paths,
+                        api_output_dir,
+                        api_options,
+                        api_sink,
                     ))?;
                     Ok(output_ok)
                 })())
@@ -192,7 +450,7 @@ fn wire__crate__api__get_supported_output_formats_impl(
         },
     )
 }
-fn wire__crate__api__get_supported_output_formats_for_file_impl(
+fn wire__crate__api__convert_options_default_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -200,7 +458,7 @@ fn wire__crate__api__get_supported_output_formats_for_file_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "get_supported_output_formats_for_file",
+            debug_name: "convert_options_default",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -214,20 +472,17 @@ fn wire__crate__api__get_supported_output_formats_for_file_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_file_path = <String>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| {
                 transform_result_sse::<_, ()>((move || {
-                    let output_ok = Result::<_, ()>::Ok(
-                        crate::api::get_supported_output_formats_for_file(api_file_path),
-                    )?;
+                    let output_ok = Result::<_, ()>::Ok(crate::api::ConvertOptions::default())?;
                     Ok(output_ok)
                 })())
             }
         },
     )
 }
-fn wire__crate__api__open_folder_impl(
+fn wire__crate__api__create_contact_sheet_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -235,7 +490,7 @@ fn wire__crate__api__open_folder_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "open_folder",
+            debug_name: "create_contact_sheet",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -249,401 +504,2620 @@ fn wire__crate__api__open_folder_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_folder_path = <String>::sse_decode(&mut deserializer);
+            let api_input_paths = <Vec<String>>::sse_decode(&mut deserializer);
+            let api_output_dir = <String>::sse_decode(&mut deserializer);
+            let api_options = <crate::api::ConvertOptions>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| {
                 transform_result_sse::<_, ()>((move || {
-                    let output_ok = Result::<_, ()>::Ok(crate::api::open_folder(api_folder_path))?;
+                    let output_ok = Result::<_, ()>::Ok(crate::api::create_contact_sheet(
+                        api_input_paths,
+                        api_output_dir,
+                        api_options,
+                    ))?;
                     Ok(output_ok)
                 })())
             }
         },
     )
 }
-
-// Section: dart2rust
-
-impl SseDecode for String {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut inner = <Vec<u8>>::sse_decode(deserializer);
-        return String::from_utf8(inner).unwrap();
-    }
-}
-
+fn wire__crate__api__create_contact_sheet_with_progress_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "create_contact_sheet_with_progress",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_input_paths = <Vec<String>>::sse_decode(&mut deserializer);
+            let api_output_dir = <String>::sse_decode(&mut deserializer);
+            let api_options = <crate::api::ConvertOptions>::sse_decode(&mut deserializer);
+            let api_sink = <StreamSink<
+                crate::api::ConvertProgress,
+                flutter_rust_bridge::for_generated::SseCodec,
+            >>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, ()>((move || {
+                    let output_ok =
+                        Result::<_, ()>::Ok(crate::api::create_contact_sheet_with_progress(
+                            api_input_paths,
+                            api_output_dir,
+                            api_options,
+                            api_sink,
+                        ))?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__detect_file_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "detect_file",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_file_path = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, ()>((move || {
+                    let output_ok = Result::<_, ()>::Ok(crate::api::detect_file(api_file_path))?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__detect_file_type_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "detect_file_type",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_file_path = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, ()>((move || {
+                    let output_ok =
+                        Result::<_, ()>::Ok(crate::api::detect_file_type(api_file_path))?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__directory_filter_default_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "directory_filter_default",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, ()>((move || {
+                    let output_ok = Result::<_, ()>::Ok(crate::api::DirectoryFilter::default())?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__get_supported_output_formats_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "get_supported_output_formats",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_file_type = <crate::api::FileType>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, ()>((move || {
+                    let output_ok = Result::<_, ()>::Ok(crate::api::get_supported_output_formats(
+                        api_file_type,
+                    ))?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__get_supported_output_formats_for_file_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "get_supported_output_formats_for_file",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_file_path = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, ()>((move || {
+                    let output_ok = Result::<_, ()>::Ok(
+                        crate::api::get_supported_output_formats_for_file(api_file_path),
+                    )?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__icc_profile_mode_default_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "icc_profile_mode_default",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, ()>((move || {
+                    let output_ok = Result::<_, ()>::Ok(crate::api::IccProfileMode::default())?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__metadata_mode_default_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "metadata_mode_default",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, ()>((move || {
+                    let output_ok = Result::<_, ()>::Ok(crate::api::MetadataMode::default())?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__on_conflict_default_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "on_conflict_default",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, ()>((move || {
+                    let output_ok = Result::<_, ()>::Ok(crate::api::OnConflict::default())?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__open_folder_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "open_folder",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_folder_path = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, ()>((move || {
+                    let output_ok = Result::<_, ()>::Ok(crate::api::open_folder(api_folder_path))?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__pdf_page_size_default_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "pdf_page_size_default",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, ()>((move || {
+                    let output_ok = Result::<_, ()>::Ok(crate::api::PdfPageSize::default())?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__png_filter_default_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "png_filter_default",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, ()>((move || {
+                    let output_ok = Result::<_, ()>::Ok(crate::api::PngFilter::default())?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__resize_filter_default_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "resize_filter_default",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, ()>((move || {
+                    let output_ok = Result::<_, ()>::Ok(crate::api::ResizeFilter::default())?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__resize_fit_default_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "resize_fit_default",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, ()>((move || {
+                    let output_ok = Result::<_, ()>::Ok(crate::api::ResizeFit::default())?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__tiff_compression_default_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "tiff_compression_default",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, ()>((move || {
+                    let output_ok = Result::<_, ()>::Ok(crate::api::TiffCompression::default())?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__watermark_position_default_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "watermark_position_default",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, ()>((move || {
+                    let output_ok = Result::<_, ()>::Ok(crate::api::WatermarkPosition::default())?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+
+// Section: dart2rust
+
+impl SseDecode for flutter_rust_bridge::for_generated::anyhow::Error {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <String>::sse_decode(deserializer);
+        return flutter_rust_bridge::for_generated::anyhow::anyhow!("{}", inner);
+    }
+}
+
+impl SseDecode
+    for StreamSink<crate::api::ConvertProgress, flutter_rust_bridge::for_generated::SseCodec>
+{
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <String>::sse_decode(deserializer);
+        return StreamSink::deserialize(inner);
+    }
+}
+
+impl SseDecode for String {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <Vec<u8>>::sse_decode(deserializer);
+        return String::from_utf8(inner).unwrap();
+    }
+}
+
 impl SseDecode for bool {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        deserializer.cursor.read_u8().unwrap() != 0
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        deserializer.cursor.read_u8().unwrap() != 0
+    }
+}
+
+impl SseDecode for crate::api::ConvertErrorCode {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <i32>::sse_decode(deserializer);
+        return match inner {
+            0 => crate::api::ConvertErrorCode::ToolMissing,
+            1 => crate::api::ConvertErrorCode::UnsupportedPair,
+            2 => crate::api::ConvertErrorCode::UnsupportedFileType,
+            3 => crate::api::ConvertErrorCode::Decode,
+            4 => crate::api::ConvertErrorCode::Encode,
+            5 => crate::api::ConvertErrorCode::Io,
+            6 => crate::api::ConvertErrorCode::ExternalToolFailed,
+            7 => crate::api::ConvertErrorCode::Internal,
+            8 => crate::api::ConvertErrorCode::Cancelled,
+            9 => crate::api::ConvertErrorCode::OutputExists,
+            10 => crate::api::ConvertErrorCode::InvalidOption,
+            _ => unreachable!("Invalid variant for ConvertErrorCode: {}", inner),
+        };
+    }
+}
+
+impl SseDecode for crate::api::ConvertErrorDetails {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_tool = <Option<String>>::sse_decode(deserializer);
+        let mut var_from = <Option<String>>::sse_decode(deserializer);
+        let mut var_to = <Option<String>>::sse_decode(deserializer);
+        let mut var_exitCode = <Option<i32>>::sse_decode(deserializer);
+        let mut var_stderr = <Option<String>>::sse_decode(deserializer);
+        return crate::api::ConvertErrorDetails {
+            tool: var_tool,
+            from: var_from,
+            to: var_to,
+            exit_code: var_exitCode,
+            stderr: var_stderr,
+        };
+    }
+}
+
+impl SseDecode for crate::api::ConvertOptions {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_outputFormat = <String>::sse_decode(deserializer);
+        let mut var_imageQuality = <Option<i32>>::sse_decode(deserializer);
+        let mut var_audioQuality = <Option<i32>>::sse_decode(deserializer);
+        let mut var_audioBitrate = <Option<i32>>::sse_decode(deserializer);
+        let mut var_audioSampleRate = <Option<i32>>::sse_decode(deserializer);
+        let mut var_videoCrf = <Option<i32>>::sse_decode(deserializer);
+        let mut var_videoBitrate = <Option<i32>>::sse_decode(deserializer);
+        let mut var_videoWidth = <Option<i32>>::sse_decode(deserializer);
+        let mut var_videoHeight = <Option<i32>>::sse_decode(deserializer);
+        let mut var_ffmpegPath = <Option<String>>::sse_decode(deserializer);
+        let mut var_taskId = <Option<String>>::sse_decode(deserializer);
+        let mut var_maxParallel = <Option<i32>>::sse_decode(deserializer);
+        let mut var_maxParallelExternal = <Option<i32>>::sse_decode(deserializer);
+        let mut var_onConflict = <Option<crate::api::OnConflict>>::sse_decode(deserializer);
+        let mut var_imageWidth = <Option<i32>>::sse_decode(deserializer);
+        let mut var_imageHeight = <Option<i32>>::sse_decode(deserializer);
+        let mut var_resizeFit = <Option<crate::api::ResizeFit>>::sse_decode(deserializer);
+        let mut var_resizeFilter = <Option<crate::api::ResizeFilter>>::sse_decode(deserializer);
+        let mut var_crop = <Option<crate::api::CropRect>>::sse_decode(deserializer);
+        let mut var_rotate = <Option<i32>>::sse_decode(deserializer);
+        let mut var_flip = <Option<crate::api::Flip>>::sse_decode(deserializer);
+        let mut var_metadata = <Option<crate::api::MetadataMode>>::sse_decode(deserializer);
+        let mut var_svgDpi = <Option<i32>>::sse_decode(deserializer);
+        let mut var_background = <Option<String>>::sse_decode(deserializer);
+        let mut var_svgFontFamily = <Option<String>>::sse_decode(deserializer);
+        let mut var_frame = <Option<i32>>::sse_decode(deserializer);
+        let mut var_extractFrames = <Option<bool>>::sse_decode(deserializer);
+        let mut var_icoSizes = <Option<Vec<i32>>>::sse_decode(deserializer);
+        let mut var_icoSize = <Option<i32>>::sse_decode(deserializer);
+        let mut var_webpLossless = <Option<bool>>::sse_decode(deserializer);
+        let mut var_webpAlphaQuality = <Option<i32>>::sse_decode(deserializer);
+        let mut var_pngCompression = <Option<i32>>::sse_decode(deserializer);
+        let mut var_pngFilter = <Option<crate::api::PngFilter>>::sse_decode(deserializer);
+        let mut var_pngColors = <Option<i32>>::sse_decode(deserializer);
+        let mut var_pngDither = <Option<bool>>::sse_decode(deserializer);
+        let mut var_pngOptimize = <Option<bool>>::sse_decode(deserializer);
+        let mut var_avifSpeed = <Option<i32>>::sse_decode(deserializer);
+        let mut var_tiffCompression =
+            <Option<crate::api::TiffCompression>>::sse_decode(deserializer);
+        let mut var_targetSizeBytes = <Option<i32>>::sse_decode(deserializer);
+        let mut var_targetSizeDownscale = <Option<bool>>::sse_decode(deserializer);
+        let mut var_pdfPageSize = <Option<crate::api::PdfPageSize>>::sse_decode(deserializer);
+        let mut var_pdfMarginMm = <Option<i32>>::sse_decode(deserializer);
+        let mut var_pdfFit = <Option<crate::api::ResizeFit>>::sse_decode(deserializer);
+        let mut var_pdfJpegQuality = <Option<i32>>::sse_decode(deserializer);
+        let mut var_watermarkText = <Option<String>>::sse_decode(deserializer);
+        let mut var_watermarkImage = <Option<String>>::sse_decode(deserializer);
+        let mut var_watermarkFontFamily = <Option<String>>::sse_decode(deserializer);
+        let mut var_watermarkFontSize = <Option<i32>>::sse_decode(deserializer);
+        let mut var_watermarkImageWidth = <Option<i32>>::sse_decode(deserializer);
+        let mut var_watermarkColor = <Option<String>>::sse_decode(deserializer);
+        let mut var_watermarkOpacity = <Option<i32>>::sse_decode(deserializer);
+        let mut var_watermarkPosition =
+            <Option<crate::api::WatermarkPosition>>::sse_decode(deserializer);
+        let mut var_watermarkMargin = <Option<i32>>::sse_decode(deserializer);
+        let mut var_watermarkTile = <Option<bool>>::sse_decode(deserializer);
+        let mut var_grayscale = <Option<bool>>::sse_decode(deserializer);
+        let mut var_brightness = <Option<i32>>::sse_decode(deserializer);
+        let mut var_contrast = <Option<i32>>::sse_decode(deserializer);
+        let mut var_hueRotate = <Option<i32>>::sse_decode(deserializer);
+        let mut var_gamma = <Option<f64>>::sse_decode(deserializer);
+        let mut var_invert = <Option<bool>>::sse_decode(deserializer);
+        let mut var_blur = <Option<f64>>::sse_decode(deserializer);
+        let mut var_sharpen = <Option<f64>>::sse_decode(deserializer);
+        let mut var_sharpenThreshold = <Option<i32>>::sse_decode(deserializer);
+        let mut var_sheetColumns = <Option<i32>>::sse_decode(deserializer);
+        let mut var_sheetCellWidth = <Option<i32>>::sse_decode(deserializer);
+        let mut var_sheetCellHeight = <Option<i32>>::sse_decode(deserializer);
+        let mut var_sheetSpacing = <Option<i32>>::sse_decode(deserializer);
+        let mut var_sheetBackground = <Option<String>>::sse_decode(deserializer);
+        let mut var_sheetCaptions = <Option<bool>>::sse_decode(deserializer);
+        let mut var_iccProfile = <Option<crate::api::IccProfileMode>>::sse_decode(deserializer);
+        return crate::api::ConvertOptions {
+            output_format: var_outputFormat,
+            image_quality: var_imageQuality,
+            audio_quality: var_audioQuality,
+            audio_bitrate: var_audioBitrate,
+            audio_sample_rate: var_audioSampleRate,
+            video_crf: var_videoCrf,
+            video_bitrate: var_videoBitrate,
+            video_width: var_videoWidth,
+            video_height: var_videoHeight,
+            ffmpeg_path: var_ffmpegPath,
+            task_id: var_taskId,
+            max_parallel: var_maxParallel,
+            max_parallel_external: var_maxParallelExternal,
+            on_conflict: var_onConflict,
+            image_width: var_imageWidth,
+            image_height: var_imageHeight,
+            resize_fit: var_resizeFit,
+            resize_filter: var_resizeFilter,
+            crop: var_crop,
+            rotate: var_rotate,
+            flip: var_flip,
+            metadata: var_metadata,
+            svg_dpi: var_svgDpi,
+            background: var_background,
+            svg_font_family: var_svgFontFamily,
+            frame: var_frame,
+            extract_frames: var_extractFrames,
+            ico_sizes: var_icoSizes,
+            ico_size: var_icoSize,
+            webp_lossless: var_webpLossless,
+            webp_alpha_quality: var_webpAlphaQuality,
+            png_compression: var_pngCompression,
+            png_filter: var_pngFilter,
+            png_colors: var_pngColors,
+            png_dither: var_pngDither,
+            png_optimize: var_pngOptimize,
+            avif_speed: var_avifSpeed,
+            tiff_compression: var_tiffCompression,
+            target_size_bytes: var_targetSizeBytes,
+            target_size_downscale: var_targetSizeDownscale,
+            pdf_page_size: var_pdfPageSize,
+            pdf_margin_mm: var_pdfMarginMm,
+            pdf_fit: var_pdfFit,
+            pdf_jpeg_quality: var_pdfJpegQuality,
+            watermark_text: var_watermarkText,
+            watermark_image: var_watermarkImage,
+            watermark_font_family: var_watermarkFontFamily,
+            watermark_font_size: var_watermarkFontSize,
+            watermark_image_width: var_watermarkImageWidth,
+            watermark_color: var_watermarkColor,
+            watermark_opacity: var_watermarkOpacity,
+            watermark_position: var_watermarkPosition,
+            watermark_margin: var_watermarkMargin,
+            watermark_tile: var_watermarkTile,
+            grayscale: var_grayscale,
+            brightness: var_brightness,
+            contrast: var_contrast,
+            hue_rotate: var_hueRotate,
+            gamma: var_gamma,
+            invert: var_invert,
+            blur: var_blur,
+            sharpen: var_sharpen,
+            sharpen_threshold: var_sharpenThreshold,
+            sheet_columns: var_sheetColumns,
+            sheet_cell_width: var_sheetCellWidth,
+            sheet_cell_height: var_sheetCellHeight,
+            sheet_spacing: var_sheetSpacing,
+            sheet_background: var_sheetBackground,
+            sheet_captions: var_sheetCaptions,
+            icc_profile: var_iccProfile,
+        };
+    }
+}
+
+impl SseDecode for crate::api::ConvertProgress {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_taskId = <String>::sse_decode(deserializer);
+        let mut var_inputPath = <String>::sse_decode(deserializer);
+        let mut var_fileIndex = <i32>::sse_decode(deserializer);
+        let mut var_fileCount = <i32>::sse_decode(deserializer);
+        let mut var_progress = <i32>::sse_decode(deserializer);
+        let mut var_status = <String>::sse_decode(deserializer);
+        return crate::api::ConvertProgress {
+            task_id: var_taskId,
+            input_path: var_inputPath,
+            file_index: var_fileIndex,
+            file_count: var_fileCount,
+            progress: var_progress,
+            status: var_status,
+        };
+    }
+}
+
+impl SseDecode for crate::api::ConvertResult {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_success = <bool>::sse_decode(deserializer);
+        let mut var_outputPath = <Option<String>>::sse_decode(deserializer);
+        let mut var_error = <Option<String>>::sse_decode(deserializer);
+        let mut var_errorCode = <Option<crate::api::ConvertErrorCode>>::sse_decode(deserializer);
+        let mut var_errorDetails =
+            <Option<crate::api::ConvertErrorDetails>>::sse_decode(deserializer);
+        let mut var_skipped = <bool>::sse_decode(deserializer);
+        let mut var_route = <Vec<String>>::sse_decode(deserializer);
+        let mut var_outputPaths = <Vec<String>>::sse_decode(deserializer);
+        let mut var_warnings = <Vec<String>>::sse_decode(deserializer);
+        let mut var_imageQuality = <Option<i32>>::sse_decode(deserializer);
+        return crate::api::ConvertResult {
+            success: var_success,
+            output_path: var_outputPath,
+            error: var_error,
+            error_code: var_errorCode,
+            error_details: var_errorDetails,
+            skipped: var_skipped,
+            route: var_route,
+            output_paths: var_outputPaths,
+            warnings: var_warnings,
+            image_quality: var_imageQuality,
+        };
+    }
+}
+
+impl SseDecode for crate::api::CropRect {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_x = <i32>::sse_decode(deserializer);
+        let mut var_y = <i32>::sse_decode(deserializer);
+        let mut var_width = <i32>::sse_decode(deserializer);
+        let mut var_height = <i32>::sse_decode(deserializer);
+        return crate::api::CropRect {
+            x: var_x,
+            y: var_y,
+            width: var_width,
+            height: var_height,
+        };
+    }
+}
+
+impl SseDecode for crate::api::DetectionConfidence {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <i32>::sse_decode(deserializer);
+        return match inner {
+            0 => crate::api::DetectionConfidence::High,
+            1 => crate::api::DetectionConfidence::Medium,
+            2 => crate::api::DetectionConfidence::Low,
+            3 => crate::api::DetectionConfidence::Unknown,
+            _ => unreachable!("Invalid variant for DetectionConfidence: {}", inner),
+        };
+    }
+}
+
+impl SseDecode for crate::api::DirectoryFileResult {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_inputPath = <String>::sse_decode(deserializer);
+        let mut var_relativePath = <String>::sse_decode(deserializer);
+        let mut var_result = <crate::api::ConvertResult>::sse_decode(deserializer);
+        return crate::api::DirectoryFileResult {
+            input_path: var_inputPath,
+            relative_path: var_relativePath,
+            result: var_result,
+        };
+    }
+}
+
+impl SseDecode for crate::api::DirectoryFilter {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_include = <Vec<String>>::sse_decode(deserializer);
+        let mut var_exclude = <Vec<String>>::sse_decode(deserializer);
+        let mut var_fileTypes = <Vec<crate::api::FileType>>::sse_decode(deserializer);
+        return crate::api::DirectoryFilter {
+            include: var_include,
+            exclude: var_exclude,
+            file_types: var_fileTypes,
+        };
+    }
+}
+
+impl SseDecode for f64 {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        deserializer.cursor.read_f64::<NativeEndian>().unwrap()
+    }
+}
+
+impl SseDecode for crate::api::FileDetection {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_fileType = <Option<crate::api::FileType>>::sse_decode(deserializer);
+        let mut var_format = <Option<String>>::sse_decode(deserializer);
+        let mut var_extension_ = <Option<String>>::sse_decode(deserializer);
+        let mut var_confidence = <crate::api::DetectionConfidence>::sse_decode(deserializer);
+        let mut var_extensionMismatch = <bool>::sse_decode(deserializer);
+        return crate::api::FileDetection {
+            file_type: var_fileType,
+            format: var_format,
+            extension: var_extension_,
+            confidence: var_confidence,
+            extension_mismatch: var_extensionMismatch,
+        };
+    }
+}
+
+impl SseDecode for crate::api::FileType {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <i32>::sse_decode(deserializer);
+        return match inner {
+            0 => crate::api::FileType::Image,
+            1 => crate::api::FileType::Document,
+            2 => crate::api::FileType::Audio,
+            3 => crate::api::FileType::Video,
+            4 => crate::api::FileType::Config,
+            _ => unreachable!("Invalid variant for FileType: {}", inner),
+        };
+    }
+}
+
+impl SseDecode for crate::api::Flip {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <i32>::sse_decode(deserializer);
+        return match inner {
+            0 => crate::api::Flip::Horizontal,
+            1 => crate::api::Flip::Vertical,
+            2 => crate::api::Flip::Both,
+            _ => unreachable!("Invalid variant for Flip: {}", inner),
+        };
+    }
+}
+
+impl SseDecode for i32 {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        deserializer.cursor.read_i32::<NativeEndian>().unwrap()
+    }
+}
+
+impl SseDecode for crate::api::IccProfileMode {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <i32>::sse_decode(deserializer);
+        return match inner {
+            0 => crate::api::IccProfileMode::Preserve,
+            1 => crate::api::IccProfileMode::ConvertToSrgb,
+            _ => unreachable!("Invalid variant for IccProfileMode: {}", inner),
+        };
+    }
+}
+
+impl SseDecode for Vec<String> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<String>::sse_decode(deserializer));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::api::ConvertResult> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::api::ConvertResult>::sse_decode(deserializer));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::api::DirectoryFileResult> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::api::DirectoryFileResult>::sse_decode(deserializer));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::api::FileType> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::api::FileType>::sse_decode(deserializer));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<i32> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<i32>::sse_decode(deserializer));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<u8> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<u8>::sse_decode(deserializer));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for crate::api::MetadataMode {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <i32>::sse_decode(deserializer);
+        return match inner {
+            0 => crate::api::MetadataMode::StripAll,
+            1 => crate::api::MetadataMode::StripGps,
+            2 => crate::api::MetadataMode::Preserve,
+            _ => unreachable!("Invalid variant for MetadataMode: {}", inner),
+        };
+    }
+}
+
+impl SseDecode for crate::api::OnConflict {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <i32>::sse_decode(deserializer);
+        return match inner {
+            0 => crate::api::OnConflict::Overwrite,
+            1 => crate::api::OnConflict::Skip,
+            2 => crate::api::OnConflict::Rename,
+            3 => crate::api::OnConflict::Fail,
+            _ => unreachable!("Invalid variant for OnConflict: {}", inner),
+        };
+    }
+}
+
+impl SseDecode for Option<String> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<String>::sse_decode(deserializer));
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for Option<bool> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<bool>::sse_decode(deserializer));
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for Option<crate::api::ConvertErrorCode> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<crate::api::ConvertErrorCode>::sse_decode(deserializer));
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for Option<crate::api::ConvertErrorDetails> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<crate::api::ConvertErrorDetails>::sse_decode(deserializer));
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for Option<crate::api::CropRect> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<crate::api::CropRect>::sse_decode(deserializer));
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for Option<f64> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<f64>::sse_decode(deserializer));
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for Option<crate::api::FileType> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<crate::api::FileType>::sse_decode(deserializer));
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for Option<crate::api::Flip> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<crate::api::Flip>::sse_decode(deserializer));
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for Option<i32> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<i32>::sse_decode(deserializer));
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for Option<crate::api::IccProfileMode> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<crate::api::IccProfileMode>::sse_decode(deserializer));
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for Option<crate::api::MetadataMode> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<crate::api::MetadataMode>::sse_decode(deserializer));
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for Option<crate::api::OnConflict> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<crate::api::OnConflict>::sse_decode(deserializer));
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for Option<crate::api::PdfPageSize> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<crate::api::PdfPageSize>::sse_decode(deserializer));
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for Option<crate::api::PngFilter> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<crate::api::PngFilter>::sse_decode(deserializer));
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for Option<crate::api::ResizeFilter> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<crate::api::ResizeFilter>::sse_decode(deserializer));
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for Option<crate::api::ResizeFit> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<crate::api::ResizeFit>::sse_decode(deserializer));
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for Option<crate::api::TiffCompression> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<crate::api::TiffCompression>::sse_decode(deserializer));
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for Option<crate::api::WatermarkPosition> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<crate::api::WatermarkPosition>::sse_decode(deserializer));
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for Option<Vec<i32>> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<Vec<i32>>::sse_decode(deserializer));
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for crate::api::PdfPageSize {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <i32>::sse_decode(deserializer);
+        return match inner {
+            0 => crate::api::PdfPageSize::Image,
+            1 => crate::api::PdfPageSize::A3,
+            2 => crate::api::PdfPageSize::A4,
+            3 => crate::api::PdfPageSize::A5,
+            4 => crate::api::PdfPageSize::Letter,
+            5 => crate::api::PdfPageSize::Legal,
+            _ => unreachable!("Invalid variant for PdfPageSize: {}", inner),
+        };
+    }
+}
+
+impl SseDecode for crate::api::PngFilter {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <i32>::sse_decode(deserializer);
+        return match inner {
+            0 => crate::api::PngFilter::NoFilter,
+            1 => crate::api::PngFilter::Sub,
+            2 => crate::api::PngFilter::Up,
+            3 => crate::api::PngFilter::Average,
+            4 => crate::api::PngFilter::Paeth,
+            5 => crate::api::PngFilter::Adaptive,
+            _ => unreachable!("Invalid variant for PngFilter: {}", inner),
+        };
+    }
+}
+
+impl SseDecode for crate::api::ResizeFilter {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <i32>::sse_decode(deserializer);
+        return match inner {
+            0 => crate::api::ResizeFilter::Nearest,
+            1 => crate::api::ResizeFilter::Triangle,
+            2 => crate::api::ResizeFilter::CatmullRom,
+            3 => crate::api::ResizeFilter::Gaussian,
+            4 => crate::api::ResizeFilter::Lanczos3,
+            _ => unreachable!("Invalid variant for ResizeFilter: {}", inner),
+        };
+    }
+}
+
+impl SseDecode for crate::api::ResizeFit {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <i32>::sse_decode(deserializer);
+        return match inner {
+            0 => crate::api::ResizeFit::Contain,
+            1 => crate::api::ResizeFit::Cover,
+            2 => crate::api::ResizeFit::Exact,
+            3 => crate::api::ResizeFit::MaxDimension,
+            _ => unreachable!("Invalid variant for ResizeFit: {}", inner),
+        };
+    }
+}
+
+impl SseDecode for crate::api::TiffCompression {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <i32>::sse_decode(deserializer);
+        return match inner {
+            0 => crate::api::TiffCompression::Uncompressed,
+            1 => crate::api::TiffCompression::Lzw,
+            2 => crate::api::TiffCompression::Deflate,
+            3 => crate::api::TiffCompression::PackBits,
+            _ => unreachable!("Invalid variant for TiffCompression: {}", inner),
+        };
+    }
+}
+
+impl SseDecode for u8 {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        deserializer.cursor.read_u8().unwrap()
+    }
+}
+
+impl SseDecode for () {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {}
+}
+
+impl SseDecode for crate::api::WatermarkPosition {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <i32>::sse_decode(deserializer);
+        return match inner {
+            0 => crate::api::WatermarkPosition::TopLeft,
+            1 => crate::api::WatermarkPosition::Top,
+            2 => crate::api::WatermarkPosition::TopRight,
+            3 => crate::api::WatermarkPosition::Left,
+            4 => crate::api::WatermarkPosition::Center,
+            5 => crate::api::WatermarkPosition::Right,
+            6 => crate::api::WatermarkPosition::BottomLeft,
+            7 => crate::api::WatermarkPosition::Bottom,
+            8 => crate::api::WatermarkPosition::BottomRight,
+            _ => unreachable!("Invalid variant for WatermarkPosition: {}", inner),
+        };
+    }
+}
+
+fn pde_ffi_dispatcher_primary_impl(
+    func_id: i32,
+    port: flutter_rust_bridge::for_generated::MessagePort,
+    ptr: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len: i32,
+    data_len: i32,
+) {
+    // Codec=Pde (Serialization + dispatch), see doc to use other codecs
+    match func_id {
+        1 => wire__crate__api__cancel_conversion_impl(port, ptr, rust_vec_len, data_len),
+        2 => wire__crate__api__combine_images_to_pdf_impl(port, ptr, rust_vec_len, data_len),
+        3 => wire__crate__api__combine_images_to_pdf_with_progress_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        4 => wire__crate__api__convert_directory_impl(port, ptr, rust_vec_len, data_len),
+        5 => wire__crate__api__convert_directory_with_progress_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        6 => {
+            wire__crate__api__convert_error_details_default_impl(port, ptr, rust_vec_len, data_len)
+        }
+        7 => wire__crate__api__convert_file_impl(port, ptr, rust_vec_len, data_len),
+        8 => wire__crate__api__convert_file_with_progress_impl(port, ptr, rust_vec_len, data_len),
+        9 => wire__crate__api__convert_files_impl(port, ptr, rust_vec_len, data_len),
+        10 => wire__crate__api__convert_files_with_progress_impl(port, ptr, rust_vec_len, data_len),
+        11 => wire__crate__api__convert_options_default_impl(port, ptr, rust_vec_len, data_len),
+        12 => wire__crate__api__create_contact_sheet_impl(port, ptr, rust_vec_len, data_len),
+        13 => wire__crate__api__create_contact_sheet_with_progress_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        14 => wire__crate__api__detect_file_impl(port, ptr, rust_vec_len, data_len),
+        15 => wire__crate__api__detect_file_type_impl(port, ptr, rust_vec_len, data_len),
+        16 => wire__crate__api__directory_filter_default_impl(port, ptr, rust_vec_len, data_len),
+        17 => {
+            wire__crate__api__get_supported_output_formats_impl(port, ptr, rust_vec_len, data_len)
+        }
+        18 => wire__crate__api__get_supported_output_formats_for_file_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        19 => wire__crate__api__icc_profile_mode_default_impl(port, ptr, rust_vec_len, data_len),
+        20 => wire__crate__api__metadata_mode_default_impl(port, ptr, rust_vec_len, data_len),
+        21 => wire__crate__api__on_conflict_default_impl(port, ptr, rust_vec_len, data_len),
+        22 => wire__crate__api__open_folder_impl(port, ptr, rust_vec_len, data_len),
+        23 => wire__crate__api__pdf_page_size_default_impl(port, ptr, rust_vec_len, data_len),
+        24 => wire__crate__api__png_filter_default_impl(port, ptr, rust_vec_len, data_len),
+        25 => wire__crate__api__resize_filter_default_impl(port, ptr, rust_vec_len, data_len),
+        26 => wire__crate__api__resize_fit_default_impl(port, ptr, rust_vec_len, data_len),
+        27 => wire__crate__api__tiff_compression_default_impl(port, ptr, rust_vec_len, data_len),
+        28 => wire__crate__api__watermark_position_default_impl(port, ptr, rust_vec_len, data_len),
+        _ => unreachable!(),
+    }
+}
+
+fn pde_ffi_dispatcher_sync_impl(
+    func_id: i32,
+    ptr: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len: i32,
+    data_len: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    // Codec=Pde (Serialization + dispatch), see doc to use other codecs
+    match func_id {
+        _ => unreachable!(),
+    }
+}
+
+// Section: rust2dart
+
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::ConvertErrorCode {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        match self {
+            Self::ToolMissing => 0.into_dart(),
+            Self::UnsupportedPair => 1.into_dart(),
+            Self::UnsupportedFileType => 2.into_dart(),
+            Self::Decode => 3.into_dart(),
+            Self::Encode => 4.into_dart(),
+            Self::Io => 5.into_dart(),
+            Self::ExternalToolFailed => 6.into_dart(),
+            Self::Internal => 7.into_dart(),
+            Self::Cancelled => 8.into_dart(),
+            Self::OutputExists => 9.into_dart(),
+            Self::InvalidOption => 10.into_dart(),
+            _ => unreachable!(),
+        }
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::api::ConvertErrorCode {}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::ConvertErrorCode>
+    for crate::api::ConvertErrorCode
+{
+    fn into_into_dart(self) -> crate::api::ConvertErrorCode {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::ConvertErrorDetails {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.tool.into_into_dart().into_dart(),
+            self.from.into_into_dart().into_dart(),
+            self.to.into_into_dart().into_dart(),
+            self.exit_code.into_into_dart().into_dart(),
+            self.stderr.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::ConvertErrorDetails
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::ConvertErrorDetails>
+    for crate::api::ConvertErrorDetails
+{
+    fn into_into_dart(self) -> crate::api::ConvertErrorDetails {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::ConvertOptions {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.output_format.into_into_dart().into_dart(),
+            self.image_quality.into_into_dart().into_dart(),
+            self.audio_quality.into_into_dart().into_dart(),
+            self.audio_bitrate.into_into_dart().into_dart(),
+            self.audio_sample_rate.into_into_dart().into_dart(),
+            self.video_crf.into_into_dart().into_dart(),
+            self.video_bitrate.into_into_dart().into_dart(),
+            self.video_width.into_into_dart().into_dart(),
+            self.video_height.into_into_dart().into_dart(),
+            self.ffmpeg_path.into_into_dart().into_dart(),
+            self.task_id.into_into_dart().into_dart(),
+            self.max_parallel.into_into_dart().into_dart(),
+            self.max_parallel_external.into_into_dart().into_dart(),
+            self.on_conflict.into_into_dart().into_dart(),
+            self.image_width.into_into_dart().into_dart(),
+            self.image_height.into_into_dart().into_dart(),
+            self.resize_fit.into_into_dart().into_dart(),
+            self.resize_filter.into_into_dart().into_dart(),
+            self.crop.into_into_dart().into_dart(),
+            self.rotate.into_into_dart().into_dart(),
+            self.flip.into_into_dart().into_dart(),
+            self.metadata.into_into_dart().into_dart(),
+            self.svg_dpi.into_into_dart().into_dart(),
+            self.background.into_into_dart().into_dart(),
+            self.svg_font_family.into_into_dart().into_dart(),
+            self.frame.into_into_dart().into_dart(),
+            self.extract_frames.into_into_dart().into_dart(),
+            self.ico_sizes.into_into_dart().into_dart(),
+            self.ico_size.into_into_dart().into_dart(),
+            self.webp_lossless.into_into_dart().into_dart(),
+            self.webp_alpha_quality.into_into_dart().into_dart(),
+            self.png_compression.into_into_dart().into_dart(),
+            self.png_filter.into_into_dart().into_dart(),
+            self.png_colors.into_into_dart().into_dart(),
+            self.png_dither.into_into_dart().into_dart(),
+            self.png_optimize.into_into_dart().into_dart(),
+            self.avif_speed.into_into_dart().into_dart(),
+            self.tiff_compression.into_into_dart().into_dart(),
+            self.target_size_bytes.into_into_dart().into_dart(),
+            self.target_size_downscale.into_into_dart().into_dart(),
+            self.pdf_page_size.into_into_dart().into_dart(),
+            self.pdf_margin_mm.into_into_dart().into_dart(),
+            self.pdf_fit.into_into_dart().into_dart(),
+            self.pdf_jpeg_quality.into_into_dart().into_dart(),
+            self.watermark_text.into_into_dart().into_dart(),
+            self.watermark_image.into_into_dart().into_dart(),
+            self.watermark_font_family.into_into_dart().into_dart(),
+            self.watermark_font_size.into_into_dart().into_dart(),
+            self.watermark_image_width.into_into_dart().into_dart(),
+            self.watermark_color.into_into_dart().into_dart(),
+            self.watermark_opacity.into_into_dart().into_dart(),
+            self.watermark_position.into_into_dart().into_dart(),
+            self.watermark_margin.into_into_dart().into_dart(),
+            self.watermark_tile.into_into_dart().into_dart(),
+            self.grayscale.into_into_dart().into_dart(),
+            self.brightness.into_into_dart().into_dart(),
+            self.contrast.into_into_dart().into_dart(),
+            self.hue_rotate.into_into_dart().into_dart(),
+            self.gamma.into_into_dart().into_dart(),
+            self.invert.into_into_dart().into_dart(),
+            self.blur.into_into_dart().into_dart(),
+            self.sharpen.into_into_dart().into_dart(),
+            self.sharpen_threshold.into_into_dart().into_dart(),
+            self.sheet_columns.into_into_dart().into_dart(),
+            self.sheet_cell_width.into_into_dart().into_dart(),
+            self.sheet_cell_height.into_into_dart().into_dart(),
+            self.sheet_spacing.into_into_dart().into_dart(),
+            self.sheet_background.into_into_dart().into_dart(),
+            self.sheet_captions.into_into_dart().into_dart(),
+            self.icc_profile.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::api::ConvertOptions {}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::ConvertOptions> for crate::api::ConvertOptions {
+    fn into_into_dart(self) -> crate::api::ConvertOptions {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::ConvertProgress {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.task_id.into_into_dart().into_dart(),
+            self.input_path.into_into_dart().into_dart(),
+            self.file_index.into_into_dart().into_dart(),
+            self.file_count.into_into_dart().into_dart(),
+            self.progress.into_into_dart().into_dart(),
+            self.status.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::api::ConvertProgress {}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::ConvertProgress>
+    for crate::api::ConvertProgress
+{
+    fn into_into_dart(self) -> crate::api::ConvertProgress {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::ConvertResult {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.success.into_into_dart().into_dart(),
+            self.output_path.into_into_dart().into_dart(),
+            self.error.into_into_dart().into_dart(),
+            self.error_code.into_into_dart().into_dart(),
+            self.error_details.into_into_dart().into_dart(),
+            self.skipped.into_into_dart().into_dart(),
+            self.route.into_into_dart().into_dart(),
+            self.output_paths.into_into_dart().into_dart(),
+            self.warnings.into_into_dart().into_dart(),
+            self.image_quality.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::api::ConvertResult {}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::ConvertResult> for crate::api::ConvertResult {
+    fn into_into_dart(self) -> crate::api::ConvertResult {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::CropRect {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.x.into_into_dart().into_dart(),
+            self.y.into_into_dart().into_dart(),
+            self.width.into_into_dart().into_dart(),
+            self.height.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::api::CropRect {}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::CropRect> for crate::api::CropRect {
+    fn into_into_dart(self) -> crate::api::CropRect {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::DetectionConfidence {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        match self {
+            Self::High => 0.into_dart(),
+            Self::Medium => 1.into_dart(),
+            Self::Low => 2.into_dart(),
+            Self::Unknown => 3.into_dart(),
+            _ => unreachable!(),
+        }
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::DetectionConfidence
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::DetectionConfidence>
+    for crate::api::DetectionConfidence
+{
+    fn into_into_dart(self) -> crate::api::DetectionConfidence {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::DirectoryFileResult {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.input_path.into_into_dart().into_dart(),
+            self.relative_path.into_into_dart().into_dart(),
+            self.result.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::DirectoryFileResult
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::DirectoryFileResult>
+    for crate::api::DirectoryFileResult
+{
+    fn into_into_dart(self) -> crate::api::DirectoryFileResult {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::DirectoryFilter {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.include.into_into_dart().into_dart(),
+            self.exclude.into_into_dart().into_dart(),
+            self.file_types.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::api::DirectoryFilter {}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::DirectoryFilter>
+    for crate::api::DirectoryFilter
+{
+    fn into_into_dart(self) -> crate::api::DirectoryFilter {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::FileDetection {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.file_type.into_into_dart().into_dart(),
+            self.format.into_into_dart().into_dart(),
+            self.extension.into_into_dart().into_dart(),
+            self.confidence.into_into_dart().into_dart(),
+            self.extension_mismatch.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::api::FileDetection {}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::FileDetection> for crate::api::FileDetection {
+    fn into_into_dart(self) -> crate::api::FileDetection {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::FileType {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        match self {
+            Self::Image => 0.into_dart(),
+            Self::Document => 1.into_dart(),
+            Self::Audio => 2.into_dart(),
+            Self::Video => 3.into_dart(),
+            Self::Config => 4.into_dart(),
+            _ => unreachable!(),
+        }
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::api::FileType {}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::FileType> for crate::api::FileType {
+    fn into_into_dart(self) -> crate::api::FileType {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::Flip {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        match self {
+            Self::Horizontal => 0.into_dart(),
+            Self::Vertical => 1.into_dart(),
+            Self::Both => 2.into_dart(),
+            _ => unreachable!(),
+        }
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::api::Flip {}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::Flip> for crate::api::Flip {
+    fn into_into_dart(self) -> crate::api::Flip {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::IccProfileMode {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        match self {
+            Self::Preserve => 0.into_dart(),
+            Self::ConvertToSrgb => 1.into_dart(),
+            _ => unreachable!(),
+        }
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::api::IccProfileMode {}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::IccProfileMode> for crate::api::IccProfileMode {
+    fn into_into_dart(self) -> crate::api::IccProfileMode {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::MetadataMode {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        match self {
+            Self::StripAll => 0.into_dart(),
+            Self::StripGps => 1.into_dart(),
+            Self::Preserve => 2.into_dart(),
+            _ => unreachable!(),
+        }
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::api::MetadataMode {}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::MetadataMode> for crate::api::MetadataMode {
+    fn into_into_dart(self) -> crate::api::MetadataMode {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::OnConflict {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        match self {
+            Self::Overwrite => 0.into_dart(),
+            Self::Skip => 1.into_dart(),
+            Self::Rename => 2.into_dart(),
+            Self::Fail => 3.into_dart(),
+            _ => unreachable!(),
+        }
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::api::OnConflict {}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::OnConflict> for crate::api::OnConflict {
+    fn into_into_dart(self) -> crate::api::OnConflict {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::PdfPageSize {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        match self {
+            Self::Image => 0.into_dart(),
+            Self::A3 => 1.into_dart(),
+            Self::A4 => 2.into_dart(),
+            Self::A5 => 3.into_dart(),
+            Self::Letter => 4.into_dart(),
+            Self::Legal => 5.into_dart(),
+            _ => unreachable!(),
+        }
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::api::PdfPageSize {}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::PdfPageSize> for crate::api::PdfPageSize {
+    fn into_into_dart(self) -> crate::api::PdfPageSize {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::PngFilter {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        match self {
+            Self::NoFilter => 0.into_dart(),
+            Self::Sub => 1.into_dart(),
+            Self::Up => 2.into_dart(),
+            Self::Average => 3.into_dart(),
+            Self::Paeth => 4.into_dart(),
+            Self::Adaptive => 5.into_dart(),
+            _ => unreachable!(),
+        }
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::api::PngFilter {}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::PngFilter> for crate::api::PngFilter {
+    fn into_into_dart(self) -> crate::api::PngFilter {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::ResizeFilter {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        match self {
+            Self::Nearest => 0.into_dart(),
+            Self::Triangle => 1.into_dart(),
+            Self::CatmullRom => 2.into_dart(),
+            Self::Gaussian => 3.into_dart(),
+            Self::Lanczos3 => 4.into_dart(),
+            _ => unreachable!(),
+        }
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::api::ResizeFilter {}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::ResizeFilter> for crate::api::ResizeFilter {
+    fn into_into_dart(self) -> crate::api::ResizeFilter {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::ResizeFit {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        match self {
+            Self::Contain => 0.into_dart(),
+            Self::Cover => 1.into_dart(),
+            Self::Exact => 2.into_dart(),
+            Self::MaxDimension => 3.into_dart(),
+            _ => unreachable!(),
+        }
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::api::ResizeFit {}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::ResizeFit> for crate::api::ResizeFit {
+    fn into_into_dart(self) -> crate::api::ResizeFit {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::TiffCompression {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        match self {
+            Self::Uncompressed => 0.into_dart(),
+            Self::Lzw => 1.into_dart(),
+            Self::Deflate => 2.into_dart(),
+            Self::PackBits => 3.into_dart(),
+            _ => unreachable!(),
+        }
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::api::TiffCompression {}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::TiffCompression>
+    for crate::api::TiffCompression
+{
+    fn into_into_dart(self) -> crate::api::TiffCompression {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::WatermarkPosition {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        match self {
+            Self::TopLeft => 0.into_dart(),
+            Self::Top => 1.into_dart(),
+            Self::TopRight => 2.into_dart(),
+            Self::Left => 3.into_dart(),
+            Self::Center => 4.into_dart(),
+            Self::Right => 5.into_dart(),
+            Self::BottomLeft => 6.into_dart(),
+            Self::Bottom => 7.into_dart(),
+            Self::BottomRight => 8.into_dart(),
+            _ => unreachable!(),
+        }
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::api::WatermarkPosition {}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::WatermarkPosition>
+    for crate::api::WatermarkPosition
+{
+    fn into_into_dart(self) -> crate::api::WatermarkPosition {
+        self
+    }
+}
+
+impl SseEncode for flutter_rust_bridge::for_generated::anyhow::Error {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(format!("{:?}", self), serializer);
+    }
+}
+
+impl SseEncode
+    for StreamSink<crate::api::ConvertProgress, flutter_rust_bridge::for_generated::SseCodec>
+{
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        unimplemented!("")
+    }
+}
+
+impl SseEncode for String {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <Vec<u8>>::sse_encode(self.into_bytes(), serializer);
+    }
+}
+
+impl SseEncode for bool {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        serializer.cursor.write_u8(self as _).unwrap();
+    }
+}
+
+impl SseEncode for crate::api::ConvertErrorCode {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(
+            match self {
+                crate::api::ConvertErrorCode::ToolMissing => 0,
+                crate::api::ConvertErrorCode::UnsupportedPair => 1,
+                crate::api::ConvertErrorCode::UnsupportedFileType => 2,
+                crate::api::ConvertErrorCode::Decode => 3,
+                crate::api::ConvertErrorCode::Encode => 4,
+                crate::api::ConvertErrorCode::Io => 5,
+                crate::api::ConvertErrorCode::ExternalToolFailed => 6,
+                crate::api::ConvertErrorCode::Internal => 7,
+                crate::api::ConvertErrorCode::Cancelled => 8,
+                crate::api::ConvertErrorCode::OutputExists => 9,
+                crate::api::ConvertErrorCode::InvalidOption => 10,
+                _ => {
+                    unimplemented!("");
+                }
+            },
+            serializer,
+        );
+    }
+}
+
+impl SseEncode for crate::api::ConvertErrorDetails {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <Option<String>>::sse_encode(self.tool, serializer);
+        <Option<String>>::sse_encode(self.from, serializer);
+        <Option<String>>::sse_encode(self.to, serializer);
+        <Option<i32>>::sse_encode(self.exit_code, serializer);
+        <Option<String>>::sse_encode(self.stderr, serializer);
     }
 }
 
-impl SseDecode for crate::api::ConvertOptions {
+impl SseEncode for crate::api::ConvertOptions {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut var_outputFormat = <String>::sse_decode(deserializer);
-        let mut var_imageQuality = <Option<i32>>::sse_decode(deserializer);
-        let mut var_audioQuality = <Option<i32>>::sse_decode(deserializer);
-        let mut var_audioBitrate = <Option<i32>>::sse_decode(deserializer);
-        let mut var_audioSampleRate = <Option<i32>>::sse_decode(deserializer);
-        let mut var_videoCrf = <Option<i32>>::sse_decode(deserializer);
-        let mut var_videoBitrate = <Option<i32>>::sse_decode(deserializer);
-        let mut var_videoWidth = <Option<i32>>::sse_decode(deserializer);
-        let mut var_videoHeight = <Option<i32>>::sse_decode(deserializer);
-        let mut var_ffmpegPath = <Option<String>>::sse_decode(deserializer);
-        return crate::api::ConvertOptions {
-            output_format: var_outputFormat,
-            image_quality: var_imageQuality,
-            audio_quality: var_audioQuality,
-            audio_bitrate: var_audioBitrate,
-            audio_sample_rate: var_audioSampleRate,
-            video_crf: var_videoCrf,
-            video_bitrate: var_videoBitrate,
-            video_width: var_videoWidth,
-            video_height: var_videoHeight,
-            ffmpeg_path: var_ffmpegPath,
-        };
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(self.output_format, serializer);
+        <Option<i32>>::sse_encode(self.image_quality, serializer);
+        <Option<i32>>::sse_encode(self.audio_quality, serializer);
+        <Option<i32>>::sse_encode(self.audio_bitrate, serializer);
+        <Option<i32>>::sse_encode(self.audio_sample_rate, serializer);
+        <Option<i32>>::sse_encode(self.video_crf, serializer);
+        <Option<i32>>::sse_encode(self.video_bitrate, serializer);
+        <Option<i32>>::sse_encode(self.video_width, serializer);
+        <Option<i32>>::sse_encode(self.video_height, serializer);
+        <Option<String>>::sse_encode(self.ffmpeg_path, serializer);
+        <Option<String>>::sse_encode(self.task_id, serializer);
+        <Option<i32>>::sse_encode(self.max_parallel, serializer);
+        <Option<i32>>::sse_encode(self.max_parallel_external, serializer);
+        <Option<crate::api::OnConflict>>::sse_encode(self.on_conflict, serializer);
+        <Option<i32>>::sse_encode(self.image_width, serializer);
+        <Option<i32>>::sse_encode(self.image_height, serializer);
+        <Option<crate::api::ResizeFit>>::sse_encode(self.resize_fit, serializer);
+        <Option<crate::api::ResizeFilter>>::sse_encode(self.resize_filter, serializer);
+        <Option<crate::api::CropRect>>::sse_encode(self.crop, serializer);
+        <Option<i32>>::sse_encode(self.rotate, serializer);
+        <Option<crate::api::Flip>>::sse_encode(self.flip, serializer);
+        <Option<crate::api::MetadataMode>>::sse_encode(self.metadata, serializer);
+        <Option<i32>>::sse_encode(self.svg_dpi, serializer);
+        <Option<String>>::sse_encode(self.background, serializer);
+        <Option<String>>::sse_encode(self.svg_font_family, serializer);
+        <Option<i32>>::sse_encode(self.frame, serializer);
+        <Option<bool>>::sse_encode(self.extract_frames, serializer);
+        <Option<Vec<i32>>>::sse_encode(self.ico_sizes, serializer);
+        <Option<i32>>::sse_encode(self.ico_size, serializer);
+        <Option<bool>>::sse_encode(self.webp_lossless, serializer);
+        <Option<i32>>::sse_encode(self.webp_alpha_quality, serializer);
+        <Option<i32>>::sse_encode(self.png_compression, serializer);
+        <Option<crate::api::PngFilter>>::sse_encode(self.png_filter, serializer);
+        <Option<i32>>::sse_encode(self.png_colors, serializer);
+        <Option<bool>>::sse_encode(self.png_dither, serializer);
+        <Option<bool>>::sse_encode(self.png_optimize, serializer);
+        <Option<i32>>::sse_encode(self.avif_speed, serializer);
+        <Option<crate::api::TiffCompression>>::sse_encode(self.tiff_compression, serializer);
+        <Option<i32>>::sse_encode(self.target_size_bytes, serializer);
+        <Option<bool>>::sse_encode(self.target_size_downscale, serializer);
+        <Option<crate::api::PdfPageSize>>::sse_encode(self.pdf_page_size, serializer);
+        <Option<i32>>::sse_encode(self.pdf_margin_mm, serializer);
+        <Option<crate::api::ResizeFit>>::sse_encode(self.pdf_fit, serializer);
+        <Option<i32>>::sse_encode(self.pdf_jpeg_quality, serializer);
+        <Option<String>>::sse_encode(self.watermark_text, serializer);
+        <Option<String>>::sse_encode(self.watermark_image, serializer);
+        <Option<String>>::sse_encode(self.watermark_font_family, serializer);
+        <Option<i32>>::sse_encode(self.watermark_font_size, serializer);
+        <Option<i32>>::sse_encode(self.watermark_image_width, serializer);
+        <Option<String>>::sse_encode(self.watermark_color, serializer);
+        <Option<i32>>::sse_encode(self.watermark_opacity, serializer);
+        <Option<crate::api::WatermarkPosition>>::sse_encode(self.watermark_position, serializer);
+        <Option<i32>>::sse_encode(self.watermark_margin, serializer);
+        <Option<bool>>::sse_encode(self.watermark_tile, serializer);
+        <Option<bool>>::sse_encode(self.grayscale, serializer);
+        <Option<i32>>::sse_encode(self.brightness, serializer);
+        <Option<i32>>::sse_encode(self.contrast, serializer);
+        <Option<i32>>::sse_encode(self.hue_rotate, serializer);
+        <Option<f64>>::sse_encode(self.gamma, serializer);
+        <Option<bool>>::sse_encode(self.invert, serializer);
+        <Option<f64>>::sse_encode(self.blur, serializer);
+        <Option<f64>>::sse_encode(self.sharpen, serializer);
+        <Option<i32>>::sse_encode(self.sharpen_threshold, serializer);
+        <Option<i32>>::sse_encode(self.sheet_columns, serializer);
+        <Option<i32>>::sse_encode(self.sheet_cell_width, serializer);
+        <Option<i32>>::sse_encode(self.sheet_cell_height, serializer);
+        <Option<i32>>::sse_encode(self.sheet_spacing, serializer);
+        <Option<String>>::sse_encode(self.sheet_background, serializer);
+        <Option<bool>>::sse_encode(self.sheet_captions, serializer);
+        <Option<crate::api::IccProfileMode>>::sse_encode(self.icc_profile, serializer);
     }
 }
 
-impl SseDecode for crate::api::ConvertResult {
+impl SseEncode for crate::api::ConvertProgress {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut var_success = <bool>::sse_decode(deserializer);
-        let mut var_outputPath = <Option<String>>::sse_decode(deserializer);
-        let mut var_error = <Option<String>>::sse_decode(deserializer);
-        return crate::api::ConvertResult {
-            success: var_success,
-            output_path: var_outputPath,
-            error: var_error,
-        };
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(self.task_id, serializer);
+        <String>::sse_encode(self.input_path, serializer);
+        <i32>::sse_encode(self.file_index, serializer);
+        <i32>::sse_encode(self.file_count, serializer);
+        <i32>::sse_encode(self.progress, serializer);
+        <String>::sse_encode(self.status, serializer);
     }
 }
 
-impl SseDecode for crate::api::FileType {
+impl SseEncode for crate::api::ConvertResult {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut inner = <i32>::sse_decode(deserializer);
-        return match inner {
-            0 => crate::api::FileType::Image,
-            1 => crate::api::FileType::Document,
-            2 => crate::api::FileType::Audio,
-            3 => crate::api::FileType::Video,
-            _ => unreachable!("Invalid variant for FileType: {}", inner),
-        };
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <bool>::sse_encode(self.success, serializer);
+        <Option<String>>::sse_encode(self.output_path, serializer);
+        <Option<String>>::sse_encode(self.error, serializer);
+        <Option<crate::api::ConvertErrorCode>>::sse_encode(self.error_code, serializer);
+        <Option<crate::api::ConvertErrorDetails>>::sse_encode(self.error_details, serializer);
+        <bool>::sse_encode(self.skipped, serializer);
+        <Vec<String>>::sse_encode(self.route, serializer);
+        <Vec<String>>::sse_encode(self.output_paths, serializer);
+        <Vec<String>>::sse_encode(self.warnings, serializer);
+        <Option<i32>>::sse_encode(self.image_quality, serializer);
     }
 }
 
-impl SseDecode for i32 {
+impl SseEncode for crate::api::CropRect {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        deserializer.cursor.read_i32::<NativeEndian>().unwrap()
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.x, serializer);
+        <i32>::sse_encode(self.y, serializer);
+        <i32>::sse_encode(self.width, serializer);
+        <i32>::sse_encode(self.height, serializer);
     }
 }
 
-impl SseDecode for Vec<String> {
+impl SseEncode for crate::api::DetectionConfidence {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut len_ = <i32>::sse_decode(deserializer);
-        let mut ans_ = vec![];
-        for idx_ in 0..len_ {
-            ans_.push(<String>::sse_decode(deserializer));
-        }
-        return ans_;
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(
+            match self {
+                crate::api::DetectionConfidence::High => 0,
+                crate::api::DetectionConfidence::Medium => 1,
+                crate::api::DetectionConfidence::Low => 2,
+                crate::api::DetectionConfidence::Unknown => 3,
+                _ => {
+                    unimplemented!("");
+                }
+            },
+            serializer,
+        );
     }
 }
 
-impl SseDecode for Vec<crate::api::ConvertResult> {
+impl SseEncode for crate::api::DirectoryFileResult {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut len_ = <i32>::sse_decode(deserializer);
-        let mut ans_ = vec![];
-        for idx_ in 0..len_ {
-            ans_.push(<crate::api::ConvertResult>::sse_decode(deserializer));
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(self.input_path, serializer);
+        <String>::sse_encode(self.relative_path, serializer);
+        <crate::api::ConvertResult>::sse_encode(self.result, serializer);
+    }
+}
+
+impl SseEncode for crate::api::DirectoryFilter {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <Vec<String>>::sse_encode(self.include, serializer);
+        <Vec<String>>::sse_encode(self.exclude, serializer);
+        <Vec<crate::api::FileType>>::sse_encode(self.file_types, serializer);
+    }
+}
+
+impl SseEncode for f64 {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        serializer.cursor.write_f64::<NativeEndian>(self).unwrap();
+    }
+}
+
+impl SseEncode for crate::api::FileDetection {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <Option<crate::api::FileType>>::sse_encode(self.file_type, serializer);
+        <Option<String>>::sse_encode(self.format, serializer);
+        <Option<String>>::sse_encode(self.extension, serializer);
+        <crate::api::DetectionConfidence>::sse_encode(self.confidence, serializer);
+        <bool>::sse_encode(self.extension_mismatch, serializer);
+    }
+}
+
+impl SseEncode for crate::api::FileType {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(
+            match self {
+                crate::api::FileType::Image => 0,
+                crate::api::FileType::Document => 1,
+                crate::api::FileType::Audio => 2,
+                crate::api::FileType::Video => 3,
+                crate::api::FileType::Config => 4,
+                _ => {
+                    unimplemented!("");
+                }
+            },
+            serializer,
+        );
+    }
+}
+
+impl SseEncode for crate::api::Flip {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(
+            match self {
+                crate::api::Flip::Horizontal => 0,
+                crate::api::Flip::Vertical => 1,
+                crate::api::Flip::Both => 2,
+                _ => {
+                    unimplemented!("");
+                }
+            },
+            serializer,
+        );
+    }
+}
+
+impl SseEncode for i32 {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        serializer.cursor.write_i32::<NativeEndian>(self).unwrap();
+    }
+}
+
+impl SseEncode for crate::api::IccProfileMode {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(
+            match self {
+                crate::api::IccProfileMode::Preserve => 0,
+                crate::api::IccProfileMode::ConvertToSrgb => 1,
+                _ => {
+                    unimplemented!("");
+                }
+            },
+            serializer,
+        );
+    }
+}
+
+impl SseEncode for Vec<String> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <String>::sse_encode(item, serializer);
         }
-        return ans_;
     }
 }
 
-impl SseDecode for Vec<u8> {
+impl SseEncode for Vec<crate::api::ConvertResult> {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut len_ = <i32>::sse_decode(deserializer);
-        let mut ans_ = vec![];
-        for idx_ in 0..len_ {
-            ans_.push(<u8>::sse_decode(deserializer));
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::api::ConvertResult>::sse_encode(item, serializer);
         }
-        return ans_;
     }
 }
 
-impl SseDecode for Option<String> {
+impl SseEncode for Vec<crate::api::DirectoryFileResult> {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        if (<bool>::sse_decode(deserializer)) {
-            return Some(<String>::sse_decode(deserializer));
-        } else {
-            return None;
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::api::DirectoryFileResult>::sse_encode(item, serializer);
         }
     }
 }
 
-impl SseDecode for Option<crate::api::FileType> {
+impl SseEncode for Vec<crate::api::FileType> {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        if (<bool>::sse_decode(deserializer)) {
-            return Some(<crate::api::FileType>::sse_decode(deserializer));
-        } else {
-            return None;
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::api::FileType>::sse_encode(item, serializer);
         }
     }
 }
 
-impl SseDecode for Option<i32> {
+impl SseEncode for Vec<i32> {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        if (<bool>::sse_decode(deserializer)) {
-            return Some(<i32>::sse_decode(deserializer));
-        } else {
-            return None;
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <i32>::sse_encode(item, serializer);
         }
     }
 }
 
-impl SseDecode for u8 {
+impl SseEncode for Vec<u8> {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        deserializer.cursor.read_u8().unwrap()
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <u8>::sse_encode(item, serializer);
+        }
     }
 }
 
-impl SseDecode for () {
+impl SseEncode for crate::api::MetadataMode {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {}
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(
+            match self {
+                crate::api::MetadataMode::StripAll => 0,
+                crate::api::MetadataMode::StripGps => 1,
+                crate::api::MetadataMode::Preserve => 2,
+                _ => {
+                    unimplemented!("");
+                }
+            },
+            serializer,
+        );
+    }
 }
 
-fn pde_ffi_dispatcher_primary_impl(
-    func_id: i32,
-    port: flutter_rust_bridge::for_generated::MessagePort,
-    ptr: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
-    rust_vec_len: i32,
-    data_len: i32,
-) {
-    // Codec=Pde (Serialization + dispatch), see doc to use other codecs
-    match func_id {
-        1 => wire__crate__api__convert_file_impl(port, ptr, rust_vec_len, data_len),
-        2 => wire__crate__api__convert_files_impl(port, ptr, rust_vec_len, data_len),
-        3 => wire__crate__api__detect_file_type_impl(port, ptr, rust_vec_len, data_len),
-        4 => wire__crate__api__get_supported_output_formats_impl(port, ptr, rust_vec_len, data_len),
-        5 => wire__crate__api__get_supported_output_formats_for_file_impl(
-            port,
-            ptr,
-            rust_vec_len,
-            data_len,
-        ),
-        6 => wire__crate__api__open_folder_impl(port, ptr, rust_vec_len, data_len),
-        _ => unreachable!(),
+impl SseEncode for crate::api::OnConflict {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(
+            match self {
+                crate::api::OnConflict::Overwrite => 0,
+                crate::api::OnConflict::Skip => 1,
+                crate::api::OnConflict::Rename => 2,
+                crate::api::OnConflict::Fail => 3,
+                _ => {
+                    unimplemented!("");
+                }
+            },
+            serializer,
+        );
     }
 }
 
-fn pde_ffi_dispatcher_sync_impl(
-    func_id: i32,
-    ptr: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
-    rust_vec_len: i32,
-    data_len: i32,
-) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
-    // Codec=Pde (Serialization + dispatch), see doc to use other codecs
-    match func_id {
-        _ => unreachable!(),
+impl SseEncode for Option<String> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <String>::sse_encode(value, serializer);
+        }
     }
 }
 
-// Section: rust2dart
-
-// Codec=Dco (DartCObject based), see doc to use other codecs
-impl flutter_rust_bridge::IntoDart for crate::api::ConvertOptions {
-    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
-        [
-            self.output_format.into_into_dart().into_dart(),
-            self.image_quality.into_into_dart().into_dart(),
-            self.audio_quality.into_into_dart().into_dart(),
-            self.audio_bitrate.into_into_dart().into_dart(),
-            self.audio_sample_rate.into_into_dart().into_dart(),
-            self.video_crf.into_into_dart().into_dart(),
-            self.video_bitrate.into_into_dart().into_dart(),
-            self.video_width.into_into_dart().into_dart(),
-            self.video_height.into_into_dart().into_dart(),
-            self.ffmpeg_path.into_into_dart().into_dart(),
-        ]
-        .into_dart()
+impl SseEncode for Option<bool> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <bool>::sse_encode(value, serializer);
+        }
     }
 }
-impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::api::ConvertOptions {}
-impl flutter_rust_bridge::IntoIntoDart<crate::api::ConvertOptions> for crate::api::ConvertOptions {
-    fn into_into_dart(self) -> crate::api::ConvertOptions {
-        self
+
+impl SseEncode for Option<crate::api::ConvertErrorCode> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <crate::api::ConvertErrorCode>::sse_encode(value, serializer);
+        }
     }
 }
-// Codec=Dco (DartCObject based), see doc to use other codecs
-impl flutter_rust_bridge::IntoDart for crate::api::ConvertResult {
-    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
-        [
-            self.success.into_into_dart().into_dart(),
-            self.output_path.into_into_dart().into_dart(),
-            self.error.into_into_dart().into_dart(),
-        ]
-        .into_dart()
+
+impl SseEncode for Option<crate::api::ConvertErrorDetails> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <crate::api::ConvertErrorDetails>::sse_encode(value, serializer);
+        }
     }
 }
-impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::api::ConvertResult {}
-impl flutter_rust_bridge::IntoIntoDart<crate::api::ConvertResult> for crate::api::ConvertResult {
-    fn into_into_dart(self) -> crate::api::ConvertResult {
-        self
+
+impl SseEncode for Option<crate::api::CropRect> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <crate::api::CropRect>::sse_encode(value, serializer);
+        }
     }
 }
-// Codec=Dco (DartCObject based), see doc to use other codecs
-impl flutter_rust_bridge::IntoDart for crate::api::FileType {
-    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
-        match self {
-            Self::Image => 0.into_dart(),
-            Self::Document => 1.into_dart(),
-            Self::Audio => 2.into_dart(),
-            Self::Video => 3.into_dart(),
-            _ => unreachable!(),
+
+impl SseEncode for Option<f64> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <f64>::sse_encode(value, serializer);
         }
     }
 }
-impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::api::FileType {}
-impl flutter_rust_bridge::IntoIntoDart<crate::api::FileType> for crate::api::FileType {
-    fn into_into_dart(self) -> crate::api::FileType {
-        self
+
+impl SseEncode for Option<crate::api::FileType> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <crate::api::FileType>::sse_encode(value, serializer);
+        }
     }
 }
 
-impl SseEncode for String {
+impl SseEncode for Option<crate::api::Flip> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <Vec<u8>>::sse_encode(self.into_bytes(), serializer);
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <crate::api::Flip>::sse_encode(value, serializer);
+        }
     }
 }
 
-impl SseEncode for bool {
+impl SseEncode for Option<i32> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        serializer.cursor.write_u8(self as _).unwrap();
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <i32>::sse_encode(value, serializer);
+        }
     }
 }
 
-impl SseEncode for crate::api::ConvertOptions {
+impl SseEncode for Option<crate::api::IccProfileMode> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <String>::sse_encode(self.output_format, serializer);
-        <Option<i32>>::sse_encode(self.image_quality, serializer);
-        <Option<i32>>::sse_encode(self.audio_quality, serializer);
-        <Option<i32>>::sse_encode(self.audio_bitrate, serializer);
-        <Option<i32>>::sse_encode(self.audio_sample_rate, serializer);
-        <Option<i32>>::sse_encode(self.video_crf, serializer);
-        <Option<i32>>::sse_encode(self.video_bitrate, serializer);
-        <Option<i32>>::sse_encode(self.video_width, serializer);
-        <Option<i32>>::sse_encode(self.video_height, serializer);
-        <Option<String>>::sse_encode(self.ffmpeg_path, serializer);
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <crate::api::IccProfileMode>::sse_encode(value, serializer);
+        }
     }
 }
 
-impl SseEncode for crate::api::ConvertResult {
+impl SseEncode for Option<crate::api::MetadataMode> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <bool>::sse_encode(self.success, serializer);
-        <Option<String>>::sse_encode(self.output_path, serializer);
-        <Option<String>>::sse_encode(self.error, serializer);
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <crate::api::MetadataMode>::sse_encode(value, serializer);
+        }
     }
 }
 
-impl SseEncode for crate::api::FileType {
+impl SseEncode for Option<crate::api::OnConflict> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <i32>::sse_encode(
-            match self {
-                crate::api::FileType::Image => 0,
-                crate::api::FileType::Document => 1,
-                crate::api::FileType::Audio => 2,
-                crate::api::FileType::Video => 3,
-                _ => {
-                    unimplemented!("");
-                }
-            },
-            serializer,
-        );
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <crate::api::OnConflict>::sse_encode(value, serializer);
+        }
     }
 }
 
-impl SseEncode for i32 {
+impl SseEncode for Option<crate::api::PdfPageSize> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        serializer.cursor.write_i32::<NativeEndian>(self).unwrap();
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <crate::api::PdfPageSize>::sse_encode(value, serializer);
+        }
     }
 }
 
-impl SseEncode for Vec<String> {
+impl SseEncode for Option<crate::api::PngFilter> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <i32>::sse_encode(self.len() as _, serializer);
-        for item in self {
-            <String>::sse_encode(item, serializer);
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <crate::api::PngFilter>::sse_encode(value, serializer);
         }
     }
 }
 
-impl SseEncode for Vec<crate::api::ConvertResult> {
+impl SseEncode for Option<crate::api::ResizeFilter> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <i32>::sse_encode(self.len() as _, serializer);
-        for item in self {
-            <crate::api::ConvertResult>::sse_encode(item, serializer);
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <crate::api::ResizeFilter>::sse_encode(value, serializer);
         }
     }
 }
 
-impl SseEncode for Vec<u8> {
+impl SseEncode for Option<crate::api::ResizeFit> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <i32>::sse_encode(self.len() as _, serializer);
-        for item in self {
-            <u8>::sse_encode(item, serializer);
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <crate::api::ResizeFit>::sse_encode(value, serializer);
         }
     }
 }
 
-impl SseEncode for Option<String> {
+impl SseEncode for Option<crate::api::TiffCompression> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
         <bool>::sse_encode(self.is_some(), serializer);
         if let Some(value) = self {
-            <String>::sse_encode(value, serializer);
+            <crate::api::TiffCompression>::sse_encode(value, serializer);
         }
     }
 }
 
-impl SseEncode for Option<crate::api::FileType> {
+impl SseEncode for Option<crate::api::WatermarkPosition> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
         <bool>::sse_encode(self.is_some(), serializer);
         if let Some(value) = self {
-            <crate::api::FileType>::sse_encode(value, serializer);
+            <crate::api::WatermarkPosition>::sse_encode(value, serializer);
         }
     }
 }
 
-impl SseEncode for Option<i32> {
+impl SseEncode for Option<Vec<i32>> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
         <bool>::sse_encode(self.is_some(), serializer);
         if let Some(value) = self {
-            <i32>::sse_encode(value, serializer);
+            <Vec<i32>>::sse_encode(value, serializer);
         }
     }
 }
 
+impl SseEncode for crate::api::PdfPageSize {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(
+            match self {
+                crate::api::PdfPageSize::Image => 0,
+                crate::api::PdfPageSize::A3 => 1,
+                crate::api::PdfPageSize::A4 => 2,
+                crate::api::PdfPageSize::A5 => 3,
+                crate::api::PdfPageSize::Letter => 4,
+                crate::api::PdfPageSize::Legal => 5,
+                _ => {
+                    unimplemented!("");
+                }
+            },
+            serializer,
+        );
+    }
+}
+
+impl SseEncode for crate::api::PngFilter {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(
+            match self {
+                crate::api::PngFilter::NoFilter => 0,
+                crate::api::PngFilter::Sub => 1,
+                crate::api::PngFilter::Up => 2,
+                crate::api::PngFilter::Average => 3,
+                crate::api::PngFilter::Paeth => 4,
+                crate::api::PngFilter::Adaptive => 5,
+                _ => {
+                    unimplemented!("");
+                }
+            },
+            serializer,
+        );
+    }
+}
+
+impl SseEncode for crate::api::ResizeFilter {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(
+            match self {
+                crate::api::ResizeFilter::Nearest => 0,
+                crate::api::ResizeFilter::Triangle => 1,
+                crate::api::ResizeFilter::CatmullRom => 2,
+                crate::api::ResizeFilter::Gaussian => 3,
+                crate::api::ResizeFilter::Lanczos3 => 4,
+                _ => {
+                    unimplemented!("");
+                }
+            },
+            serializer,
+        );
+    }
+}
+
+impl SseEncode for crate::api::ResizeFit {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(
+            match self {
+                crate::api::ResizeFit::Contain => 0,
+                crate::api::ResizeFit::Cover => 1,
+                crate::api::ResizeFit::Exact => 2,
+                crate::api::ResizeFit::MaxDimension => 3,
+                _ => {
+                    unimplemented!("");
+                }
+            },
+            serializer,
+        );
+    }
+}
+
+impl SseEncode for crate::api::TiffCompression {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(
+            match self {
+                crate::api::TiffCompression::Uncompressed => 0,
+                crate::api::TiffCompression::Lzw => 1,
+                crate::api::TiffCompression::Deflate => 2,
+                crate::api::TiffCompression::PackBits => 3,
+                _ => {
+                    unimplemented!("");
+                }
+            },
+            serializer,
+        );
+    }
+}
+
 impl SseEncode for u8 {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
@@ -656,6 +3130,29 @@ impl SseEncode for () {
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {}
 }
 
+impl SseEncode for crate::api::WatermarkPosition {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(
+            match self {
+                crate::api::WatermarkPosition::TopLeft => 0,
+                crate::api::WatermarkPosition::Top => 1,
+                crate::api::WatermarkPosition::TopRight => 2,
+                crate::api::WatermarkPosition::Left => 3,
+                crate::api::WatermarkPosition::Center => 4,
+                crate::api::WatermarkPosition::Right => 5,
+                crate::api::WatermarkPosition::BottomLeft => 6,
+                crate::api::WatermarkPosition::Bottom => 7,
+                crate::api::WatermarkPosition::BottomRight => 8,
+                _ => {
+                    unimplemented!("");
+                }
+            },
+            serializer,
+        );
+    }
+}
+
 #[cfg(not(target_family = "wasm"))]
 mod io {
     // This file is automatically generated, so please do not edit it.
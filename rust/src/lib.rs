@@ -1,6 +1,11 @@
 mod frb_generated; /* AUTO INJECTED BY flutter_rust_bridge. This line may not be accurate, and you can change it according to your needs. */
 pub mod api;
 pub mod converters;
+pub mod detect;
+pub mod error;
+pub mod progress;
+pub mod task;
 
 pub use api::*;
 pub use converters::*;
+pub use error::ConvertError;
@@ -0,0 +1,97 @@
+use crate::api::ConvertProgress;
+use std::sync::atomic::{AtomicI32, AtomicU64, Ordering};
+use std::sync::Arc;
+
+/// Progress callback
+pub type ProgressCallback = Arc<dyn Fn(ConvertProgress) + Send + Sync>;
+
+/// Generate a new unique task ID
+pub fn new_task_id() -> String {
+    static NEXT_TASK_ID: AtomicU64 = AtomicU64::new(1);
+    format!("task-{}", NEXT_TASK_ID.fetch_add(1, Ordering::Relaxed))
+}
+
+/// Reports the progress of one file within a task
+pub struct ProgressReporter {
+    callback: Option<ProgressCallback>,
+    task_id: String,
+    input_path: String,
+    file_index: i32,
+    file_count: i32,
+    last_progress: AtomicI32,
+    /// Part of the file's progress the current step maps onto (multi-step routes)
+    range_start: AtomicI32,
+    range_end: AtomicI32,
+}
+
+impl ProgressReporter {
+    pub fn new(
+        callback: ProgressCallback,
+        task_id: &str,
+        input_path: &str,
+        file_index: usize,
+        file_count: usize,
+    ) -> Self {
+        Self {
+            callback: Some(callback),
+            task_id: task_id.to_string(),
+            input_path: input_path.to_string(),
+            file_index: file_index as i32,
+            file_count: file_count as i32,
+            last_progress: AtomicI32::new(0),
+            range_start: AtomicI32::new(0),
+            range_end: AtomicI32::new(100),
+        }
+    }
+
+    /// Reporter that discards all events
+    pub fn silent(task_id: &str, input_path: &str) -> Self {
+        Self {
+            callback: None,
+            task_id: task_id.to_string(),
+            input_path: input_path.to_string(),
+            file_index: 0,
+            file_count: 1,
+            last_progress: AtomicI32::new(0),
+            range_start: AtomicI32::new(0),
+            range_end: AtomicI32::new(100),
+        }
+    }
+
+    pub fn task_id(&self) -> &str {
+        &self.task_id
+    }
+
+    /// Report a percentage (0-100) for the current file together with a status message
+    pub fn report(&self, progress: i32, status: impl Into<String>) {
+        let start = self.range_start.load(Ordering::Relaxed);
+        let end = self.range_end.load(Ordering::Relaxed);
+        let progress = start + progress.clamp(0, 100) * (end - start) / 100;
+        self.last_progress.store(progress, Ordering::Relaxed);
+        self.emit(progress, status.into());
+    }
+
+    /// Map the 0-100 reported by the current step onto `start..end` of the file's progress
+    pub fn set_range(&self, start: i32, end: i32) {
+        self.range_start.store(start, Ordering::Relaxed);
+        self.range_end.store(end, Ordering::Relaxed);
+    }
+
+    /// Report a new stage without changing the percentage
+    pub fn stage(&self, status: impl Into<String>) {
+        self.emit(self.last_progress.load(Ordering::Relaxed), status.into());
+    }
+
+    fn emit(&self, progress: i32, status: String) {
+        if let Some(callback) = &self.callback {
+            callback(ConvertProgress {
+                task_id: self.task_id.clone(),
+                input_path: self.input_path.clone(),
+                file_index: self.file_index,
+                file_count: self.file_count,
+                progress,
+                status,
+            });
+        }
+    }
+}
@@ -0,0 +1,85 @@
+use std::collections::HashMap;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, Mutex, OnceLock};
+
+/// Cancellation flag shared between a running task and `cancel_conversion`
+#[derive(Default)]
+pub struct CancelToken {
+    cancelled: AtomicBool,
+}
+
+impl CancelToken {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn cancel(&self) {
+        self.cancelled.store(true, Ordering::Relaxed);
+    }
+
+    pub fn is_cancelled(&self) -> bool {
+        self.cancelled.load(Ordering::Relaxed)
+    }
+}
+
+fn running_tasks() -> &'static Mutex<HashMap<String, Arc<CancelToken>>> {
+    static TASKS: OnceLock<Mutex<HashMap<String, Arc<CancelToken>>>> = OnceLock::new();
+    TASKS.get_or_init(Default::default)
+}
+
+/// A running task; unregistered when dropped
+pub struct TaskHandle {
+    task_id: String,
+    token: Arc<CancelToken>,
+}
+
+impl TaskHandle {
+    /// Register a running task so that it can be cancelled by ID
+    pub fn register(task_id: &str) -> Self {
+        let token = Arc::new(CancelToken::new());
+        running_tasks()
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .insert(task_id.to_string(), token.clone());
+        Self {
+            task_id: task_id.to_string(),
+            token,
+        }
+    }
+
+    pub fn task_id(&self) -> &str {
+        &self.task_id
+    }
+
+    pub fn token(&self) -> &Arc<CancelToken> {
+        &self.token
+    }
+}
+
+impl Drop for TaskHandle {
+    fn drop(&mut self) {
+        let mut tasks = running_tasks().lock().unwrap_or_else(|e| e.into_inner());
+        // Only remove our own entry (the ID may have been reused by a newer task)
+        if tasks
+            .get(&self.task_id)
+            .is_some_and(|t| Arc::ptr_eq(t, &self.token))
+        {
+            tasks.remove(&self.task_id);
+        }
+    }
+}
+
+/// Cancel a running task; returns false if no task with this ID is running
+pub fn cancel(task_id: &str) -> bool {
+    match running_tasks()
+        .lock()
+        .unwrap_or_else(|e| e.into_inner())
+        .get(task_id)
+    {
+        Some(token) => {
+            token.cancel();
+            true
+        }
+        None => false,
+    }
+}